@@ -47,9 +47,64 @@ pub fn is_running_as_admin() -> bool {
     false
 }
 
+// 检查前台窗口所属进程是否以管理员权限运行（无法打开进程时视为已提升）
+#[cfg(windows)]
+pub fn is_foreground_window_elevated() -> bool {
+    use windows::Win32::Foundation::{CloseHandle, HWND};
+    use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd == HWND(0) {
+            return false;
+        }
+
+        let mut process_id: u32 = 0;
+        GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+        if process_id == 0 || process_id == std::process::id() {
+            return false;
+        }
+
+        let process = match OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id) {
+            Ok(handle) => handle,
+            Err(_) => return true,
+        };
+
+        let mut token: HANDLE = HANDLE::default();
+        if OpenProcessToken(process, TOKEN_QUERY, &mut token).is_err() {
+            let _ = CloseHandle(process);
+            return true;
+        }
+
+        let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+        let mut return_length = 0u32;
+        let result = GetTokenInformation(
+            token,
+            TokenElevation,
+            Some(&mut elevation as *mut _ as *mut _),
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut return_length,
+        );
+
+        let _ = CloseHandle(token);
+        let _ = CloseHandle(process);
+
+        result.is_ok() && elevation.TokenIsElevated != 0
+    }
+}
+
+#[cfg(not(windows))]
+pub fn is_foreground_window_elevated() -> bool {
+    false
+}
+
 // 以管理员权限重启应用程序
 #[cfg(windows)]
 pub fn restart_as_admin() -> Result<(), String> {
+    // UAC 被拒绝时 ShellExecuteW 返回 SE_ERR_ACCESSDENIED
+    const SE_ERR_ACCESSDENIED: isize = 5;
+
     let current_exe =
         std::env::current_exe().map_err(|e| format!("获取当前程序路径失败: {}", e))?;
 
@@ -76,6 +131,9 @@ pub fn restart_as_admin() -> Result<(), String> {
         if result.0 > 32 {
             // 成功启动管理员进程，退出当前进程
             std::process::exit(0);
+        } else if result.0 == SE_ERR_ACCESSDENIED {
+            // 用户在 UAC 提示中选择了"否"
+            Err(ELEVATION_CANCELLED_ERROR.to_string())
         } else {
             Err(format!("启动管理员进程失败，错误代码: {}", result.0))
         }
@@ -99,6 +157,14 @@ pub fn check_admin_required_for_shortcuts() -> bool {
     false
 }
 
+// 用户拒绝 UAC 提示时 restart_as_admin 返回的错误
+pub const ELEVATION_CANCELLED_ERROR: &str = "已取消管理员权限请求";
+
+// 判断错误是否由用户拒绝 UAC 导致
+pub fn is_elevation_cancelled(error: &str) -> bool {
+    error == ELEVATION_CANCELLED_ERROR
+}
+
 // 获取管理员权限状态信息
 pub fn get_admin_status() -> AdminStatus {
     AdminStatus {
@@ -146,15 +146,41 @@ impl AIConfigManager {
     }
 }
 
+// 模型参数的有效范围，超出范围时使用默认值
+pub const TEMPERATURE_RANGE: std::ops::RangeInclusive<f64> = 0.0..=2.0;
+pub const MAX_TOKENS_RANGE: std::ops::RangeInclusive<u32> = 1..=32768;
+
 // 从应用设置创建AI配置
 pub fn create_ai_config_from_settings(settings: &crate::settings::AppSettings) -> AIConfig {
+    let defaults = AIConfig::default();
+
+    let temperature = if TEMPERATURE_RANGE.contains(&settings.ai_temperature) {
+        settings.ai_temperature as f32
+    } else {
+        defaults.temperature
+    };
+    let max_tokens = if MAX_TOKENS_RANGE.contains(&settings.ai_max_tokens) {
+        settings.ai_max_tokens
+    } else {
+        defaults.max_tokens
+    };
+
     AIConfig {
         api_key: settings.ai_api_key.clone(),
         model: settings.ai_model.clone(),
         base_url: settings.ai_base_url.clone(),
         timeout_secs: 120,
-        temperature: 0.3,
-        max_tokens: 2048,
+        temperature,
+        max_tokens,
+    }
+}
+
+// 检查 AI 功能是否被功能开关禁用
+pub fn ensure_ai_enabled(settings: &crate::settings::AppSettings) -> Result<(), String> {
+    if settings.enable_ai {
+        Ok(())
+    } else {
+        Err("AI功能已在设置中禁用".to_string())
     }
 }
 
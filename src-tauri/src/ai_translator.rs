@@ -1,9 +1,23 @@
 
 use crate::ai_config::AIConfig;
+use once_cell::sync::OnceCell;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use tauri::Emitter;
 use tokio::sync::mpsc;
 
+// 用于发送翻译事件
+static APP_HANDLE: OnceCell<tauri::AppHandle> = OnceCell::new();
+
+// 初始化翻译事件通知
+pub fn init_translation_events(app_handle: tauri::AppHandle) {
+    let _ = APP_HANDLE.set(app_handle);
+}
+
+pub fn get_app_handle() -> Option<&'static tauri::AppHandle> {
+    APP_HANDLE.get()
+}
+
 #[derive(Debug, Clone)]
 pub struct TranslationConfig {
     // 通用AI配置
@@ -12,6 +26,10 @@ pub struct TranslationConfig {
     pub target_language: String,
     // 翻译提示词模板
     pub prompt_template: String,
+    // 请求失败时的最大重试次数
+    pub max_retries: u32,
+    // 重试基础延迟（毫秒），每次重试翻倍
+    pub retry_base_delay_ms: u64,
 }
 
 impl Default for TranslationConfig {
@@ -22,6 +40,8 @@ impl Default for TranslationConfig {
             prompt_template:
                 "请将以下文本翻译成{target_language}，严格保持原文的所有格式、换行符、段落结构和空白字符，只返回翻译结果，不要添加任何解释或修改格式："
                     .to_string(),
+            max_retries: 2,
+            retry_base_delay_ms: 1000,
         }
     }
 }
@@ -74,6 +94,7 @@ pub enum TranslationError {
     NetworkError(reqwest::Error),
     AuthenticationError,
     RateLimitError,
+    ServerError(u16, String),
     ParseError(String),
     ConfigError(String),
     TimeoutError,
@@ -86,6 +107,7 @@ impl std::fmt::Display for TranslationError {
             TranslationError::NetworkError(e) => write!(f, "网络请求失败: {}", e),
             TranslationError::AuthenticationError => write!(f, "API认证失败"),
             TranslationError::RateLimitError => write!(f, "API限流"),
+            TranslationError::ServerError(status, e) => write!(f, "服务器错误 HTTP {}: {}", status, e),
             TranslationError::ParseError(e) => write!(f, "API响应解析失败: {}", e),
             TranslationError::ConfigError(e) => write!(f, "配置错误: {}", e),
             TranslationError::TimeoutError => write!(f, "超时"),
@@ -96,6 +118,19 @@ impl std::fmt::Display for TranslationError {
 
 impl std::error::Error for TranslationError {}
 
+impl TranslationError {
+    // 限流、服务器错误和连接超时可重试，认证等错误直接失败
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            TranslationError::RateLimitError
+            | TranslationError::ServerError(_, _)
+            | TranslationError::TimeoutError => true,
+            TranslationError::NetworkError(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+}
+
 impl From<reqwest::Error> for TranslationError {
     fn from(error: reqwest::Error) -> Self {
         TranslationError::NetworkError(error)
@@ -191,6 +226,8 @@ impl AITranslator {
         let url = self.config.ai_config.get_chat_completions_url();
         let client = self.client.clone();
         let api_key = self.config.ai_config.api_key.clone();
+        let max_retries = self.config.max_retries;
+        let retry_base_delay_ms = self.config.retry_base_delay_ms;
 
         // 调试输出
         println!("AI翻译请求:");
@@ -200,33 +237,68 @@ impl AITranslator {
         println!("  API Key: {}...", &api_key[..api_key.len().min(10)]);
 
         tokio::spawn(async move {
-            let result = Self::send_stream_request(client, url, api_key, request, tx.clone()).await;
-
-            if let Err(e) = result {
-                let _ = tx.send(TranslationResult::Error(e)).await;
-            } else {
-                let _ = tx.send(TranslationResult::Complete).await;
+            let mut attempt = 0;
+            loop {
+                let mut chunks_sent = false;
+                let result =
+                    Self::send_stream_request(&client, &url, &api_key, &request, &tx, &mut chunks_sent)
+                        .await;
+
+                match result {
+                    Ok(()) => {
+                        let _ = tx.send(TranslationResult::Complete).await;
+                        break;
+                    }
+                    // 已输出部分内容时不再重试，避免重复输入
+                    Err(e) if !chunks_sent && attempt < max_retries && e.is_retryable() && !tx.is_closed() => {
+                        attempt += 1;
+                        let delay_ms = retry_base_delay_ms.saturating_mul(1u64 << (attempt - 1).min(16));
+                        println!("翻译请求失败，{}ms 后进行第 {} 次重试: {}", delay_ms, attempt, e);
+                        Self::emit_retry_event(attempt, max_retries, delay_ms, &e);
+                        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+                    }
+                    Err(e) => {
+                        let _ = tx.send(TranslationResult::Error(e)).await;
+                        break;
+                    }
+                }
             }
         });
 
         Ok(rx)
     }
 
+    // 通知前端正在重试
+    fn emit_retry_event(attempt: u32, max_retries: u32, delay_ms: u64, error: &TranslationError) {
+        if let Some(app_handle) = APP_HANDLE.get() {
+            let _ = app_handle.emit(
+                "translation-retry",
+                serde_json::json!({
+                    "attempt": attempt,
+                    "maxRetries": max_retries,
+                    "delayMs": delay_ms,
+                    "error": error.to_string(),
+                }),
+            );
+        }
+    }
+
     // 发送流式请求
     async fn send_stream_request(
-        client: Client,
-        url: String,
-        api_key: String,
-        request: TranslationRequest,
-        tx: mpsc::Sender<TranslationResult>,
+        client: &Client,
+        url: &str,
+        api_key: &str,
+        request: &TranslationRequest,
+        tx: &mpsc::Sender<TranslationResult>,
+        chunks_sent: &mut bool,
     ) -> Result<(), TranslationError> {
         use futures_util::StreamExt;
 
         let response = client
-            .post(&url)
+            .post(url)
             .header("Authorization", format!("Bearer {}", api_key))
             .header("Content-Type", "application/json")
-            .json(&request)
+            .json(request)
             .send()
             .await?;
 
@@ -245,6 +317,7 @@ impl AITranslator {
                 }
                 401 => TranslationError::AuthenticationError,
                 429 => TranslationError::RateLimitError,
+                500..=599 => TranslationError::ServerError(status.as_u16(), error_body),
                 _ => TranslationError::UnknownError(format!(
                     "HTTP {} {}: {}",
                     status.as_u16(),
@@ -282,6 +355,7 @@ impl AITranslator {
                                     if let Some(delta) = choice.delta {
                                         if let Some(content) = delta.content {
                                             if !content.is_empty() {
+                                                *chunks_sent = true;
                                                 if let Err(_) =
                                                     tx.send(TranslationResult::Chunk(content)).await
                                                 {
@@ -314,10 +388,22 @@ pub fn config_from_settings(settings: &crate::settings::AppSettings) -> Translat
     TranslationConfig {
         ai_config,
         target_language: settings.ai_target_language.clone(),
-        prompt_template: settings.ai_translation_prompt.clone(),
+        prompt_template: select_prompt_template(settings),
+        max_retries: settings.ai_max_retries,
+        retry_base_delay_ms: settings.ai_retry_base_delay_ms as u64,
     }
 }
 
+// 选择目标语言对应的提示词模板，未配置时使用默认模板
+fn select_prompt_template(settings: &crate::settings::AppSettings) -> String {
+    settings
+        .ai_language_prompts
+        .get(&settings.ai_target_language)
+        .filter(|prompt| !prompt.trim().is_empty())
+        .unwrap_or(&settings.ai_translation_prompt)
+        .clone()
+}
+
 // 检查翻译配置是否有效
 pub fn is_translation_config_valid(settings: &crate::settings::AppSettings) -> bool {
     crate::ai_config::is_ai_config_valid(settings) && !settings.ai_target_language.is_empty()
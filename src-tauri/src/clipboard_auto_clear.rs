@@ -0,0 +1,52 @@
+// 粘贴敏感内容后定时清除系统剪贴板（类似密码管理器）
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+// 当前清除任务的ID，新任务或取消时递增，使旧任务失效
+static CLEAR_TASK_ID: AtomicU64 = AtomicU64::new(0);
+
+// 粘贴标记为敏感的条目后调用：设置了超时时间时安排清除
+pub fn schedule_after_paste(content: &str) {
+    let seconds = crate::settings::get_global_settings().auto_clear_clipboard_seconds;
+    if seconds == 0 {
+        return;
+    }
+
+    let task_id = CLEAR_TASK_ID.fetch_add(1, Ordering::SeqCst) + 1;
+    let content = content.to_string();
+
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_secs(seconds as u64));
+
+        // 已被新任务替换或取消
+        if CLEAR_TASK_ID.load(Ordering::SeqCst) != task_id {
+            return;
+        }
+
+        if let Err(e) = clear_if_unchanged(&content) {
+            println!("自动清除剪贴板失败: {}", e);
+        }
+    });
+}
+
+// 取消尚未执行的清除任务
+pub fn cancel_pending_clear() {
+    CLEAR_TASK_ID.fetch_add(1, Ordering::SeqCst);
+}
+
+// 仅当剪贴板仍是该内容时清除，用户期间复制了其他内容则保留
+fn clear_if_unchanged(content: &str) -> Result<(), String> {
+    let mut clipboard = arboard::Clipboard::new().map_err(|e| format!("获取剪贴板失败: {}", e))?;
+
+    match clipboard.get_text() {
+        Ok(current) if current == content => {}
+        _ => return Ok(()),
+    }
+
+    clipboard
+        .clear()
+        .map_err(|e| format!("清除剪贴板失败: {}", e))?;
+    println!("已自动清除剪贴板中的敏感内容");
+    Ok(())
+}
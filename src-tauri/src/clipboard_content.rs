@@ -157,6 +157,84 @@ pub fn set_windows_clipboard_image_with_file(
     Ok(())
 }
 
+// 列出系统剪贴板当前可用的格式名称（用于排查粘贴格式问题）
+#[cfg(windows)]
+pub fn get_clipboard_formats() -> Result<Vec<String>, String> {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::DataExchange::{
+        EnumClipboardFormats, GetClipboardFormatNameW, OpenClipboard,
+    };
+
+    unsafe {
+        if OpenClipboard(HWND(0)).is_err() {
+            return Err("打开剪贴板失败".into());
+        }
+
+        let _guard = ClipboardGuard;
+
+        let mut formats = Vec::new();
+        let mut format = EnumClipboardFormats(0);
+        while format != 0 {
+            let name = match standard_clipboard_format_name(format) {
+                Some(name) => name.to_string(),
+                None => {
+                    let mut buffer = [0u16; 256];
+                    let len = GetClipboardFormatNameW(format, &mut buffer);
+                    if len > 0 {
+                        let name = String::from_utf16_lossy(&buffer[..len as usize]);
+                        if name == "HTML Format" {
+                            "CF_HTML".to_string()
+                        } else {
+                            name
+                        }
+                    } else {
+                        format!("0x{:04X}", format)
+                    }
+                }
+            };
+            formats.push(name);
+            format = EnumClipboardFormats(format);
+        }
+
+        Ok(formats)
+    }
+}
+
+#[cfg(not(windows))]
+pub fn get_clipboard_formats() -> Result<Vec<String>, String> {
+    Err("当前平台不支持查询剪贴板格式".to_string())
+}
+
+#[cfg(windows)]
+fn standard_clipboard_format_name(format: u32) -> Option<&'static str> {
+    let name = match format {
+        1 => "CF_TEXT",
+        2 => "CF_BITMAP",
+        3 => "CF_METAFILEPICT",
+        4 => "CF_SYLK",
+        5 => "CF_DIF",
+        6 => "CF_TIFF",
+        7 => "CF_OEMTEXT",
+        8 => "CF_DIB",
+        9 => "CF_PALETTE",
+        10 => "CF_PENDATA",
+        11 => "CF_RIFF",
+        12 => "CF_WAVE",
+        13 => "CF_UNICODETEXT",
+        14 => "CF_ENHMETAFILE",
+        15 => "CF_HDROP",
+        16 => "CF_LOCALE",
+        17 => "CF_DIBV5",
+        0x0080 => "CF_OWNERDISPLAY",
+        0x0081 => "CF_DSPTEXT",
+        0x0082 => "CF_DSPBITMAP",
+        0x0083 => "CF_DSPMETAFILEPICT",
+        0x008E => "CF_DSPENHMETAFILE",
+        _ => return None,
+    };
+    Some(name)
+}
+
 #[cfg(windows)]
 struct ClipboardGuard;
 
@@ -401,22 +479,75 @@ fn fix_image_urls(html: &str) -> String {
 
 // 自动判断文本/图片并设置剪贴板内容
 pub fn set_clipboard_content(content: String) -> Result<(), String> {
-    set_clipboard_content_internal(content, true)
+    with_set_retry(|| set_clipboard_content_internal(content.clone(), true))
 }
 
 // 设置剪贴板内容（包含HTML格式）
 pub fn set_clipboard_content_with_html(content: String, html_content: Option<String>) -> Result<(), String> {
-    set_clipboard_content_with_html_internal(content, html_content, true)
+    with_set_retry(|| set_clipboard_content_with_html_internal(content.clone(), html_content.clone(), true))
 }
 
 // 设置剪贴板内容但不添加到历史记录（用于避免重复添加）
 pub fn set_clipboard_content_no_history(content: String) -> Result<(), String> {
-    set_clipboard_content_internal(content, false)
+    with_set_retry(|| set_clipboard_content_internal(content.clone(), false))
 }
 
 // 设置剪贴板内容但不添加到历史记录（包含HTML格式）
 pub fn set_clipboard_content_no_history_with_html(content: String, html_content: Option<String>) -> Result<(), String> {
-    set_clipboard_content_with_html_internal(content, html_content, false)
+    with_set_retry(|| set_clipboard_content_with_html_internal(content.clone(), html_content.clone(), false))
+}
+
+// 异步调用方使用：重试等待放到阻塞线程中，不占用异步运行时
+pub async fn set_clipboard_content_no_history_async(content: String) -> Result<(), String> {
+    run_set_blocking(move || set_clipboard_content_no_history(content)).await
+}
+
+// 异步调用方使用（包含HTML格式）
+pub async fn set_clipboard_content_no_history_with_html_async(
+    content: String,
+    html_content: Option<String>,
+) -> Result<(), String> {
+    run_set_blocking(move || set_clipboard_content_no_history_with_html(content, html_content)).await
+}
+
+async fn run_set_blocking<F>(set: F) -> Result<(), String>
+where
+    F: FnOnce() -> Result<(), String> + Send + 'static,
+{
+    tokio::task::spawn_blocking(set)
+        .await
+        .map_err(|e| format!("设置剪贴板任务失败: {}", e))?
+}
+
+// 是否为剪贴板被其他应用占用导致的失败（只有这类错误值得重试）
+fn is_clipboard_busy_error(error: &str) -> bool {
+    ["打开剪贴板失败", "获取剪贴板失败", "设置剪贴板文本失败", "清空剪贴板失败"]
+        .iter()
+        .any(|prefix| error.starts_with(prefix))
+}
+
+// 剪贴板可能被其他应用短暂占用，失败后按指数退避重试
+fn with_set_retry<F>(mut set: F) -> Result<(), String>
+where
+    F: FnMut() -> Result<(), String>,
+{
+    let settings = crate::settings::get_global_settings();
+    let retries = settings.clipboard_set_retries;
+    let mut delay_ms = settings.clipboard_set_retry_delay_ms as u64;
+
+    let mut attempt = 0;
+    loop {
+        match set() {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt < retries && is_clipboard_busy_error(&e) => {
+                attempt += 1;
+                log::debug!("设置剪贴板失败，{}ms 后第 {} 次重试: {}", delay_ms, attempt, e);
+                std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+                delay_ms *= 2;
+            }
+            Err(e) => return Err(e),
+        }
+    }
 }
 
 // 内部函数：设置剪贴板内容（包含HTML格式）
@@ -493,3 +624,16 @@ fn set_clipboard_content_internal(content: String, add_to_history: bool) -> Resu
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_busy_errors_are_retried() {
+        assert!(is_clipboard_busy_error("打开剪贴板失败"));
+        assert!(is_clipboard_busy_error("设置剪贴板文本失败: clipboard occupied"));
+        assert!(!is_clipboard_busy_error("图片文件不存在"));
+        assert!(!is_clipboard_busy_error("Base64解码失败: invalid"));
+    }
+}
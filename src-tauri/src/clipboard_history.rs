@@ -1,7 +1,7 @@
 use crate::database;
 use crate::image_manager::get_image_manager;
 use once_cell::sync::Lazy;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::RwLock;
 
 // 使用database模块中的ClipboardItem结构
@@ -22,53 +22,182 @@ static IGNORE_DUPLICATES: AtomicBool = AtomicBool::new(false);
 // 保存图片控制
 static SAVE_IMAGES: AtomicBool = AtomicBool::new(true);
 
+// 本次运行最近一次记录到历史的时间（秒级时间戳，0 表示尚未记录）
+static LAST_CAPTURE_TIME: AtomicI64 = AtomicI64::new(0);
+
+// 剪贴板监听状态（用于前端状态面板）
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MonitorStatus {
+    pub enabled: bool,
+    pub last_capture_time: Option<i64>,
+    pub total_items: usize,
+    pub ignore_duplicates: bool,
+    pub save_images: bool,
+}
+
+// 记录最近一次成功添加历史的时间
+fn mark_captured() {
+    LAST_CAPTURE_TIME.store(chrono::Local::now().timestamp(), Ordering::Relaxed);
+}
+
+// 获取剪贴板监听状态，只读取内存状态和一次计数查询
+pub fn get_monitor_status() -> Result<MonitorStatus, String> {
+    let last_capture_time = match LAST_CAPTURE_TIME.load(Ordering::Relaxed) {
+        0 => None,
+        time => Some(time),
+    };
+
+    Ok(MonitorStatus {
+        enabled: is_monitoring_enabled(),
+        last_capture_time,
+        total_items: database::get_clipboard_count()?,
+        ignore_duplicates: IGNORE_DUPLICATES.load(Ordering::Relaxed),
+        save_images: is_save_images(),
+    })
+}
+
 // 从数据库加载历史记录（数据库模式下不需要显式加载）
 pub fn load_history() {
-    println!("剪贴板历史记录将从数据库动态加载");
+    log::debug!("剪贴板历史记录将从数据库动态加载");
 }
 
 // 监听剪贴板变化并添加到历史记录
 pub fn add_to_history(text: String) {
     if !MONITORING_ENABLED.load(Ordering::Relaxed) {
-        println!("剪贴板监听已禁用，跳过添加历史记录");
+        log::debug!("剪贴板监听已禁用，跳过添加历史记录");
         return;
     }
 
-    // 过滤空白内容：检查去除空白字符后是否为空
-    if text.trim().is_empty() {
-        println!("跳过空白内容，不添加到历史记录");
+    if !should_record(&text) {
+        log::debug!("跳过空白内容，不添加到历史记录");
         return;
     }
 
-    if let Err(e) = database::add_clipboard_item_smart(text, None) {
-        println!("添加剪贴板历史失败: {}", e);
+    if !is_content_type_recorded(&text) {
+        return;
+    }
+
+    let text = match apply_capture_size_limits(text) {
+        Some(text) => text,
+        None => return,
+    };
+
+    match database::add_clipboard_item_smart(text, None) {
+        Ok(_) => mark_captured(),
+        Err(e) => log::warn!("添加剪贴板历史失败: {}", e),
     }
 }
 
+// 按设置限制记录内容的大小，返回 None 表示跳过该内容
+fn apply_capture_size_limits(text: String) -> Option<String> {
+    let settings = crate::settings::get_global_settings();
+
+    // 图片：超过大小限制直接跳过
+    if text.starts_with("image:") || text.starts_with("data:image/") {
+        if settings.max_image_bytes == 0 {
+            return Some(text);
+        }
+
+        let image_bytes = if let Some(image_id) = text.strip_prefix("image:") {
+            get_image_manager()
+                .ok()
+                .and_then(|manager| manager.lock().ok()?.get_image_file_path(image_id).ok())
+                .and_then(|path| std::fs::metadata(path).ok())
+                .map(|meta| meta.len())
+                .unwrap_or(0)
+        } else {
+            // data URL 按 Base64 长度估算原始大小
+            (text.len() as u64) * 3 / 4
+        };
+
+        if image_bytes > settings.max_image_bytes {
+            log::debug!(
+                "图片大小 {} 字节超过限制 {} 字节，跳过添加历史记录",
+                image_bytes, settings.max_image_bytes
+            );
+            return None;
+        }
+        return Some(text);
+    }
+
+    // 文件列表不受文本长度限制
+    if text.starts_with("files:") || settings.max_text_length == 0 {
+        return Some(text);
+    }
+
+    let max_chars = settings.max_text_length as usize;
+    if text.chars().count() <= max_chars {
+        return Some(text);
+    }
+
+    if settings.oversized_text_action == "skip" {
+        log::debug!("文本长度超过限制 {} 字符，跳过添加历史记录", max_chars);
+        None
+    } else {
+        log::info!("文本长度超过限制 {} 字符，已截断", max_chars);
+        Some(text.chars().take(max_chars).collect())
+    }
+}
 
 
+
+// 过滤空白内容：空内容始终跳过，仅含空白字符的内容按设置决定是否记录
+pub fn should_record(content: &str) -> bool {
+    if content.is_empty() {
+        return false;
+    }
+    if content.trim().is_empty() {
+        return crate::settings::get_global_settings().record_whitespace_only;
+    }
+    true
+}
+
+// 按内容类型检查是否需要记录（文本 / 图片 / 文件可分别关闭）
+pub fn is_content_type_recorded(content: &str) -> bool {
+    let settings = crate::settings::get_global_settings();
+    if content.starts_with("files:") {
+        settings.monitor_record_files
+    } else if content.starts_with("data:image/") || content.starts_with("image:") {
+        settings.monitor_record_images && is_save_images()
+    } else {
+        settings.monitor_record_text
+    }
+}
+
 // 添加到历史记录并返回是否真正添加了新内容，支持HTML内容，控制是否移动重复内容
 pub fn add_to_history_with_check_and_move_html(text: String, html_content: Option<String>, move_duplicates: bool) -> bool {
     if !MONITORING_ENABLED.load(Ordering::Relaxed) {
-        println!("剪贴板监听已禁用，跳过添加历史记录");
+        log::debug!("剪贴板监听已禁用，跳过添加历史记录");
         return false;
     }
 
-    // 过滤空白内容：检查去除空白字符后是否为空
-    if text.trim().is_empty() {
-        println!("跳过空白内容，不添加到历史记录");
+    if !should_record(&text) {
+        log::debug!("跳过空白内容，不添加到历史记录");
         return false;
     }
 
-    // 检查是否已存在相同内容
-    match database::clipboard_item_exists(&text) {
+    if !is_content_type_recorded(&text) {
+        return false;
+    }
+
+    // 超出大小限制的内容截断或跳过（截断后 HTML 不再对应，一并丢弃）
+    let original_len = text.len();
+    let text = match apply_capture_size_limits(text) {
+        Some(text) => text,
+        None => return false,
+    };
+    let html_content = if text.len() != original_len { None } else { html_content };
+
+    // 检查是否已存在相同内容（按去重模式比较）
+    match find_duplicate_item(&text) {
         Ok(Some(existing_id)) => {
             if move_duplicates {
                 // 移动到最前面（复制操作）
                 if let Err(e) = database::move_clipboard_item_to_front(existing_id) {
-                    println!("移动剪贴板项目到前面失败: {}", e);
+                    log::warn!("移动剪贴板项目到前面失败: {}", e);
                     return false;
                 }
+                mark_captured();
                 true // 移动了位置，算作添加了新内容
             } else {
                 // 不移动重复内容（粘贴操作）
@@ -80,30 +209,105 @@ pub fn add_to_history_with_check_and_move_html(text: String, html_content: Optio
             let result = database::add_clipboard_item_smart(text, html_content);
             
             if let Err(e) = result {
-                println!("添加剪贴板历史失败: {}", e);
+                log::warn!("添加剪贴板历史失败: {}", e);
                 return false;
             }
+            mark_captured();
+
+            // 写入新记录的去重键
+            if let Err(e) = refresh_dedup_keys() {
+                log::warn!("更新去重键失败: {}", e);
+            }
 
             // 限制历史记录数量
-            let limit = *HISTORY_LIMIT.read().unwrap();
-            if let Err(e) = database::limit_clipboard_history(limit) {
-                println!("限制剪贴板历史数量失败: {}", e);
+            if let Err(e) = enforce_history_limits() {
+                log::warn!("限制剪贴板历史数量失败: {}", e);
             }
 
             true // 添加了新内容
         }
         Err(e) => {
-            println!("检查剪贴板项目是否存在失败: {}", e);
+            log::warn!("检查剪贴板项目是否存在失败: {}", e);
             false
         }
     }
 }
 
+// 按设置的去重模式查找已存在的相同内容
+fn find_duplicate_item(text: &str) -> Result<Option<i64>, String> {
+    let mode = crate::settings::get_global_settings().dedup_mode;
+    if mode == "exact" || !is_plain_text_content(text) {
+        return database::clipboard_item_exists(text);
+    }
+
+    // 其他途径新增或修改的记录在这里补齐去重键
+    refresh_dedup_keys()?;
+    database::find_clipboard_item_by_dedup_key(&dedup_key(text, &mode))
+}
+
+// 按当前去重模式补齐历史记录的去重键（exact 模式直接比较原文，不需要）
+fn refresh_dedup_keys() -> Result<(), String> {
+    let mode = crate::settings::get_global_settings().dedup_mode;
+    if mode == "exact" {
+        return Ok(());
+    }
+    database::update_dedup_keys(&mode, |content| stored_dedup_key(content, &mode))
+}
+
+// 写入数据库的去重键，图片、文件等特殊内容不参与
+fn stored_dedup_key(content: &str, mode: &str) -> Option<String> {
+    is_plain_text_content(content).then(|| dedup_key(content, mode))
+}
+
+// 图片、文件等特殊内容始终按原文比较
+fn is_plain_text_content(content: &str) -> bool {
+    !(content.starts_with("image:") || content.starts_with("data:image/") || content.starts_with("files:"))
+}
+
+// 生成去重比较用的键：exact 原文，trimmed 去除首尾空白，normalized 额外合并内部空白
+pub fn dedup_key(text: &str, mode: &str) -> String {
+    match mode {
+        "trimmed" => text.trim().to_string(),
+        "normalized" => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        _ => text.to_string(),
+    }
+}
+
+// 清理历史中已存在的重复内容，保留每组中最新的一条（其余移入回收站），返回删除数量
+pub fn deduplicate_history() -> Result<usize, String> {
+    let mode = crate::settings::get_global_settings().dedup_mode;
+    // 需按完整内容比较，历史列表中的长文本已被截断
+    let items = database::get_clipboard_contents()?;
+
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicate_ids = Vec::new();
+    for (id, content, pinned) in items {
+        let key = if is_plain_text_content(&content) {
+            dedup_key(&content, &mode)
+        } else {
+            content
+        };
+
+        // 固定的项目不参与删除
+        if !seen.insert(key) && !pinned {
+            duplicate_ids.push(id);
+        }
+    }
+
+    if duplicate_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let removed = database::trash_clipboard_items(&duplicate_ids)?;
+    cleanup_orphaned_images();
+    log::info!("已清理 {} 条重复的剪贴板记录", removed);
+    Ok(removed)
+}
+
 // 检查内容是否在历史记录中且需要移动到第一位
 pub fn move_to_front_if_exists(text: String) -> bool {
-    // 过滤空白内容：检查去除空白字符后是否为空
-    if text.trim().is_empty() {
-        println!("跳过空白内容，不移动到前面");
+    if !should_record(&text) {
+        log::debug!("跳过空白内容，不移动到前面");
         return false;
     }
 
@@ -119,20 +323,20 @@ pub fn move_to_front_if_exists(text: String) -> bool {
 
                     // 移动到第一位
                     if let Err(e) = database::move_clipboard_item_to_front(existing_id) {
-                        println!("移动剪贴板项目到前面失败: {}", e);
+                        log::warn!("移动剪贴板项目到前面失败: {}", e);
                         return false;
                     }
                     true
                 }
                 Err(e) => {
-                    println!("获取剪贴板历史失败: {}", e);
+                    log::warn!("获取剪贴板历史失败: {}", e);
                     false
                 }
             }
         }
         Ok(None) => false,
         Err(e) => {
-            println!("检查剪贴板项目是否存在失败: {}", e);
+            log::warn!("检查剪贴板项目是否存在失败: {}", e);
             false
         }
     }
@@ -143,16 +347,45 @@ pub fn get_history_limit() -> usize {
     *HISTORY_LIMIT.read().unwrap()
 }
 
+// 将一组文件路径作为一条文件类型的历史记录加入，返回实际记录的文件数量（超出单条上限时截断）
+pub fn add_files_entry(paths: &[String]) -> Result<usize, String> {
+    let max_files = crate::settings::get_global_settings().max_files_per_entry as usize;
+    let file_data = crate::file_handler::FileClipboardData::from_paths(paths, max_files)?;
+    let json_str = serde_json::to_string(&file_data)
+        .map_err(|e| format!("序列化文件信息失败: {}", e))?;
+
+    database::add_clipboard_item_smart(format!("files:{}", json_str), None)?;
+    if let Err(e) = enforce_history_limits() {
+        log::warn!("限制历史记录数量失败: {}", e);
+    }
+
+    Ok(file_data.files.len())
+}
+
+// 按历史数量限制和图片数量限制清理记录（图片数量限制为 0 时与其他内容共用限制）
+pub(crate) fn enforce_history_limits() -> Result<(), String> {
+    let limit = *HISTORY_LIMIT.read().unwrap();
+    let image_limit = crate::settings::get_global_settings().image_history_limit as usize;
+
+    database::limit_clipboard_history(limit, image_limit > 0)?;
+    if image_limit > 0 {
+        database::limit_clipboard_images(image_limit)?;
+    }
+    Ok(())
+}
+
 // 设置历史记录数量限制
 pub fn set_history_limit(limit: usize) {
-    let mut history_limit = HISTORY_LIMIT.write().unwrap();
-    *history_limit = limit;
+    {
+        let mut history_limit = HISTORY_LIMIT.write().unwrap();
+        *history_limit = limit;
+    }
 
     // 在数据库中限制历史记录数量
-    if let Err(e) = database::limit_clipboard_history(limit) {
-        println!("数据库限制操作失败: {}", e);
+    if let Err(e) = enforce_history_limits() {
+        log::warn!("数据库限制操作失败: {}", e);
     } else {
-        println!("历史记录数量限制已设置为: {}", limit);
+        log::debug!("历史记录数量限制已设置为: {}", limit);
     }
 }
 
@@ -161,6 +394,18 @@ pub fn move_item(from_index: usize, to_index: usize) -> Result<(), String> {
     let items =
         database::get_clipboard_history(None).map_err(|e| format!("获取剪贴板历史失败: {}", e))?;
 
+    // 最旧优先显示时，前端索引需换算为最新优先的索引
+    let (from_index, to_index) =
+        if crate::services::clipboard_service::ClipboardService::is_oldest_first() {
+            let shown = items.len().min(get_history_limit());
+            if from_index >= shown || to_index >= shown {
+                return Err(format!("索引 {} / {} 超出范围", from_index, to_index));
+            }
+            (shown - 1 - from_index, shown - 1 - to_index)
+        } else {
+            (from_index, to_index)
+        };
+
     if from_index >= items.len() {
         return Err(format!("源索引 {} 超出范围", from_index));
     }
@@ -201,18 +446,18 @@ pub fn is_monitoring_enabled() -> bool {
 // 设置忽略重复内容状态
 pub fn set_ignore_duplicates(enabled: bool) {
     IGNORE_DUPLICATES.store(enabled, Ordering::Relaxed);
-    println!("忽略重复内容设置: {}", enabled);
+    log::debug!("忽略重复内容设置: {}", enabled);
 }
 
 // 设置保存图片状态
 pub fn set_save_images(enabled: bool) {
     SAVE_IMAGES.store(enabled, Ordering::Relaxed);
-    println!("保存图片设置: {}", enabled);
+    log::debug!("保存图片设置: {}", enabled);
 }
 
-// 检查是否保存图片
+// 检查是否保存图片（临时模式下图片文件会写入磁盘，因此不记录图片）
 pub fn is_save_images() -> bool {
-    SAVE_IMAGES.load(Ordering::Relaxed)
+    SAVE_IMAGES.load(Ordering::Relaxed) && !crate::database::is_ephemeral_mode()
 }
 
 
@@ -224,7 +469,15 @@ pub fn clear_all() -> Result<(), String> {
     // 清理未使用的图片
     cleanup_orphaned_images();
 
-    println!("已清空所有剪贴板历史记录");
+    log::info!("已清空所有剪贴板历史记录");
+    Ok(())
+}
+
+// 清空未固定的剪贴板历史
+pub fn clear_unpinned() -> Result<(), String> {
+    database::clear_unpinned_clipboard_history()?;
+
+    log::info!("已清空未固定的剪贴板历史记录");
     Ok(())
 }
 
@@ -233,21 +486,25 @@ pub fn cleanup_orphaned_images() {
     // 收集所有正在使用的图片ID
     let mut used_image_ids = Vec::new();
     
-    // 从剪贴板历史中收集图片ID
-    if let Ok(clipboard_items) = database::get_clipboard_history(None) {
-        for item in clipboard_items {
-            // 从image_id字段收集（纯图片类型）
-            if item.content_type == crate::database::ContentType::Image {
-                if let Some(image_id) = item.image_id {
+    // 从剪贴板历史和回收站（仍可能被恢复）中收集图片ID，临时模式下也包含磁盘上的记录
+    match database::get_image_references() {
+        Ok(references) => {
+            for (image_id, html) in references {
+                if let Some(image_id) = image_id {
                     used_image_ids.push(image_id);
                 }
-            }
-            
-            // 从html_content字段中提取图片ID（富文本中的图片）
-            if let Some(html) = &item.html_content {
-                extract_image_ids_from_html(html, &mut used_image_ids);
+
+                // 从html_content字段中提取图片ID（富文本中的图片）
+                if let Some(html) = &html {
+                    extract_image_ids_from_html(html, &mut used_image_ids);
+                }
             }
         }
+        Err(e) => {
+            // 无法确定哪些图片仍在使用时不做清理
+            log::warn!("获取图片引用失败，跳过清理: {}", e);
+            return;
+        }
     }
     
     // 从常用文本中收集图片ID
@@ -272,9 +529,9 @@ pub fn cleanup_orphaned_images() {
     if let Ok(image_manager) = get_image_manager() {
         if let Ok(manager) = image_manager.lock() {
             if let Err(e) = manager.cleanup_unused_images(&used_image_ids) {
-                println!("清理未使用的图片失败: {}", e);
+                log::warn!("清理未使用的图片失败: {}", e);
             } else {
-                println!("已清理未使用的图片，保留 {} 个正在使用的图片", used_image_ids.len());
+                log::info!("已清理未使用的图片，保留 {} 个正在使用的图片", used_image_ids.len());
             }
         }
     }
@@ -303,3 +560,16 @@ fn extract_image_ids_from_html(html: &str, image_ids: &mut Vec<String>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_key() {
+        assert_eq!(dedup_key("foo ", "exact"), "foo ");
+        assert_eq!(dedup_key("  foo \n", "trimmed"), "foo");
+        assert_eq!(dedup_key("foo   bar\n\tbaz ", "normalized"), "foo bar baz");
+        assert_eq!(dedup_key(" a  b ", "trimmed"), "a  b");
+    }
+}
@@ -5,7 +5,7 @@ use std::sync::{
     Arc, Mutex,
 };
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
 
 use crate::clipboard_content::image_to_data_url;
@@ -24,6 +24,19 @@ use windows::Win32::System::DataExchange::{
 #[cfg(windows)]
 use windows::Win32::System::Memory::{GlobalLock, GlobalSize, GlobalUnlock};
 
+// 已知会抢占剪贴板监听的其他剪贴板管理器（进程名小写）
+const KNOWN_CLIPBOARD_MANAGERS: [(&str, &str); 9] = [
+    ("ditto.exe", "Ditto"),
+    ("clipboardfusion.exe", "ClipboardFusion"),
+    ("copyq.exe", "CopyQ"),
+    ("clipclip.exe", "ClipClip"),
+    ("clipmate.exe", "ClipMate"),
+    ("arsclip.exe", "ArsClip"),
+    ("clipdiary.exe", "Clipdiary"),
+    ("clipangel.exe", "ClipAngel"),
+    ("1clipboard.exe", "1Clipboard"),
+];
+
 // 监听器控制状态
 static MONITOR_RUNNING: AtomicBool = AtomicBool::new(false);
 static LAST_CLIPBOARD_CONTENT: Lazy<Arc<Mutex<String>>> =
@@ -32,6 +45,9 @@ static LAST_CLIPBOARD_CONTENT: Lazy<Arc<Mutex<String>>> =
 // 粘贴状态计数器
 static PASTING_COUNT: AtomicUsize = AtomicUsize::new(0);
 
+// 暂停记录计数器（程序模拟复制读取选中内容时不写入历史）
+static SUPPRESS_RECORD_COUNT: AtomicUsize = AtomicUsize::new(0);
+
 // 上次忽略的缓存文件路径 - 避免重复检测相同的缓存文件
 static LAST_IGNORED_CACHE_FILES: Lazy<Arc<Mutex<Vec<String>>>> =
     Lazy::new(|| Arc::new(Mutex::new(Vec::new())));
@@ -40,6 +56,13 @@ static LAST_IGNORED_CACHE_FILES: Lazy<Arc<Mutex<Vec<String>>>> =
 static LAST_FILE_PATHS: Lazy<Arc<Mutex<Vec<String>>>> =
     Lazy::new(|| Arc::new(Mutex::new(Vec::new())));
 
+// 远程桌面会话中重新读取剪贴板文本的最大次数
+const RDP_MAX_REREADS: usize = 3;
+
+// 复制通知节流
+const COPY_NOTIFICATION_INTERVAL: Duration = Duration::from_secs(2);
+static LAST_COPY_NOTIFICATION: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
 #[cfg(windows)]
 fn try_get_windows_clipboard_image() -> Option<arboard::ImageData<'static>> {
     unsafe {
@@ -125,7 +148,7 @@ fn process_png_data(data: &[u8]) -> Option<arboard::ImageData<'static>> {
             })
         }
         Err(e) => {
-            println!("解析PNG数据失败: {}", e);
+            log::warn!("解析PNG数据失败: {}", e);
             None
         }
     }
@@ -216,28 +239,125 @@ fn process_dib_data(data: &[u8]) -> Option<arboard::ImageData<'static>> {
 
 pub fn start_clipboard_monitor(app_handle: AppHandle) {
     if MONITOR_RUNNING.load(Ordering::Relaxed) {
-        println!("剪贴板监听器已在运行");
+        log::debug!("剪贴板监听器已在运行");
         return;
     }
 
     MONITOR_RUNNING.store(true, Ordering::Relaxed);
-    println!("启动剪贴板监听器");
+    log::info!("启动剪贴板监听器");
+
+    // 检测其他剪贴板管理器（延迟发送，确保前端已开始监听）
+    let conflict_app_handle = app_handle.clone();
+    thread::spawn(move || {
+        let conflicts = detect_clipboard_manager_conflicts();
+        if conflicts.is_empty() {
+            return;
+        }
+        log::warn!("检测到其他剪贴板管理器正在运行: {}", conflicts.join(", "));
+        thread::sleep(Duration::from_secs(3));
+
+        use tauri::Emitter;
+        for name in conflicts {
+            let _ = conflict_app_handle.emit(
+                "clipboard-manager-conflict",
+                serde_json::json!({ "name": name }),
+            );
+        }
+    });
 
     thread::spawn(move || {
         clipboard_monitor_loop(app_handle);
     });
 }
 
+// 检测正在运行的其他剪贴板管理器，返回其名称
+#[cfg(windows)]
+pub fn detect_clipboard_manager_conflicts() -> Vec<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::ProcessStatus::EnumProcesses;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    let mut process_ids = vec![0u32; 4096];
+    let mut bytes_returned = 0u32;
+    unsafe {
+        if EnumProcesses(
+            process_ids.as_mut_ptr(),
+            (process_ids.len() * std::mem::size_of::<u32>()) as u32,
+            &mut bytes_returned,
+        )
+        .is_err()
+        {
+            return Vec::new();
+        }
+    }
+    process_ids.truncate(bytes_returned as usize / std::mem::size_of::<u32>());
+
+    let mut found = Vec::new();
+    for process_id in process_ids {
+        if process_id == 0 {
+            continue;
+        }
+
+        let process_name = unsafe {
+            let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id) else {
+                continue;
+            };
+            let mut buffer = [0u16; 260];
+            let mut len = buffer.len() as u32;
+            let result = QueryFullProcessImageNameW(
+                handle,
+                PROCESS_NAME_WIN32,
+                PWSTR(buffer.as_mut_ptr()),
+                &mut len,
+            );
+            let _ = CloseHandle(handle);
+            if result.is_err() {
+                continue;
+            }
+            String::from_utf16_lossy(&buffer[..len as usize])
+        };
+
+        let file_name = process_name
+            .rsplit(['\\', '/'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+
+        if let Some((_, display_name)) = KNOWN_CLIPBOARD_MANAGERS
+            .iter()
+            .find(|(exe, _)| *exe == file_name)
+        {
+            if !found.iter().any(|name| name == display_name) {
+                found.push(display_name.to_string());
+            }
+        }
+    }
+
+    found
+}
+
+#[cfg(not(windows))]
+pub fn detect_clipboard_manager_conflicts() -> Vec<String> {
+    Vec::new()
+}
+
 fn clipboard_monitor_loop(app_handle: AppHandle) {
     let mut clipboard = match Clipboard::new() {
         Ok(cb) => cb,
         Err(e) => {
-            println!("创建剪贴板实例失败: {}", e);
+            log::warn!("创建剪贴板实例失败: {}", e);
             MONITOR_RUNNING.store(false, Ordering::Relaxed);
             return;
         }
     };
 
+    // 防抖中的内容及首次出现时间
+    let mut pending_change: Option<(String, Instant)> = None;
+
     while MONITOR_RUNNING.load(Ordering::Relaxed) {
         // 检查剪贴板监听是否被禁用
         if !clipboard_history::is_monitoring_enabled() {
@@ -253,58 +373,73 @@ fn clipboard_monitor_loop(app_handle: AppHandle) {
 
         let current_content = get_clipboard_content(&mut clipboard);
 
-        if let Some((content, html_content)) = current_content {
-            let mut last_content = LAST_CLIPBOARD_CONTENT.lock().unwrap();
-            if *last_content != content {
-
-                *last_content = content.clone();
-                drop(last_content); 
+        if let Some((mut content, mut html_content)) = current_content {
+            // 暂停记录期间只更新基准内容
+            if SUPPRESS_RECORD_COUNT.load(Ordering::Relaxed) > 0 {
+                *LAST_CLIPBOARD_CONTENT.lock().unwrap() = content;
+                pending_change = None;
+                thread::sleep(Duration::from_millis(200));
+                continue;
+            }
 
-                let is_existing = matches!(
-                    crate::database::clipboard_item_exists(&content),
-                    Ok(Some(_))
-                );
+            // 远程桌面下文本为延迟渲染，首次读取可能是不完整的值
+            let rdp_delay_ms = crate::settings::get_global_settings().rdp_clipboard_delay_ms;
+            if rdp_delay_ms > 0
+                && is_plain_text_content(&content)
+                && *LAST_CLIPBOARD_CONTENT.lock().unwrap() != content
+                && crate::utils::window_utils::is_remote_session()
+            {
+                (content, html_content) =
+                    settle_remote_clipboard_text(&mut clipboard, content, html_content, rdp_delay_ms);
+            }
 
-                let move_duplicates = !is_pasting_internal();
-                let was_added =
-                    clipboard_history::add_to_history_with_check_and_move_html(content, html_content, move_duplicates);
+            // 防抖：内容保持不变达到设定时长后才记录，连续快速变化只保留最终值
+            let debounce_ms = crate::settings::get_global_settings().monitor_debounce_ms;
+            if debounce_ms > 0 && *LAST_CLIPBOARD_CONTENT.lock().unwrap() != content {
+                let is_stable = match &pending_change {
+                    Some((pending, since)) if *pending == content => {
+                        since.elapsed() >= Duration::from_millis(debounce_ms as u64)
+                    }
+                    _ => {
+                        pending_change = Some((content.clone(), Instant::now()));
+                        false
+                    }
+                };
 
-                if was_added && !is_pasting_internal() && !is_existing {
-                    crate::sound_manager::play_copy_sound();
+                if !is_stable {
+                    thread::sleep(Duration::from_millis(50));
+                    continue;
                 }
+                pending_change = None;
+            }
 
-                if was_added {
-                    if let Ok(items) = crate::database::get_clipboard_history(Some(1)) {
-                        if let Some(latest_item) = items.first() {
-                            use tauri::Emitter;
-                            #[derive(Clone, serde::Serialize)]
-                            struct ClipboardUpdatePayload {
-                                item: crate::database::ClipboardItem,
-                                is_new: bool,
-                            }
-                            
-                            if is_existing {
-                                let payload = ClipboardUpdatePayload {
-                                    item: latest_item.clone(),
-                                    is_new: false,
-                                };
-                                
-                                if let Err(e) = app_handle.emit("clipboard-item-moved", payload) {
-                                    println!("发射剪贴板移动事件失败: {}", e);
-                                }
-                            } else {
-                                let payload = ClipboardUpdatePayload {
-                                    item: latest_item.clone(),
-                                    is_new: true,
-                                };
-                                
-                                if let Err(e) = app_handle.emit("clipboard-item-added", payload) {
-                                    println!("发射剪贴板新增事件失败: {}", e);
-                                }
-                            }
+            let mut last_content = LAST_CLIPBOARD_CONTENT.lock().unwrap();
+            if *last_content != content {
+
+                // 追加模式：把新复制的文本拼接到上一条文本之后，并写回剪贴板
+                let (content, html_content) = match build_appended_copy(&last_content, &content) {
+                    Some(combined) => match crate::clipboard_content::set_clipboard_content_no_history(combined.clone()) {
+                        Ok(()) => (combined, None),
+                        Err(e) => {
+                            log::warn!("追加到剪贴板失败: {}", e);
+                            (content, html_content)
                         }
+                    },
+                    None => (content, html_content),
+                };
+
+                *last_content = content.clone();
+                drop(last_content); 
+
+                // 文本和图片同时存在且设置为都记录时，先记录图片，使文本位于最前
+                let is_plain_text = !content.starts_with("image:") && !content.starts_with("files:");
+                if is_plain_text && crate::settings::get_global_settings().mixed_content_preference == "both" {
+                    if let Some((image_content, _)) = get_clipboard_image(&mut clipboard) {
+                        record_clipboard_content(&app_handle, image_content, None);
                     }
                 }
+
+                record_clipboard_content(&app_handle, content, html_content);
             }
         }
 
@@ -385,8 +520,168 @@ fn extract_html_fragment(html_format: &str) -> String {
     html_format.to_string()
 }
 
+// 等待远程桌面完成延迟渲染后重新读取文本，直到连续两次读取一致
+fn settle_remote_clipboard_text(
+    clipboard: &mut Clipboard,
+    mut content: String,
+    mut html_content: Option<String>,
+    delay_ms: u32,
+) -> (String, Option<String>) {
+    for _ in 0..RDP_MAX_REREADS {
+        thread::sleep(Duration::from_millis(delay_ms as u64));
+
+        let text = match clipboard.get_text() {
+            Ok(text) if clipboard_history::should_record(&text) => text,
+            _ => continue,
+        };
+        if text == content {
+            break;
+        }
+
+        content = text;
+        #[cfg(windows)]
+        let html = try_get_windows_clipboard_html();
+        #[cfg(not(windows))]
+        let html = None;
+        html_content = html;
+    }
+
+    (content, html_content)
+}
+
+// 开启追加模式或复制时按住追加修饰键时，返回拼接后的文本
+fn build_appended_copy(previous: &str, content: &str) -> Option<String> {
+    if is_pasting_internal() || !is_plain_text_content(previous) || !is_plain_text_content(content) {
+        return None;
+    }
+
+    let settings = crate::settings::get_global_settings();
+    let (_, alt, shift, win) = crate::input_monitor::get_modifier_keys_state();
+    let modifier_held = match settings.append_modifier.as_str() {
+        "Alt" => alt,
+        "Shift" => shift,
+        "Win" => win,
+        _ => false,
+    };
+
+    if !settings.append_mode && !modifier_held {
+        return None;
+    }
+
+    Some(join_appended_text(previous, content, &settings.append_separator))
+}
+
+// 是否为纯文本内容（非图片、非文件）
+fn is_plain_text_content(content: &str) -> bool {
+    !content.is_empty() && !content.starts_with("image:") && !content.starts_with("files:")
+}
+
+// 用分隔符拼接追加的文本，前一条为空时直接返回追加内容
+pub fn join_appended_text(previous: &str, addition: &str, separator: &str) -> String {
+    if previous.is_empty() {
+        return addition.to_string();
+    }
+    format!("{}{}{}", previous, separator, addition)
+}
+
+// 将文本追加到当前剪贴板文本之后，并记录拼接结果
+pub fn append_text_to_clipboard(app_handle: &AppHandle, text: String) -> Result<String, String> {
+    if text.is_empty() {
+        return Err("追加的文本不能为空".to_string());
+    }
+
+    let current = crate::services::clipboard_service::ClipboardService::get_text().unwrap_or_default();
+    let separator = crate::settings::get_global_settings().append_separator;
+    let combined = join_appended_text(&current, &text, &separator);
+
+    crate::clipboard_content::set_clipboard_content_no_history(combined.clone())?;
+    initialize_last_content(combined.clone());
+    record_clipboard_content(app_handle, combined.clone(), None);
+
+    Ok(combined)
+}
+
+// 将剪贴板内容写入历史并通知前端
+fn record_clipboard_content(app_handle: &AppHandle, content: String, html_content: Option<String>) {
+    // 复制发生时的前台应用即内容来源（粘贴产生的变化不记录来源）
+    let source_app = if is_pasting_internal() {
+        None
+    } else {
+        crate::utils::window_utils::get_active_window_process_name()
+    };
+
+    let is_existing = matches!(
+        crate::database::clipboard_item_exists(&content),
+        Ok(Some(_))
+    );
+
+    let move_duplicates = !is_pasting_internal();
+    let was_added =
+        clipboard_history::add_to_history_with_check_and_move_html(content, html_content, move_duplicates);
+
+    if was_added && !is_pasting_internal() && !is_existing {
+        crate::sound_manager::play_copy_sound();
+    }
+
+    if was_added {
+        if let Ok(items) = crate::database::get_clipboard_history(Some(1)) {
+            if let Some(latest_item) = items.first() {
+                // 外部复制的内容成为相对历史粘贴的新基准
+                if !is_pasting_internal() {
+                    crate::services::paste_service::set_current_history_item_id(latest_item.id);
+                }
+
+                if let Some(source_app) = source_app.as_deref() {
+                    if let Err(e) = crate::database::set_clipboard_item_source(latest_item.id, Some(source_app)) {
+                        log::warn!("记录剪贴板来源应用失败: {}", e);
+                    }
+                }
+
+                use tauri::Emitter;
+                #[derive(Clone, serde::Serialize)]
+                struct ClipboardUpdatePayload {
+                    item: crate::database::ClipboardItem,
+                    is_new: bool,
+                }
+                
+                if is_existing {
+                    let payload = ClipboardUpdatePayload {
+                        item: latest_item.clone(),
+                        is_new: false,
+                    };
+                    
+                    if let Err(e) = app_handle.emit("clipboard-item-moved", payload) {
+                        log::warn!("发射剪贴板移动事件失败: {}", e);
+                    }
+                } else {
+                    let payload = ClipboardUpdatePayload {
+                        item: latest_item.clone(),
+                        is_new: true,
+                    };
+                    
+                    if let Err(e) = app_handle.emit("clipboard-item-added", payload) {
+                        log::warn!("发射剪贴板新增事件失败: {}", e);
+                    }
+
+                    if !is_pasting_internal() {
+                        show_copy_notification(app_handle, &latest_item.content);
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn get_clipboard_content(clipboard: &mut Clipboard) -> Option<(String, Option<String>)> {
-    if let Ok(file_paths) = crate::file_handler::get_clipboard_files() {
+    let settings = crate::settings::get_global_settings();
+
+    // 关闭文件记录时不再读取文件列表，直接按文本/图片处理
+    let file_paths = if settings.monitor_record_files {
+        crate::file_handler::get_clipboard_files()
+    } else {
+        Ok(Vec::new())
+    };
+    if let Ok(file_paths) = file_paths {
         if !file_paths.is_empty() {
             let all_from_cache = file_paths.iter().all(|path| is_from_image_cache(path));
 
@@ -417,19 +712,11 @@ fn get_clipboard_content(clipboard: &mut Clipboard) -> Option<(String, Option<St
                 *last_paths = file_paths.clone();
             }
 
-            let mut file_infos = Vec::new();
-            for path in &file_paths {
-                if let Ok(file_info) = crate::file_handler::get_file_info(path) {
-                    file_infos.push(file_info);
-                }
-            }
-
-            if !file_infos.is_empty() {
-                let file_data = crate::file_handler::FileClipboardData {
-                    files: file_infos,
-                    operation: "copy".to_string(),
-                };
-
+            // 文件数量超过上限时只记录前一部分，避免误复制大量文件时卡顿
+            if let Ok(file_data) = crate::file_handler::FileClipboardData::from_paths(
+                &file_paths,
+                settings.max_files_per_entry as usize,
+            ) {
                 if let Ok(json_str) = serde_json::to_string(&file_data) {
                     return Some((format!("files:{}", json_str), None));
                 }
@@ -437,8 +724,21 @@ fn get_clipboard_content(clipboard: &mut Clipboard) -> Option<(String, Option<St
         }
     }
 
-    if let Ok(text) = clipboard.get_text() {
-        if !text.is_empty() && !text.trim().is_empty() {
+    // 同时存在文本和图片时，按设置决定优先记录哪一种
+    let prefer_image = settings.mixed_content_preference == "image";
+    if prefer_image {
+        if let Some(image_content) = get_clipboard_image(clipboard) {
+            return Some(image_content);
+        }
+    }
+
+    let text = if settings.monitor_record_text {
+        clipboard.get_text().ok()
+    } else {
+        None
+    };
+    if let Some(text) = text {
+        if clipboard_history::should_record(&text) {
             if let Ok(mut last_paths) = LAST_FILE_PATHS.lock() {
                 last_paths.clear();
             }
@@ -452,21 +752,32 @@ fn get_clipboard_content(clipboard: &mut Clipboard) -> Option<(String, Option<St
         }
     }
 
-    if clipboard_history::is_save_images() {
-        #[cfg(windows)]
-        if let Some(img) = try_get_windows_clipboard_image() {
-            if let Ok(mut last_paths) = LAST_FILE_PATHS.lock() {
-                last_paths.clear();
-            }
-            return save_image_optimized(&img);
+    if prefer_image {
+        return None;
+    }
+    get_clipboard_image(clipboard)
+}
+
+fn get_clipboard_image(clipboard: &mut Clipboard) -> Option<(String, Option<String>)> {
+    if !clipboard_history::is_save_images()
+        || !crate::settings::get_global_settings().monitor_record_images
+    {
+        return None;
+    }
+
+    #[cfg(windows)]
+    if let Some(img) = try_get_windows_clipboard_image() {
+        if let Ok(mut last_paths) = LAST_FILE_PATHS.lock() {
+            last_paths.clear();
         }
+        return save_image_optimized(&img);
+    }
 
-        if let Ok(img) = clipboard.get_image() {
-            if let Ok(mut last_paths) = LAST_FILE_PATHS.lock() {
-                last_paths.clear();
-            }
-            return save_image_optimized(&img);
+    if let Ok(img) = clipboard.get_image() {
+        if let Ok(mut last_paths) = LAST_FILE_PATHS.lock() {
+            last_paths.clear();
         }
+        return save_image_optimized(&img);
     }
 
     None
@@ -581,6 +892,37 @@ fn save_image_optimized(img: &arboard::ImageData) -> Option<(String, Option<Stri
     Some((image_to_data_url(img), None))
 }
 
+// 新记录提示通知（开启 notify_on_copy 时）
+fn show_copy_notification(app_handle: &AppHandle, content: &str) {
+    use tauri_plugin_notification::NotificationExt;
+
+    if !crate::settings::get_global_settings().notify_on_copy {
+        return;
+    }
+
+    {
+        let mut last = LAST_COPY_NOTIFICATION.lock().unwrap();
+        if last.map_or(false, |t| t.elapsed() < COPY_NOTIFICATION_INTERVAL) {
+            return;
+        }
+        *last = Some(Instant::now());
+    }
+
+    let summary = crate::utils::content_utils::generate_content_summary(content, 60);
+    let body = if content.starts_with("files:") {
+        format!("[文件] {}", summary)
+    } else {
+        summary
+    };
+
+    let _ = app_handle
+        .notification()
+        .builder()
+        .title("已记录到剪贴板历史")
+        .body(&body)
+        .show();
+}
+
 pub fn start_pasting_operation() {
     PASTING_COUNT.fetch_add(1, Ordering::Relaxed);
 }
@@ -589,6 +931,14 @@ pub fn end_pasting_operation() {
     PASTING_COUNT.fetch_sub(1, Ordering::Relaxed);
 }
 
+pub fn start_suppressing_record() {
+    SUPPRESS_RECORD_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn end_suppressing_record() {
+    SUPPRESS_RECORD_COUNT.fetch_sub(1, Ordering::Relaxed);
+}
+
 fn is_pasting_internal() -> bool {
     PASTING_COUNT.load(Ordering::Relaxed) > 0
 }
@@ -606,7 +956,7 @@ pub fn initialize_last_content(content: String) {
 pub fn initialize_clipboard_state() {
     if let Ok(mut clipboard) = Clipboard::new() {
         if let Some((content, html_content)) = get_clipboard_content(&mut clipboard) {
-            if !content.trim().is_empty() {
+            if clipboard_history::should_record(&content) {
                 let _was_added =
                     clipboard_history::add_to_history_with_check_and_move_html(content.clone(), html_content, false);
                 initialize_last_content(content);
@@ -616,9 +966,14 @@ pub fn initialize_clipboard_state() {
 }
 
 fn is_from_image_cache(file_path: &str) -> bool {
-    if file_path.contains("scrolling_screenshots") || file_path.contains("pin_images") {
+    if file_path.contains("screenshots") || file_path.contains("pin_images") {
         return false;
     }
+
+    // 以文件形式粘贴图片时导出的临时文件，会被定期清理，不应记录为新的文件条目
+    if crate::image_manager::is_paste_temp_file(file_path) {
+        return true;
+    }
     
     if let Some(app_data_dir) = dirs::data_local_dir() {
         let cache_dir = app_data_dir.join("quickclipboard").join("clipboard_images");
@@ -630,3 +985,15 @@ fn is_from_image_cache(file_path: &str) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_appended_text_uses_separator() {
+        assert_eq!(join_appended_text("a", "b", "\n"), "a\nb");
+        assert_eq!(join_appended_text("a", "b", ""), "ab");
+        assert_eq!(join_appended_text("", "b", ", "), "b");
+    }
+}
@@ -34,6 +34,12 @@ pub fn get_clipboard_text() -> Result<String, String> {
     crate::services::clipboard_service::ClipboardService::get_text()
 }
 
+// 列出系统剪贴板当前可用的格式
+#[tauri::command]
+pub fn get_clipboard_formats() -> Result<Vec<String>, String> {
+    crate::clipboard_content::get_clipboard_formats()
+}
+
 // 设置剪贴板文本
 #[tauri::command]
 pub fn set_clipboard_text(text: String) -> Result<(), String> {
@@ -47,6 +53,45 @@ pub fn set_clipboard_text_with_html(text: String, html: Option<String>) -> Resul
     crate::services::clipboard_service::ClipboardService::set_content_with_html(text, html_content)
 }
 
+// 比较两条文本历史记录的差异
+#[tauri::command]
+pub fn diff_clipboard_items(a: usize, b: usize) -> Result<Vec<crate::utils::text_diff::DiffChunk>, String> {
+    crate::services::clipboard_service::ClipboardService::diff_items(a, b)
+}
+
+// 粘贴当前剪贴板之前的第 N 条记录
+#[tauri::command]
+pub async fn paste_relative_history(offset: usize, window: WebviewWindow) -> Result<(), String> {
+    crate::services::paste_service::paste_relative_history(offset, window).await
+}
+
+// 将多行文本项目按行拆分为多条记录
+#[tauri::command]
+pub async fn split_clipboard_item_by_lines(
+    app: tauri::AppHandle,
+    index: usize,
+    delete_original: Option<bool>,
+) -> Result<Vec<i64>, String> {
+    let ids = crate::services::clipboard_service::ClipboardService::split_item_by_lines(
+        index,
+        delete_original.unwrap_or(false),
+    )?;
+    emit_clipboard_updated(app).await?;
+    Ok(ids)
+}
+
+// 将多张图片历史拼接为一张网格图，返回新记录ID
+#[tauri::command]
+pub async fn make_contact_sheet(
+    app: tauri::AppHandle,
+    indices: Vec<usize>,
+    columns: u32,
+) -> Result<i64, String> {
+    let id = crate::services::clipboard_service::ClipboardService::make_contact_sheet(indices, columns)?;
+    emit_clipboard_updated(app).await?;
+    Ok(id)
+}
+
 // 设置剪贴板图片
 #[tauri::command]
 pub fn set_clipboard_image(data_url: String) -> Result<(), String> {
@@ -59,10 +104,23 @@ pub fn move_clipboard_item_to_front(text: String) -> Result<(), String> {
     crate::services::clipboard_service::ClipboardService::move_to_front(text)
 }
 
-// 获取剪贴板历史
+// 获取剪贴板历史（按 history_order 设置排列，newest_first 为 true 时始终最新优先）
+#[tauri::command]
+pub fn get_clipboard_history(newest_first: Option<bool>) -> Vec<ClipboardItem> {
+    if newest_first.unwrap_or(false) {
+        crate::services::clipboard_service::ClipboardService::get_history_newest_first()
+    } else {
+        crate::services::clipboard_service::ClipboardService::get_history()
+    }
+}
+
+// 分页获取剪贴板历史（用于无限滚动）
 #[tauri::command]
-pub fn get_clipboard_history() -> Vec<ClipboardItem> {
-    crate::services::clipboard_service::ClipboardService::get_history()
+pub fn get_clipboard_history_page(
+    offset: usize,
+    count: usize,
+) -> Result<crate::services::clipboard_service::ClipboardHistoryPage, String> {
+    crate::services::clipboard_service::ClipboardService::get_history_page(offset, count)
 }
 
 // 刷新剪贴板监听函数，只添加新内容
@@ -122,9 +180,9 @@ pub fn get_quick_texts() -> Vec<FavoriteItem> {
 pub fn add_quick_text(
     title: String,
     content: String,
-    groupName: String,
+    groupName: Option<String>,
 ) -> Result<FavoriteItem, String> {
-    crate::services::quick_text_service::QuickTextService::add(title, content, groupName)
+    crate::services::quick_text_service::QuickTextService::add(title, content, groupName.unwrap_or_default())
 }
 
 // 更新常用文本
@@ -201,6 +259,12 @@ pub fn get_groups() -> Vec<GroupInfo> {
     crate::services::group_service::GroupService::get_all_groups()
 }
 
+// 获取最近使用的分组（用于"添加到分组"时优先展示）
+#[tauri::command]
+pub fn get_recent_groups(limit: Option<usize>) -> Vec<GroupInfo> {
+    crate::services::group_service::GroupService::get_recent_groups(limit.unwrap_or(5))
+}
+
 // 添加分组
 #[tauri::command]
 pub fn add_group(name: String, icon: String) -> Result<GroupInfo, String> {
@@ -245,6 +309,25 @@ pub async fn open_text_editor_window(app: tauri::AppHandle) -> Result<(), String
     crate::services::window_service::WindowService::open_text_editor_window(app).await
 }
 
+// 预览常用文本占位符展开结果（只读取剪贴板，不修改）
+#[tauri::command]
+pub fn preview_quick_text_expansion(content: String) -> String {
+    let clipboard_text = crate::services::clipboard_service::ClipboardService::get_text().unwrap_or_default();
+    crate::utils::placeholders::expand_placeholders(&content, &clipboard_text)
+}
+
+// 设置文本编辑窗口置顶
+#[tauri::command]
+pub fn set_editor_topmost(app: tauri::AppHandle, topmost: bool) -> Result<(), String> {
+    crate::services::window_service::WindowService::set_editor_topmost(&app, topmost)
+}
+
+// 获取文本编辑窗口置顶状态
+#[tauri::command]
+pub fn get_editor_topmost() -> bool {
+    crate::services::window_service::WindowService::is_editor_topmost()
+}
+
 // 获取设置
 #[tauri::command]
 pub fn get_settings() -> Result<serde_json::Value, String> {
@@ -273,6 +356,19 @@ pub fn save_settings(
     crate::settings::SettingsService::save_settings(app_handle, settings)
 }
 
+// 列出设置备份
+#[tauri::command]
+pub fn list_settings_backups() -> Result<Vec<String>, String> {
+    crate::settings::SettingsStorage::list_backups()
+}
+
+// 从备份恢复设置
+#[tauri::command]
+pub fn restore_settings_backup(app_handle: tauri::AppHandle, name: String) -> Result<serde_json::Value, String> {
+    crate::settings::SettingsService::restore_backup(app_handle, &name)?;
+    Ok(crate::settings::SettingsConverter::to_json(&crate::settings::get_global_settings()))
+}
+
 // 调试日志
 #[tauri::command]
 pub fn log_debug(message: String) {
@@ -339,8 +435,21 @@ pub fn get_active_sound_count() -> usize {
 
 // 从剪贴板历史添加到分组
 #[tauri::command]
-pub fn add_clipboard_to_group(index: usize, groupName: String) -> Result<FavoriteItem, String> {
-    crate::services::group_service::GroupService::add_clipboard_to_group(index, groupName)
+pub fn add_clipboard_to_group(id: i64, groupName: String) -> Result<FavoriteItem, String> {
+    crate::services::group_service::GroupService::add_clipboard_to_group(id, groupName)
+}
+
+// 批量将剪贴板历史移动到分组
+#[tauri::command]
+pub async fn move_clipboard_items_to_group(
+    app: tauri::AppHandle,
+    ids: Vec<i64>,
+    groupName: String,
+) -> Result<Vec<FavoriteItem>, String> {
+    let items = crate::services::group_service::GroupService::move_clipboard_items_to_group(&ids, groupName)?;
+    emit_clipboard_updated(app.clone()).await?;
+    emit_quick_texts_updated(app).await?;
+    Ok(items)
 }
 
 // 设置主窗口为置顶
@@ -368,6 +477,12 @@ pub fn get_image_file_path(content: String) -> Result<String, String> {
     crate::services::image_service::ImageService::get_image_file_path(content)
 }
 
+// 获取图片缩略图路径
+#[tauri::command]
+pub fn get_image_thumbnail_path(content: String) -> Result<String, String> {
+    crate::services::image_service::ImageService::get_image_thumbnail_path(content)
+}
+
 // 保存图片到指定路径
 #[tauri::command]
 pub fn save_image_to_file(content: String, file_path: String) -> Result<(), String> {
@@ -386,10 +501,59 @@ pub async fn cancel_preview() -> Result<(), String> {
     crate::services::preview_service::PreviewService::cancel_preview()
 }
 
-// 删除剪贴板项目
+// 删除剪贴板项目（移入回收站，前端可据此显示撤销提示）
+#[tauri::command]
+pub fn delete_clipboard_item(app: tauri::AppHandle, id: i64) -> Result<(), String> {
+    use tauri::Emitter;
+
+    crate::database::delete_clipboard_item(id)?;
+
+    #[derive(Clone, serde::Serialize)]
+    struct ClipboardDeletePayload {
+        id: i64,
+        can_undo: bool,
+    }
+
+    let _ = app.emit("clipboard-item-deleted", ClipboardDeletePayload { id, can_undo: true });
+    Ok(())
+}
+
+// 批量删除剪贴板项目（移入回收站）
+#[tauri::command]
+pub async fn delete_clipboard_items(app: tauri::AppHandle, ids: Vec<i64>) -> Result<usize, String> {
+    let deleted = crate::database::trash_clipboard_items(&ids)?;
+    crate::clipboard_history::cleanup_orphaned_images();
+    emit_clipboard_updated(app).await?;
+    Ok(deleted)
+}
+
+// 撤销最近一次删除
+#[tauri::command]
+pub fn restore_last_deleted(app: tauri::AppHandle) -> Result<Option<ClipboardItem>, String> {
+    use tauri::Emitter;
+
+    let restored = crate::database::restore_last_deleted()?;
+
+    if let Some(item) = &restored {
+        #[derive(Clone, serde::Serialize)]
+        struct ClipboardUpdatePayload {
+            item: ClipboardItem,
+            is_new: bool,
+        }
+
+        let _ = app.emit(
+            "clipboard-item-added",
+            ClipboardUpdatePayload { item: item.clone(), is_new: false },
+        );
+    }
+
+    Ok(restored)
+}
+
+// 清空回收站
 #[tauri::command]
-pub fn delete_clipboard_item(id: i64) -> Result<(), String> {
-    crate::database::delete_clipboard_item(id)
+pub fn empty_trash() -> Result<(), String> {
+    crate::database::empty_trash()
 }
 
 // 更新剪贴板项目内容
@@ -398,10 +562,119 @@ pub fn update_clipboard_item(id: i64, content: String) -> Result<(), String> {
     crate::database::update_clipboard_item(id, content)
 }
 
+// 对当前剪贴板内容执行转换流水线
+#[tauri::command]
+pub fn run_pipeline(name: String) -> Result<String, String> {
+    crate::services::transform_service::TransformService::run_pipeline(&name)
+}
+
+// 获取支持的转换步骤
+#[tauri::command]
+pub fn get_available_transforms() -> Vec<String> {
+    crate::services::transform_service::TransformService::get_available_transforms()
+}
+
+// 用外部编辑器编辑文本历史项
+#[tauri::command]
+pub fn open_clipboard_item_in_external_editor(app: tauri::AppHandle, index: usize) -> Result<(), String> {
+    crate::services::clipboard_service::ClipboardService::open_in_external_editor(app, index)
+}
+
+// 获取文件类型历史项的摘要（文件数量、总大小、是否被截断）
+#[tauri::command]
+pub fn get_files_entry_summary(index: usize) -> Result<crate::file_handler::FilesEntrySummary, String> {
+    let history = crate::services::clipboard_service::ClipboardService::get_history();
+    let item = history
+        .get(index)
+        .ok_or_else(|| format!("索引 {} 超出历史记录范围", index))?;
+
+    crate::file_handler::summarize_files_entry(&item.content)
+}
+
+// 校验文件类型历史项中的各个路径是否仍然存在
+#[tauri::command]
+pub fn validate_files_entry(index: usize) -> Result<Vec<bool>, String> {
+    let history = crate::services::clipboard_service::ClipboardService::get_history();
+    let item = history
+        .get(index)
+        .ok_or_else(|| format!("索引 {} 超出历史记录范围", index))?;
+
+    let paths = crate::file_handler::parse_files_entry_paths(&item.content)?;
+    Ok(crate::file_handler::validate_file_paths(&paths))
+}
+
+// 获取剪贴板监听状态（是否启用、最近记录时间、历史总数等）
+#[tauri::command]
+pub fn get_monitor_status() -> Result<clipboard_history::MonitorStatus, String> {
+    clipboard_history::get_monitor_status()
+}
+
 // 清空剪贴板历史
+// 启用清空确认时，未传入 confirmed 会返回 "confirmation_required"，由前端弹出确认框
+#[tauri::command]
+pub fn clear_clipboard_history(confirmed: Option<bool>, keep_pinned: Option<bool>) -> Result<(), String> {
+    let settings = crate::settings::get_global_settings();
+    if settings.confirm_before_clear && !confirmed.unwrap_or(false) {
+        return Err("confirmation_required".to_string());
+    }
+
+    if keep_pinned.unwrap_or(false) {
+        clipboard_history::clear_unpinned()
+    } else {
+        clipboard_history::clear_all()
+    }
+}
+
+// 清理历史中的重复内容（按当前去重模式，移入回收站），返回删除数量
+#[tauri::command]
+pub async fn deduplicate_history(app: tauri::AppHandle) -> Result<usize, String> {
+    let removed = clipboard_history::deduplicate_history()?;
+    if removed > 0 {
+        emit_clipboard_updated(app).await?;
+    }
+    Ok(removed)
+}
+
+// 获取剪贴板项目的来源应用和原始复制时间
+#[tauri::command]
+pub fn get_clipboard_item_origin(id: i64) -> Result<crate::database::ClipboardItemOrigin, String> {
+    crate::database::get_clipboard_item_origin(id)
+}
+
+// 设置剪贴板项目固定状态（固定的项目可在清空时保留）
+#[tauri::command]
+pub fn set_clipboard_item_pinned(id: i64, pinned: bool) -> Result<(), String> {
+    crate::database::set_clipboard_item_pinned(id, pinned)
+}
+
+// 设置剪贴板项目敏感标记（敏感项目粘贴后按设置自动清除剪贴板）
+#[tauri::command]
+pub fn set_clipboard_item_sensitive(id: i64, sensitive: bool) -> Result<(), String> {
+    crate::database::set_clipboard_item_sensitive(id, sensitive)
+}
+
+// 设置收藏栏槽位
+#[tauri::command]
+pub fn set_favorite_slot(slot: u32, item_id: i64) -> Result<(), String> {
+    crate::database::set_favorite_slot(slot, item_id)
+}
+
+// 获取收藏栏
+#[tauri::command]
+pub fn get_favorites() -> Result<Vec<crate::database::FavoriteSlot>, String> {
+    crate::database::get_favorite_slots()
+}
+
+// 清空收藏栏槽位
 #[tauri::command]
-pub fn clear_clipboard_history() -> Result<(), String> {
-    clipboard_history::clear_all()
+pub fn clear_favorite_slot(slot: u32) -> Result<(), String> {
+    crate::database::clear_favorite_slot(slot)
+}
+
+// 获取粘贴次数最多的历史项目（days 为统计天数，不传表示全部）
+#[tauri::command]
+pub fn get_paste_stats(limit: usize, days: Option<u32>) -> Result<Vec<crate::database::PasteStat>, String> {
+    crate::database::get_paste_stats(limit, days)
 }
 
 // 手动清理未使用的图片
@@ -459,15 +732,22 @@ pub fn get_main_window_state() -> Result<serde_json::Value, String> {
     crate::services::preview_service::PreviewService::get_main_window_state()
 }
 
-// 更新主题设置
+// 更新主题设置（支持 "system" 跟随系统）
 #[tauri::command]
-pub fn update_theme_setting(theme: String) -> Result<(), String> {
+pub fn update_theme_setting(app: tauri::AppHandle, theme: String) -> Result<(), String> {
     let mut settings = crate::settings::get_global_settings();
     settings.theme = theme;
     crate::settings::update_global_settings(settings)?;
+    crate::system_theme::apply_theme(&app);
     Ok(())
 }
 
+// 获取当前系统主题（"dark" 或 "light"）
+#[tauri::command]
+pub fn get_system_theme() -> String {
+    crate::system_theme::resolve_theme(crate::system_theme::SYSTEM_THEME)
+}
+
 // 获取应用版本信息
 #[tauri::command]
 pub fn get_app_version(app: tauri::AppHandle) -> Result<serde_json::Value, String> {
@@ -531,6 +811,59 @@ pub fn restart_as_admin() -> Result<(), String> {
     admin_privileges::restart_as_admin()
 }
 
+// 请求以管理员权限重启：先通知前端保存状态，UAC 被拒绝时发送取消事件
+#[tauri::command(async)]
+pub fn request_elevation(app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Emitter;
+
+    if admin_privileges::is_running_as_admin() {
+        return Ok(());
+    }
+
+    let _ = app.emit("before-elevation", ());
+    // 给前端留出处理未保存内容的时间
+    std::thread::sleep(std::time::Duration::from_millis(300));
+
+    admin_privileges::restart_as_admin().map_err(|e| {
+        if admin_privileges::is_elevation_cancelled(&e) {
+            let _ = app.emit("elevation-cancelled", ());
+        }
+        e
+    })
+}
+
+// 运行时启用/禁用全局输入钩子（hook: "keyboard" | "mouse"），并保存到设置
+#[tauri::command]
+pub fn set_input_hook_enabled(hook: String, enabled: bool) -> Result<(), String> {
+    let mut settings = crate::settings::get_global_settings();
+    match hook.as_str() {
+        "keyboard" => {
+            settings.enable_keyboard_hook = enabled;
+            crate::input_monitor::set_keyboard_hook_enabled(enabled);
+        }
+        "mouse" => {
+            settings.enable_mouse_hook = enabled;
+            crate::input_monitor::set_mouse_hook_enabled(enabled);
+        }
+        _ => return Err(format!("未知的钩子类型: {}", hook)),
+    }
+    crate::settings::update_global_settings(settings)
+}
+
+// 开启/关闭追加模式，并保存到设置
+#[tauri::command]
+pub fn set_append_mode(enabled: bool) -> Result<(), String> {
+    let mut settings = crate::settings::get_global_settings();
+    settings.append_mode = enabled;
+    crate::settings::update_global_settings(settings)
+}
+
+// 将文本追加到当前剪贴板文本之后
+#[tauri::command]
+pub fn append_to_clipboard(app: tauri::AppHandle, text: String) -> Result<String, String> {
+    crate::clipboard_monitor::append_text_to_clipboard(&app, text)
+}
+
 // 检查后端是否初始化完成
 #[tauri::command]
 pub fn is_backend_initialized() -> bool {
@@ -603,6 +936,18 @@ pub fn cancel_translation() -> Result<(), String> {
     crate::services::translation_service::cancel_translation()
 }
 
+// 获取翻译用量统计
+#[tauri::command]
+pub fn get_translation_usage() -> Result<crate::services::translation_service::TranslationUsage, String> {
+    crate::services::translation_service::get_translation_usage()
+}
+
+// 重置翻译用量统计
+#[tauri::command]
+pub fn reset_translation_usage() -> Result<(), String> {
+    crate::services::translation_service::reset_translation_usage()
+}
+
 // 启用AI翻译取消快捷键
 #[tauri::command]
 pub fn enable_ai_translation_cancel_shortcut() -> Result<(), String> {
@@ -627,6 +972,12 @@ pub async fn translate_and_input_text(text: String) -> Result<(), String> {
     crate::services::translation_service::translate_and_input_text(text).await
 }
 
+// 将选中文本或剪贴板文本翻译为指定语言（不修改设置中的目标语言）
+#[tauri::command]
+pub async fn translate_clipboard_to(language: String) -> Result<(), String> {
+    crate::services::translation_service::translate_clipboard_to(language).await
+}
+
 // 智能翻译文本（根据设置选择流式输入或直接粘贴）
 #[tauri::command]
 pub async fn translate_text_smart(text: String) -> Result<(), String> {
@@ -666,6 +1017,14 @@ pub async fn get_file_info(path: String) -> Result<crate::file_handler::FileInfo
     crate::services::file_operation_service::FileOperationService::get_file_info(path).await
 }
 
+// 将拖入的文件添加到剪贴板历史
+#[tauri::command]
+pub async fn add_dropped_files(app: tauri::AppHandle, files: Vec<String>) -> Result<usize, String> {
+    let count = crate::services::file_operation_service::FileOperationService::add_dropped_files(files)?;
+    emit_clipboard_updated(app).await?;
+    Ok(count)
+}
+
 #[tauri::command]
 pub async fn get_clipboard_files() -> Result<Vec<String>, String> {
     crate::services::file_operation_service::FileOperationService::get_clipboard_files().await
@@ -676,6 +1035,24 @@ pub async fn set_clipboard_files(files: Vec<String>) -> Result<(), String> {
     crate::services::file_operation_service::FileOperationService::set_clipboard_files(files).await
 }
 
+// 按指定格式设置剪贴板文件（hdrop | text_paths | both）
+#[tauri::command]
+pub async fn set_clipboard_files_as(files: Vec<String>, mode: String) -> Result<(), String> {
+    crate::services::file_operation_service::FileOperationService::set_clipboard_files_as(files, mode).await
+}
+
+// 获取OCR支持的识别语言
+#[tauri::command]
+pub fn get_available_ocr_languages() -> Result<Vec<String>, String> {
+    crate::ocr::get_available_ocr_languages()
+}
+
+// 校验OCR语言是否可用，返回实际使用的 Tesseract 语言包
+#[tauri::command]
+pub fn check_ocr_language(language: String) -> Result<String, String> {
+    crate::ocr::resolve_ocr_language(&language)
+}
+
 // 获取可用的AI模型列表
 #[tauri::command]
 pub async fn get_available_ai_models() -> Result<Vec<String>, String> {
@@ -723,6 +1100,12 @@ pub async fn paste_content(
     crate::services::paste_service::paste_content(params, window).await
 }
 
+// 仅复制历史项目到剪贴板（不粘贴）
+#[tauri::command]
+pub fn copy_clipboard_item(id: i64) -> Result<(), String> {
+    crate::services::paste_service::copy_history_item_by_id(id)
+}
+
 // 读取图片文件并返回base64数据
 #[tauri::command]
 pub fn read_image_file(file_path: String) -> Result<String, String> {
@@ -749,6 +1132,18 @@ pub async fn import_data(
     crate::data_manager::import_data(&import_path, options).await
 }
 
+// 导出完整数据包（数据库、图片、设置和音效）
+#[tauri::command]
+pub async fn export_all_data(path: String) -> Result<(), String> {
+    crate::data_manager::export_all_data(&path).await
+}
+
+// 从完整数据包恢复所有数据
+#[tauri::command]
+pub async fn import_all_data(app: tauri::AppHandle, path: String, confirmed: bool) -> Result<(), String> {
+    crate::data_manager::import_all_data(&app, &path, confirmed).await
+}
+
 // 重启应用程序
 #[tauri::command]
 pub async fn restart_app(app: tauri::AppHandle) -> Result<(), String> {
@@ -768,10 +1163,11 @@ pub async fn reset_all_data() -> Result<(), String> {
     crate::data_manager::reset_all_data().await
 }
 
-// 恢复默认配置
+// 恢复默认配置（不影响数据，重新应用设置与快捷键）
 #[tauri::command]
-pub async fn reset_settings_to_default() -> Result<(), String> {
-    crate::data_manager::reset_settings_to_default().await
+pub fn reset_settings_to_defaults(app_handle: tauri::AppHandle) -> Result<serde_json::Value, String> {
+    crate::settings::SettingsService::reset_to_defaults(app_handle)?;
+    Ok(crate::settings::SettingsConverter::to_json(&crate::settings::get_global_settings()))
 }
 
 // 获取应用数据目录
@@ -852,6 +1248,13 @@ pub fn get_saved_window_position() -> Result<Option<(i32, i32)>, String> {
     Ok(settings.saved_window_position)
 }
 
+// 在窗口大小预设之间循环切换主窗口大小
+#[tauri::command]
+pub fn cycle_window_size(app: tauri::AppHandle) -> Result<(u32, u32), String> {
+    let window = app.get_webview_window("main").ok_or("找不到主窗口")?;
+    crate::window_management::cycle_window_size(&window)
+}
+
 // 获取保存的窗口大小
 #[tauri::command]
 pub fn get_saved_window_size() -> Result<Option<(u32, u32)>, String> {
@@ -861,9 +1264,12 @@ pub fn get_saved_window_size() -> Result<Option<(u32, u32)>, String> {
 
 // =================== 内置截屏程序命令 ===================
 
-// 启动内置截屏窗口
-#[tauri::command]
+// 启动内置截屏窗口（后台线程执行，截屏期间不阻塞界面）
+#[tauri::command(async)]
 pub fn start_builtin_screenshot(app: tauri::AppHandle) -> Result<(), String> {
+    if !crate::settings::get_global_settings().enable_screenshot {
+        return Err("截屏功能已在设置中禁用".to_string());
+    }
     // 检查窗口是否已显示，防止重复请求
     if crate::screenshot::ScreenshotWindowManager::is_screenshot_window_visible() {
         return Ok(()); // 静默忽略重复请求
@@ -955,6 +1361,22 @@ pub fn refresh_all_windows(app: tauri::AppHandle) -> Result<(), String> {
             Ok(())
 }
 
+// 获取设置中的全部快捷键（全局快捷键与键盘钩子快捷键）
+#[tauri::command]
+pub fn get_registered_shortcuts() -> Vec<crate::hotkey_manager::ShortcutInfo> {
+    crate::hotkey_manager::collect_settings_shortcuts(&crate::settings::get_global_settings())
+}
+
+// 检查快捷键冲突（setting_key 为正在编辑的设置项），返回冲突的用途
+#[tauri::command]
+pub fn check_shortcut_conflict(shortcut: String, setting_key: Option<String>) -> Option<String> {
+    crate::hotkey_manager::find_shortcut_conflict(
+        &crate::settings::get_global_settings(),
+        &shortcut,
+        setting_key.as_deref(),
+    )
+}
+
 // 设置快捷键录制状态
 #[tauri::command]
 pub fn set_shortcut_recording(recording: bool) -> Result<(), String> {
@@ -31,6 +31,8 @@ pub struct ExportMetadata {
     pub images_folder: bool,
     #[serde(default)]
     pub exclude_image_cache: bool,
+    #[serde(default)]
+    pub sounds_folder: bool,
 }
 
 // 获取应用数据目录
@@ -57,6 +59,7 @@ pub async fn export_data(export_path: &str, _options: ExportOptions) -> Result<(
         settings_file: false,
         images_folder: false,
         exclude_image_cache: true, 
+        sounds_folder: false,
     };
 
     // 导出数据库文件（排除 image_data 表）
@@ -137,6 +140,116 @@ pub async fn import_data(import_path: &str, options: ImportOptions) -> Result<()
     Ok(())
 }
 
+// 导出完整数据包（包含图片缓存和自定义音效），用于迁移到新电脑
+pub async fn export_all_data(export_path: &str) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir()?;
+
+    let file = fs::File::create(export_path).map_err(|e| format!("创建导出文件失败: {}", e))?;
+    let mut zip = ZipWriter::new(file);
+    let zip_options = FileOptions::<()>::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .unix_permissions(0o755);
+
+    let mut metadata = ExportMetadata {
+        version: "2.0".to_string(),
+        export_time: Local::now().to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        database_file: false,
+        settings_file: false,
+        images_folder: false,
+        exclude_image_cache: false,
+        sounds_folder: false,
+    };
+
+    // 导出完整数据库（VACUUM INTO 生成一致的快照）
+    let temp_db_path = app_data_dir.join("temp_export_full.db");
+    if temp_db_path.exists() {
+        fs::remove_file(&temp_db_path).map_err(|e| format!("删除临时数据库失败: {}", e))?;
+    }
+    crate::database::with_connection(|conn| {
+        conn.execute("VACUUM INTO ?1", [temp_db_path.to_string_lossy().to_string()])
+    })
+    .map_err(|e| format!("导出数据库失败: {}", e))?;
+    add_file_to_zip(&mut zip, &temp_db_path, "quickclipboard.db", zip_options)?;
+    let _ = fs::remove_file(&temp_db_path);
+    metadata.database_file = true;
+
+    let default_data_dir = crate::settings::AppSettings::get_default_data_directory()
+        .map_err(|e| format!("获取默认数据目录失败: {}", e))?;
+    let settings_path = default_data_dir.join("settings.json");
+    if settings_path.exists() {
+        add_file_to_zip(&mut zip, &settings_path, "settings.json", zip_options)?;
+        metadata.settings_file = true;
+    }
+
+    let images_dir = app_data_dir.join("clipboard_images");
+    if images_dir.exists() {
+        add_directory_to_zip(&mut zip, &images_dir, "clipboard_images", zip_options)?;
+        metadata.images_folder = true;
+    }
+
+    // 导出自定义音效文件
+    let settings = crate::settings::get_global_settings();
+    for sound_path in [&settings.copy_sound_path, &settings.paste_sound_path] {
+        let path = Path::new(sound_path);
+        if sound_path.is_empty() || !path.is_file() {
+            continue;
+        }
+        if let Some(name) = path.file_name() {
+            add_file_to_zip(&mut zip, path, &format!("sounds/{}", name.to_string_lossy()), zip_options)?;
+            metadata.sounds_folder = true;
+        }
+    }
+
+    zip.start_file("metadata.json", zip_options)
+        .map_err(|e| format!("创建元数据文件失败: {}", e))?;
+    let metadata_json = serde_json::to_string_pretty(&metadata)
+        .map_err(|e| format!("序列化元数据失败: {}", e))?;
+    zip.write_all(metadata_json.as_bytes())
+        .map_err(|e| format!("写入元数据失败: {}", e))?;
+
+    zip.finish()
+        .map_err(|e| format!("完成ZIP文件创建失败: {}", e))?;
+
+    Ok(())
+}
+
+// 从完整数据包恢复所有数据（覆盖当前数据和设置）
+pub async fn import_all_data(app: &tauri::AppHandle, import_path: &str, confirmed: bool) -> Result<(), String> {
+    if !confirmed {
+        return Err("导入完整数据包会覆盖当前所有数据和设置，请确认后再导入".to_string());
+    }
+
+    let app_data_dir = get_app_data_dir()?;
+
+    let file = fs::File::open(import_path).map_err(|e| format!("打开导入文件失败: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("读取ZIP文件失败: {}", e))?;
+    let metadata = read_metadata_from_zip(&mut archive)?;
+
+    backup_current_data(&app_data_dir).await?;
+
+    // 替换数据库文件前先关闭连接，并清理残留的 WAL 文件
+    crate::database::close_database_connection()?;
+    if metadata.database_file {
+        let db_path = crate::database::get_database_path()?;
+        for suffix in ["-wal", "-shm"] {
+            let _ = fs::remove_file(format!("{}{}", db_path.display(), suffix));
+        }
+    }
+
+    let extract_result = extract_bundle_files(&mut archive, &app_data_dir);
+
+    crate::database::reinitialize_database()
+        .map_err(|e| format!("重新初始化数据库失败: {}", e))?;
+    extract_result?;
+
+    if metadata.settings_file {
+        restore_bundle_settings(app, &mut archive, &app_data_dir)?;
+    }
+
+    crate::commands::refresh_all_windows(app.clone())
+}
+
 // 清空剪贴板历史
 pub async fn clear_clipboard_history() -> Result<(), String> {
     crate::clipboard_history::clear_all().map_err(|e| format!("清空剪贴板历史失败: {}", e))
@@ -169,19 +282,6 @@ pub async fn reset_all_data() -> Result<(), String> {
     Ok(())
 }
 
-// 恢复默认配置（仅恢复设置，不影响数据）
-pub async fn reset_settings_to_default() -> Result<(), String> {
-    let default_data_dir = crate::settings::AppSettings::get_default_data_directory()
-        .map_err(|e| format!("获取默认数据目录失败: {}", e))?;
-    let settings_path = default_data_dir.join("settings.json");
-    
-    if settings_path.exists() {
-        fs::remove_file(&settings_path).map_err(|e| format!("删除设置文件失败: {}", e))?;
-    }
-
-    Ok(())
-}
-
 // =================== 辅助函数 ===================
 
 // 导出数据库但排除 image_data 表
@@ -272,6 +372,69 @@ async fn backup_current_data(app_data_dir: &Path) -> Result<(), String> {
     Ok(())
 }
 
+// 解压数据包中的数据文件（跳过元数据和设置文件，拒绝越界路径）
+fn extract_bundle_files(archive: &mut ZipArchive<fs::File>, app_data_dir: &Path) -> Result<(), String> {
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| format!("读取ZIP文件项失败: {}", e))?;
+
+        if file.is_dir() || file.name() == "metadata.json" || file.name() == "settings.json" {
+            continue;
+        }
+
+        let relative_path = file
+            .enclosed_name()
+            .ok_or_else(|| format!("数据包中包含非法路径: {}", file.name()))?;
+        let output_path = app_data_dir.join(relative_path);
+
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("创建目录失败: {}", e))?;
+        }
+
+        let mut output_file = fs::File::create(&output_path)
+            .map_err(|e| format!("创建文件失败: {}", e))?;
+        std::io::copy(&mut file, &mut output_file)
+            .map_err(|e| format!("复制文件失败: {}", e))?;
+    }
+
+    Ok(())
+}
+
+// 恢复数据包中的设置：保留本机存储位置，音效路径指向解压后的文件，并重新应用
+fn restore_bundle_settings(
+    app: &tauri::AppHandle,
+    archive: &mut ZipArchive<fs::File>,
+    app_data_dir: &Path,
+) -> Result<(), String> {
+    let mut contents = String::new();
+    archive
+        .by_name("settings.json")
+        .map_err(|e| format!("读取设置文件失败: {}", e))?
+        .read_to_string(&mut contents)
+        .map_err(|e| format!("读取设置文件失败: {}", e))?;
+
+    let mut imported = crate::settings::SettingsStorage::parse_imported(&contents)?;
+
+    let current = crate::settings::get_global_settings();
+    imported.use_custom_storage = current.use_custom_storage;
+    imported.custom_storage_path = current.custom_storage_path;
+    imported.saved_window_position = current.saved_window_position;
+    imported.saved_window_size = current.saved_window_size;
+
+    let sounds_dir = app_data_dir.join("sounds");
+    for sound_path in [&mut imported.copy_sound_path, &mut imported.paste_sound_path] {
+        if let Some(name) = Path::new(sound_path.as_str()).file_name() {
+            let restored_path = sounds_dir.join(name);
+            if restored_path.is_file() {
+                *sound_path = restored_path.to_string_lossy().to_string();
+            }
+        }
+    }
+
+    crate::settings::SettingsService::replace_all_settings(app, imported)
+}
+
 // 从ZIP文件读取元数据
 fn read_metadata_from_zip(archive: &mut ZipArchive<fs::File>) -> Result<ExportMetadata, String> {
     let mut file = archive
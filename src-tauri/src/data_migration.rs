@@ -1,7 +1,10 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::AppHandle;
 
+// 需要迁移的文件和文件夹（除了settings.json）
+const ITEMS_TO_MIGRATE: [&str; 3] = ["quickclipboard.db", "clipboard_images", "backups"];
+
 // 数据迁移服务
 pub struct DataMigrationService;
 
@@ -10,52 +13,48 @@ impl DataMigrationService {
     pub async fn migrate_data(from_dir: &PathBuf, to_dir: &PathBuf, app: Option<AppHandle>) -> Result<(), String> {
         // 确保目标目录存在
         fs::create_dir_all(to_dir).map_err(|e| format!("创建目标目录失败: {}", e))?;
+
+        // 迁移前检查目标目录可写且空间足够
+        Self::preflight_check(from_dir, to_dir)?;
         
         // 首先关闭数据库连接，确保数据库文件可以被移动
         println!("关闭数据库连接以进行数据迁移...");
         crate::database::close_database_connection()
             .map_err(|e| format!("关闭数据库连接失败: {}", e))?;
         
-        // 需要迁移的文件和文件夹（除了settings.json）
-        let items_to_migrate = [
-            "quickclipboard.db",
-            "clipboard_images", 
-            "backups",
-        ];
-        
-        for item in &items_to_migrate {
-            let source_path = from_dir.join(item);
-            let target_path = to_dir.join(item);
-            
-            if source_path.exists() {
-                if source_path.is_file() {
-                    // 复制文件
-                    if let Some(parent) = target_path.parent() {
-                        fs::create_dir_all(parent).map_err(|e| format!("创建目标目录失败: {}", e))?;
-                    }
-                    
-                    if *item == "quickclipboard.db" {
-                        Self::copy_database_file(&source_path, &target_path)?;
-                        
-                        // 复制成功后删除原文件
-                        fs::remove_file(&source_path)
-                            .map_err(|e| format!("删除原数据库文件失败: {}", e))?;
-                        println!("数据库文件迁移完成");
+        // 先全部复制，任一项失败则清理本次复制的内容并恢复原数据库连接
+        let preexisting: Vec<bool> = ITEMS_TO_MIGRATE.iter().map(|item| to_dir.join(item).exists()).collect();
+        if let Err(e) = Self::copy_items(from_dir, to_dir) {
+            for (item, existed) in ITEMS_TO_MIGRATE.iter().zip(preexisting) {
+                let target_path = to_dir.join(item);
+                if !existed && target_path.exists() {
+                    let _ = if target_path.is_dir() {
+                        fs::remove_dir_all(&target_path)
                     } else {
-                        // 其他文件直接复制
-                        fs::copy(&source_path, &target_path)
-                            .map_err(|e| format!("复制文件 {} 失败: {}", item, e))?;
-                    }
-                } else if source_path.is_dir() {
-                    // 复制目录
-                    Self::copy_dir_recursive(&source_path, &target_path)?;
-                    
-                    // 复制成功后删除原目录
-                    fs::remove_dir_all(&source_path)
-                        .map_err(|e| format!("删除原目录 {} 失败: {}", item, e))?;
-                    println!("目录 {} 迁移完成", item);
+                        fs::remove_file(&target_path)
+                    };
                 }
             }
+            if let Err(reinit_err) = crate::database::reinitialize_database() {
+                println!("恢复数据库连接失败: {}", reinit_err);
+            }
+            return Err(e);
+        }
+
+        // 全部复制成功后再删除原数据
+        for item in &ITEMS_TO_MIGRATE {
+            let source_path = from_dir.join(item);
+            let result = if source_path.is_file() {
+                fs::remove_file(&source_path)
+            } else if source_path.is_dir() {
+                fs::remove_dir_all(&source_path)
+            } else {
+                continue;
+            };
+            match result {
+                Ok(_) => println!("{} 迁移完成", item),
+                Err(e) => println!("删除原数据 {} 失败: {}", item, e),
+            }
         }
         
         // 如果提供了 AppHandle，刷新所有窗口
@@ -71,8 +70,98 @@ impl DataMigrationService {
         Ok(())
     }
     
+    // 复制所有需要迁移的文件和文件夹
+    fn copy_items(from_dir: &Path, to_dir: &Path) -> Result<(), String> {
+        for item in &ITEMS_TO_MIGRATE {
+            let source_path = from_dir.join(item);
+            let target_path = to_dir.join(item);
+
+            if source_path.is_file() {
+                if *item == "quickclipboard.db" {
+                    Self::copy_database_file(&source_path, &target_path)?;
+                } else {
+                    fs::copy(&source_path, &target_path)
+                        .map_err(|e| format!("复制文件 {} 失败: {}", item, e))?;
+                }
+            } else if source_path.is_dir() {
+                Self::copy_dir_recursive(&source_path, &target_path)?;
+            }
+        }
+        Ok(())
+    }
+
+    // 迁移前检查：目标目录可写，且剩余空间不小于当前数据大小
+    pub fn preflight_check(from_dir: &Path, to_dir: &Path) -> Result<(), String> {
+        let probe_path = to_dir.join(".quickclipboard_write_test");
+        fs::write(&probe_path, b"test")
+            .map_err(|e| format!("目标目录不可写，请选择一个您有写入权限的目录: {}", e))?;
+        let _ = fs::remove_file(&probe_path);
+
+        let required: u64 = ITEMS_TO_MIGRATE
+            .iter()
+            .map(|item| Self::path_size(&from_dir.join(item)))
+            .sum();
+
+        if let Some(available) = Self::available_space(to_dir) {
+            if available < required {
+                return Err(format!(
+                    "目标磁盘空间不足：需要 {:.1} MB，可用 {:.1} MB",
+                    required as f64 / 1024.0 / 1024.0,
+                    available as f64 / 1024.0 / 1024.0
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // 计算文件或目录的总大小
+    fn path_size(path: &Path) -> u64 {
+        if path.is_file() {
+            return fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        }
+        fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| Self::path_size(&entry.path()))
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
+    // 获取目录所在磁盘的可用空间
+    #[cfg(windows)]
+    fn available_space(dir: &Path) -> Option<u64> {
+        use std::os::windows::ffi::OsStrExt;
+        use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+        let wide_path: Vec<u16> = dir
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+        let mut available: u64 = 0;
+
+        unsafe {
+            GetDiskFreeSpaceExW(
+                windows::core::PCWSTR(wide_path.as_ptr()),
+                Some(&mut available as *mut u64),
+                None,
+                None,
+            )
+            .ok()?;
+        }
+        Some(available)
+    }
+
+    #[cfg(not(windows))]
+    fn available_space(_dir: &Path) -> Option<u64> {
+        None
+    }
+
     // 复制数据库文件
-    fn copy_database_file(source: &PathBuf, target: &PathBuf) -> Result<(), String> {
+    fn copy_database_file(source: &Path, target: &Path) -> Result<(), String> {
         fs::copy(source, target)
             .map_err(|e| {
                 if e.kind() == std::io::ErrorKind::PermissionDenied {
@@ -86,7 +175,7 @@ impl DataMigrationService {
     }
 
     // 递归复制目录
-    fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(), String> {
+    fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
         fs::create_dir_all(dst).map_err(|e| format!("创建目录失败: {}", e))?;
         
         for entry in fs::read_dir(src).map_err(|e| format!("读取源目录失败: {}", e))? {
@@ -2,6 +2,7 @@ use once_cell::sync::Lazy;
 use rusqlite::{params, Connection, Result as SqliteResult};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 // 对于文本内容，超过此限制会被截断
@@ -82,6 +83,10 @@ pub struct ClipboardItem {
     pub item_order: i32,
     pub created_at: i64,
     pub updated_at: i64,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub sensitive: bool, // 敏感条目粘贴后按设置定时清除剪贴板
 }
 
 impl ClipboardItem {
@@ -98,6 +103,8 @@ impl ClipboardItem {
             item_order: 0,
             created_at: timestamp,
             updated_at: timestamp,
+            pinned: false,
+            sensitive: false,
         }
     }
 
@@ -114,6 +121,8 @@ impl ClipboardItem {
             item_order: 0,
             created_at: timestamp,
             updated_at: timestamp,
+            pinned: false,
+            sensitive: false,
         }
     }
 
@@ -130,6 +139,8 @@ impl ClipboardItem {
             item_order: 0,
             created_at: timestamp,
             updated_at: timestamp,
+            pinned: false,
+            sensitive: false,
         }
     }
 
@@ -146,6 +157,8 @@ impl ClipboardItem {
             item_order: 0,
             created_at: timestamp,
             updated_at: timestamp,
+            pinned: false,
+            sensitive: false,
         }
     }
 
@@ -162,6 +175,8 @@ impl ClipboardItem {
             item_order: 0,
             created_at: timestamp,
             updated_at: timestamp,
+            pinned: false,
+            sensitive: false,
         }
     }
 
@@ -266,6 +281,14 @@ pub fn initialize_database() -> SqliteResult<()> {
     // 创建表
     create_tables(&conn)?;
 
+    // 临时模式下用内存表遮蔽历史记录表（重新打开连接后需要重新创建）
+    let ephemeral = crate::settings::get_global_settings().ephemeral_mode;
+    if ephemeral {
+        create_ephemeral_tables(&conn)?;
+    }
+    EPHEMERAL_MODE.store(ephemeral, Ordering::Relaxed);
+    invalidate_dedup_keys();
+
     // 存储连接
     let mut db_conn = DB_CONNECTION.lock().unwrap();
     *db_conn = Some(conn);
@@ -286,10 +309,36 @@ fn create_tables(conn: &Connection) -> SqliteResult<()> {
             image_id TEXT,
             item_order INTEGER NOT NULL DEFAULT 0,
             created_at INTEGER NOT NULL,
-            updated_at INTEGER NOT NULL
+            updated_at INTEGER NOT NULL,
+            pinned INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+
+    // 旧版本数据库补齐新增字段
+    ensure_column(conn, "clipboard", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+    ensure_column(conn, "clipboard", "source_app", "TEXT")?;
+    ensure_column(conn, "clipboard", "sensitive", "INTEGER NOT NULL DEFAULT 0")?;
+    ensure_column(conn, "clipboard", "dedup_key", "TEXT")?;
+
+    // 回收站表（删除的剪贴板项目暂存于此，可撤销）
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS clipboard_trash (
+            id INTEGER PRIMARY KEY,
+            content TEXT NOT NULL,
+            html_content TEXT,
+            content_type TEXT NOT NULL DEFAULT 'text',
+            image_id TEXT,
+            item_order INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            pinned INTEGER NOT NULL DEFAULT 0,
+            deleted_at INTEGER NOT NULL
         )",
         [],
     )?;
+    ensure_column(conn, "clipboard_trash", "sensitive", "INTEGER NOT NULL DEFAULT 0")?;
+    ensure_column(conn, "clipboard_trash", "source_app", "TEXT")?;
 
     // 收藏表（原常用文本表，通过group_name引用groups表）
     conn.execute(
@@ -308,6 +357,26 @@ fn create_tables(conn: &Connection) -> SqliteResult<()> {
         [],
     )?;
 
+    // 收藏栏槽位表（槽位 -> 剪贴板项目）
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS favorite_slots (
+            slot INTEGER PRIMARY KEY,
+            item_id INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    // 粘贴记录表（按剪贴板项目ID统计粘贴次数）
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS paste_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            item_id INTEGER NOT NULL,
+            pasted_at INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
     // 分组表（支持空分组）
     conn.execute(
         "CREATE TABLE IF NOT EXISTS groups (
@@ -320,12 +389,24 @@ fn create_tables(conn: &Connection) -> SqliteResult<()> {
         [],
     )?;
 
+    ensure_column(conn, "groups", "last_used_at", "INTEGER")?;
+
     // 创建索引
     conn.execute(
         "CREATE INDEX IF NOT EXISTS idx_clipboard_created ON clipboard(created_at DESC)",
         [],
     )?;
 
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_clipboard_dedup_key ON clipboard(dedup_key)",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_paste_events_item ON paste_events(item_id, pasted_at)",
+        [],
+    )?;
+
     conn.execute(
         "CREATE INDEX IF NOT EXISTS idx_favorites_group ON favorites(group_name, item_order)",
         [],
@@ -349,9 +430,35 @@ fn create_tables(conn: &Connection) -> SqliteResult<()> {
         [],
     )?;
 
+    // 翻译用量表（按天累计字符数）
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS translation_usage (
+            day TEXT PRIMARY KEY,
+            chars INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+
     Ok(())
 }
 
+// 确保表中存在指定字段（用于旧数据库升级）
+fn ensure_column(conn: &Connection, table: &str, column: &str, definition: &str) -> SqliteResult<()> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|name| name.ok())
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            [],
+        )?;
+    }
+
+    Ok(())
+}
 
 // 关闭数据库连接
 pub fn close_database_connection() -> Result<(), String> {
@@ -369,6 +476,93 @@ pub fn close_database_connection() -> Result<(), String> {
     Ok(())
 }
 
+// =================== 临时（仅内存）模式 ===================
+
+// 临时模式是否启用
+static EPHEMERAL_MODE: AtomicBool = AtomicBool::new(false);
+
+// 检查是否处于临时模式
+pub fn is_ephemeral_mode() -> bool {
+    EPHEMERAL_MODE.load(Ordering::Relaxed)
+}
+
+// 创建同名 TEMP 表：SQLite 对未指定库名的表优先查找 temp 库，
+// 因此所有历史记录读写都会落到内存表中，收藏和分组仍使用磁盘上的表
+fn create_ephemeral_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute_batch(
+        "PRAGMA temp_store = MEMORY;
+        CREATE TEMP TABLE IF NOT EXISTS clipboard (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            content TEXT NOT NULL,
+            html_content TEXT,
+            content_type TEXT NOT NULL DEFAULT 'text',
+            image_id TEXT,
+            item_order INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            pinned INTEGER NOT NULL DEFAULT 0,
+            source_app TEXT,
+            sensitive INTEGER NOT NULL DEFAULT 0,
+            dedup_key TEXT
+        );
+        CREATE INDEX IF NOT EXISTS temp.idx_clipboard_dedup_key ON clipboard(dedup_key);
+        CREATE TEMP TABLE IF NOT EXISTS clipboard_trash (
+            id INTEGER PRIMARY KEY,
+            content TEXT NOT NULL,
+            html_content TEXT,
+            content_type TEXT NOT NULL DEFAULT 'text',
+            image_id TEXT,
+            item_order INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            pinned INTEGER NOT NULL DEFAULT 0,
+            deleted_at INTEGER NOT NULL,
+            sensitive INTEGER NOT NULL DEFAULT 0,
+            source_app TEXT
+        );
+        CREATE TEMP TABLE IF NOT EXISTS favorite_slots (
+            slot INTEGER PRIMARY KEY,
+            item_id INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+        CREATE TEMP TABLE IF NOT EXISTS paste_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            item_id INTEGER NOT NULL,
+            pasted_at INTEGER NOT NULL
+        );",
+    )
+}
+
+// 删除 TEMP 表，恢复使用磁盘上的历史记录
+fn drop_ephemeral_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute_batch(
+        "DROP TABLE IF EXISTS temp.clipboard;
+        DROP TABLE IF EXISTS temp.clipboard_trash;
+        DROP TABLE IF EXISTS temp.favorite_slots;
+        DROP TABLE IF EXISTS temp.paste_events;",
+    )
+}
+
+// 切换临时模式：开启后新历史只保存在内存中，关闭或退出时全部丢弃
+pub fn set_ephemeral_mode(enabled: bool) -> Result<(), String> {
+    if is_ephemeral_mode() == enabled {
+        return Ok(());
+    }
+
+    with_connection(|conn| {
+        if enabled {
+            create_ephemeral_tables(conn)
+        } else {
+            drop_ephemeral_tables(conn)
+        }
+    })?;
+
+    EPHEMERAL_MODE.store(enabled, Ordering::Relaxed);
+    invalidate_dedup_keys();
+    println!("临时模式: {}", if enabled { "开启" } else { "关闭" });
+    Ok(())
+}
+
 // 重新初始化数据库连接
 pub fn reinitialize_database() -> Result<(), String> {
     // 先关闭现有连接
@@ -521,6 +715,8 @@ pub fn add_clipboard_item_smart(content: String, html: Option<String>) -> Result
                     item_order: 0,
                     created_at: chrono::Local::now().timestamp(),
                     updated_at: chrono::Local::now().timestamp(),
+                    pinned: false,
+                    sensitive: false,
                 };
                 
                 with_connection(|conn| {
@@ -685,59 +881,16 @@ pub fn get_clipboard_history(limit: Option<usize>) -> Result<Vec<ClipboardItem>,
         let sql = if let Some(limit) = limit {
             // 如果限制数量非常大（≥999999），直接无限制
             if limit >= 999999 {
-                "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at FROM clipboard ORDER BY item_order, updated_at DESC".to_string()
+                "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive FROM clipboard ORDER BY item_order, updated_at DESC".to_string()
             } else {
-                format!("SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at FROM clipboard ORDER BY item_order, updated_at DESC LIMIT {}", limit)
+                format!("SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive FROM clipboard ORDER BY item_order, updated_at DESC LIMIT {}", limit)
             }
         } else {
-            "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at FROM clipboard ORDER BY item_order, updated_at DESC".to_string()
+            "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive FROM clipboard ORDER BY item_order, updated_at DESC".to_string()
         };
 
         let mut stmt = conn.prepare(&sql)?;
-        let rows = stmt.query_map([], |row| {
-            let content: String = row.get(1)?;
-            let html_content: Option<String> = row.get(2).ok();
-            let content_type = ContentType::from_string(&row.get::<_, String>(3).unwrap_or_default());
-
-            let (truncated_content, truncated_html) = match content_type {
-                ContentType::Text | ContentType::RichText | ContentType::Link => {
-                    // 截断content
-                    let truncated_content = if content.len() > MAX_CONTENT_LENGTH_FOR_DISPLAY {
-                        truncate_string_for_display(content, MAX_CONTENT_LENGTH_FOR_DISPLAY)
-                    } else {
-                        content
-                    };
-                    
-                    // 截断html_content
-                    let truncated_html = if let Some(html) = html_content {
-                        if html.len() > MAX_CONTENT_LENGTH_FOR_DISPLAY {
-                            Some(truncate_string_for_display(html, MAX_CONTENT_LENGTH_FOR_DISPLAY))
-                        } else {
-                            Some(html)
-                        }
-                    } else {
-                        None
-                    };
-                    
-                    (truncated_content, truncated_html)
-                },
-                // 图片和文件类型不截断
-                ContentType::Image | ContentType::File => {
-                    (content, html_content)
-                }
-            };
-            
-            Ok(ClipboardItem {
-                id: row.get(0)?,
-                content: truncated_content,
-                html_content: truncated_html,
-                content_type,
-                image_id: row.get(4)?,
-                item_order: row.get(5)?,
-                created_at: row.get(6)?,
-                updated_at: row.get(7)?,
-            })
-        })?;
+        let rows = stmt.query_map([], map_clipboard_row)?;
 
         let mut items = Vec::new();
         for row in rows {
@@ -748,6 +901,97 @@ pub fn get_clipboard_history(limit: Option<usize>) -> Result<Vec<ClipboardItem>,
     })
 }
 
+// 获取剪贴板历史总数
+pub fn get_clipboard_count() -> Result<usize, String> {
+    with_connection(|conn| {
+        conn.query_row("SELECT COUNT(*) FROM clipboard", [], |row| row.get::<_, i64>(0))
+            .map(|count| count as usize)
+    })
+}
+
+// 分页获取剪贴板历史，返回当前页项目和总数（oldest_first 时按最旧优先排列）
+pub fn get_clipboard_history_page(offset: usize, count: usize, oldest_first: bool) -> Result<(Vec<ClipboardItem>, usize), String> {
+    with_connection(|conn| {
+        let total: i64 = conn.query_row("SELECT COUNT(*) FROM clipboard", [], |row| row.get(0))?;
+
+        let order_by = if oldest_first {
+            "item_order DESC, updated_at ASC"
+        } else {
+            "item_order, updated_at DESC"
+        };
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive FROM clipboard ORDER BY {} LIMIT ?1 OFFSET ?2",
+            order_by
+        ))?;
+        let rows = stmt.query_map(params![count as i64, offset as i64], map_clipboard_row)?;
+
+        let mut items = Vec::new();
+        for row in rows {
+            items.push(row?);
+        }
+
+        Ok((items, total as usize))
+    })
+}
+
+// 将查询结果行转换为剪贴板项目（长文本截断用于显示）
+fn map_clipboard_row(row: &rusqlite::Row) -> SqliteResult<ClipboardItem> {
+    let mut item = map_full_clipboard_row(row)?;
+
+    // 图片和文件类型不截断
+    if matches!(item.content_type, ContentType::Text | ContentType::RichText | ContentType::Link) {
+        if item.content.len() > MAX_CONTENT_LENGTH_FOR_DISPLAY {
+            item.content = truncate_string_for_display(item.content, MAX_CONTENT_LENGTH_FOR_DISPLAY);
+        }
+        item.html_content = item.html_content.map(|html| {
+            if html.len() > MAX_CONTENT_LENGTH_FOR_DISPLAY {
+                truncate_string_for_display(html, MAX_CONTENT_LENGTH_FOR_DISPLAY)
+            } else {
+                html
+            }
+        });
+    }
+
+    Ok(item)
+}
+
+// 将查询结果行转换为剪贴板项目（保留完整内容）
+fn map_full_clipboard_row(row: &rusqlite::Row) -> SqliteResult<ClipboardItem> {
+    Ok(ClipboardItem {
+        id: row.get(0)?,
+        content: row.get(1)?,
+        html_content: row.get(2).ok(),
+        content_type: ContentType::from_string(&row.get::<_, String>(3).unwrap_or_default()),
+        image_id: row.get(4)?,
+        item_order: row.get(5)?,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+        pinned: row.get::<_, i64>(8)? != 0,
+        sensitive: row.get::<_, i64>(9)? != 0,
+    })
+}
+
+// 按ID获取剪贴板项目的完整内容（不截断），顺序与 ids 一致，不存在的ID跳过
+pub fn get_clipboard_items_full(ids: &[i64]) -> Result<Vec<ClipboardItem>, String> {
+    with_connection(|conn| query_clipboard_items_full(conn, ids))
+}
+
+fn query_clipboard_items_full(conn: &Connection, ids: &[i64]) -> SqliteResult<Vec<ClipboardItem>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive FROM clipboard WHERE id = ?1",
+    )?;
+
+    let mut items = Vec::new();
+    for id in ids {
+        match stmt.query_row(params![id], map_full_clipboard_row) {
+            Ok(item) => items.push(item),
+            Err(rusqlite::Error::QueryReturnedNoRows) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(items)
+}
+
 // 检查剪贴板项目是否存在
 pub fn clipboard_item_exists(content: &str) -> Result<Option<i64>, String> {
     with_connection(|conn| {
@@ -764,6 +1008,71 @@ pub fn clipboard_item_exists(content: &str) -> Result<Option<i64>, String> {
     })
 }
 
+// dedup_key 列当前对应的去重模式，为 None 时需要全部重建
+static DEDUP_KEY_MODE: Mutex<Option<String>> = Mutex::new(None);
+
+// 切换数据库或临时模式后，已有的去重键可能不再对应当前模式
+fn invalidate_dedup_keys() {
+    *DEDUP_KEY_MODE.lock().unwrap() = None;
+}
+
+// 按去重模式更新 dedup_key 列：模式变化后重建所有记录的键，之后只为尚未计算键的记录补齐
+// compute_key 返回 None 表示该记录不参与去重
+pub fn update_dedup_keys<F>(mode: &str, compute_key: F) -> Result<(), String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    with_connection(|conn| {
+        let mut built_mode = DEDUP_KEY_MODE.lock().unwrap();
+        if built_mode.as_deref() != Some(mode) {
+            conn.execute("UPDATE clipboard SET dedup_key = NULL", [])?;
+            *built_mode = Some(mode.to_string());
+        }
+        drop(built_mode);
+
+        fill_dedup_keys(conn, compute_key)
+    })
+}
+
+// 按去重键查找最近一条剪贴板项目（需先调用 update_dedup_keys）
+pub fn find_clipboard_item_by_dedup_key(key: &str) -> Result<Option<i64>, String> {
+    with_connection(|conn| query_clipboard_item_by_dedup_key(conn, key))
+}
+
+// 为尚未计算去重键的文本记录补齐键
+fn fill_dedup_keys<F>(conn: &Connection, compute_key: F) -> SqliteResult<()>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let pending: Vec<(i64, String)> = {
+        let mut stmt = conn.prepare(
+            "SELECT id, content FROM clipboard
+             WHERE dedup_key IS NULL AND content_type IN ('text', 'rich_text', 'link')",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<SqliteResult<_>>()?
+    };
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    for (id, content) in pending {
+        if let Some(key) = compute_key(&content) {
+            tx.execute("UPDATE clipboard SET dedup_key = ?1 WHERE id = ?2", params![key, id])?;
+        }
+    }
+    tx.commit()
+}
+
+fn query_clipboard_item_by_dedup_key(conn: &Connection, key: &str) -> SqliteResult<Option<i64>> {
+    let mut stmt = conn.prepare(
+        "SELECT id FROM clipboard WHERE dedup_key = ?1 ORDER BY created_at DESC LIMIT 1",
+    )?;
+    let mut rows = stmt.query_map([key], |row| row.get::<_, i64>(0))?;
+    rows.next().transpose()
+}
+
 // 移动剪贴板项目到最前面（使用item_order排序）
 pub fn move_clipboard_item_to_front(id: i64) -> Result<(), String> {
     let now = chrono::Local::now();
@@ -787,16 +1096,181 @@ pub fn move_clipboard_item_to_front(id: i64) -> Result<(), String> {
     })
 }
 
-// 删除剪贴板项目
+// 删除剪贴板项目（移入回收站，可撤销）
 pub fn delete_clipboard_item(id: i64) -> Result<(), String> {
+    let now = chrono::Local::now().timestamp();
+
     with_connection(|conn| {
+        conn.execute(
+            "INSERT OR REPLACE INTO clipboard_trash (id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive, source_app, deleted_at)
+             SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive, source_app, ?2 FROM clipboard WHERE id = ?1",
+            params![id, now],
+        )?;
         conn.execute("DELETE FROM clipboard WHERE id = ?1", params![id])?;
         Ok(())
     })?;
-    std::thread::spawn(|| {
-        crate::clipboard_history::cleanup_orphaned_images();
+
+    let retention_days = crate::settings::get_global_settings().trash_retention_days;
+    std::thread::spawn(move || {
+        if let Err(e) = purge_expired_trash(retention_days) {
+            println!("清理过期回收站记录失败: {}", e);
+        }
     });
-    
+
+    Ok(())
+}
+
+// 批量删除剪贴板项目（在一个事务中移入回收站）
+pub fn trash_clipboard_items(ids: &[i64]) -> Result<usize, String> {
+    let now = chrono::Local::now().timestamp();
+
+    let deleted = with_connection(|conn| {
+        let tx = conn.unchecked_transaction()?;
+        let mut deleted = 0;
+        for id in ids {
+            tx.execute(
+                "INSERT OR REPLACE INTO clipboard_trash (id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive, source_app, deleted_at)
+                 SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive, source_app, ?2 FROM clipboard WHERE id = ?1",
+                params![id, now],
+            )?;
+            deleted += tx.execute("DELETE FROM clipboard WHERE id = ?1", params![id])?;
+        }
+        tx.commit()?;
+        Ok(deleted)
+    })?;
+
+    let retention_days = crate::settings::get_global_settings().trash_retention_days;
+    std::thread::spawn(move || {
+        if let Err(e) = purge_expired_trash(retention_days) {
+            println!("清理过期回收站记录失败: {}", e);
+        }
+    });
+
+    Ok(deleted)
+}
+
+// 恢复最近删除的剪贴板项目
+pub fn restore_last_deleted() -> Result<Option<ClipboardItem>, String> {
+    let restored_id: Option<i64> = with_connection(|conn| {
+        let id = match conn.query_row(
+            "SELECT id FROM clipboard_trash ORDER BY deleted_at DESC, id DESC LIMIT 1",
+            [],
+            |row| row.get::<_, i64>(0),
+        ) {
+            Ok(id) => id,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        conn.execute(
+            "INSERT OR REPLACE INTO clipboard (id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive, source_app)
+             SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive, source_app FROM clipboard_trash WHERE id = ?1",
+            params![id],
+        )?;
+        conn.execute("DELETE FROM clipboard_trash WHERE id = ?1", params![id])?;
+        Ok(Some(id))
+    })?;
+
+    match restored_id {
+        Some(id) => Ok(get_clipboard_history(None)?.into_iter().find(|item| item.id == id)),
+        None => Ok(None),
+    }
+}
+
+// 获取回收站中的项目
+pub fn get_trash_items() -> Result<Vec<ClipboardItem>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, content, html_content, content_type, image_id, item_order, created_at, updated_at, pinned, sensitive FROM clipboard_trash ORDER BY deleted_at DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(ClipboardItem {
+                id: row.get(0)?,
+                content: row.get(1)?,
+                html_content: row.get(2).ok(),
+                content_type: ContentType::from_string(&row.get::<_, String>(3).unwrap_or_default()),
+                image_id: row.get(4)?,
+                item_order: row.get(5)?,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+                pinned: row.get::<_, i64>(8)? != 0,
+                sensitive: row.get::<_, i64>(9)? != 0,
+            })
+        })?;
+
+        let mut items = Vec::new();
+        for row in rows {
+            items.push(row?);
+        }
+
+        Ok(items)
+    })
+}
+
+// 获取历史记录和回收站中仍被引用的图片来源（image_id, html_content）
+pub fn get_image_references() -> Result<Vec<(Option<String>, Option<String>)>, String> {
+    with_connection(collect_image_references)
+}
+
+// 显式查询 main 库：临时模式下同名 TEMP 表会遮蔽磁盘上的表，
+// 只读未限定库名的表会让已持久化的图片被当作孤立图片删除
+fn collect_image_references(conn: &Connection) -> SqliteResult<Vec<(Option<String>, Option<String>)>> {
+    let mut tables = vec!["main.clipboard", "main.clipboard_trash"];
+
+    let has_temp_tables: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_temp_master WHERE type = 'table' AND name = 'clipboard'",
+        [],
+        |row| row.get(0),
+    )?;
+    if has_temp_tables > 0 {
+        tables.push("temp.clipboard");
+        tables.push("temp.clipboard_trash");
+    }
+
+    let mut references = Vec::new();
+    for table in tables {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT image_id, html_content FROM {} WHERE image_id IS NOT NULL OR html_content IS NOT NULL",
+            table
+        ))?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, Option<String>>(0)?, row.get::<_, Option<String>>(1)?))
+        })?;
+        for row in rows {
+            references.push(row?);
+        }
+    }
+
+    Ok(references)
+}
+
+// 清空回收站
+pub fn empty_trash() -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute("DELETE FROM clipboard_trash", [])?;
+        Ok(())
+    })?;
+
+    crate::clipboard_history::cleanup_orphaned_images();
+
+    Ok(())
+}
+
+// 清理超过保留天数的回收站记录
+pub fn purge_expired_trash(retention_days: u32) -> Result<(), String> {
+    let cutoff = chrono::Local::now().timestamp() - retention_days as i64 * 24 * 60 * 60;
+
+    let removed = with_connection(|conn| {
+        conn.execute(
+            "DELETE FROM clipboard_trash WHERE deleted_at < ?1",
+            params![cutoff],
+        )
+    })?;
+
+    if removed > 0 {
+        crate::clipboard_history::cleanup_orphaned_images();
+    }
+
     Ok(())
 }
 
@@ -806,7 +1280,7 @@ pub fn update_clipboard_item(id: i64, new_content: String) -> Result<(), String>
     
     with_connection(|conn| {
         conn.execute(
-            "UPDATE clipboard SET content = ?1, updated_at = ?2 WHERE id = ?3",
+            "UPDATE clipboard SET content = ?1, updated_at = ?2, dedup_key = NULL WHERE id = ?3",
             params![new_content, now, id],
         )?;
         Ok(())
@@ -825,25 +1299,310 @@ pub fn clear_clipboard_history() -> Result<(), String> {
     Ok(())
 }
 
-// 限制剪贴板历史数量
-pub fn limit_clipboard_history(max_count: usize) -> Result<(), String> {
+// 清空未固定的剪贴板历史
+pub fn clear_unpinned_clipboard_history() -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute("DELETE FROM clipboard WHERE pinned = 0", [])?;
+        Ok(())
+    })?;
+
+    crate::clipboard_history::cleanup_orphaned_images();
+
+    Ok(())
+}
+
+// 获取全部剪贴板项目的完整内容（id, 内容, 固定状态），按创建时间倒序
+pub fn get_clipboard_contents() -> Result<Vec<(i64, String, bool)>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, content, pinned FROM clipboard ORDER BY created_at DESC, id DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, bool>(2)?))
+        })?;
+
+        let mut items = Vec::new();
+        for row in rows {
+            items.push(row?);
+        }
+        Ok(items)
+    })
+}
+
+// 收藏栏槽位数量
+pub const MAX_FAVORITE_SLOTS: u32 = 10;
+
+// 收藏栏槽位
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FavoriteSlot {
+    pub slot: u32,
+    pub item: ClipboardItem,
+}
+
+// 设置收藏栏槽位引用的剪贴板项目
+pub fn set_favorite_slot(slot: u32, item_id: i64) -> Result<(), String> {
+    if slot >= MAX_FAVORITE_SLOTS {
+        return Err(format!("槽位 {} 超出范围（0-{}）", slot, MAX_FAVORITE_SLOTS - 1));
+    }
+
+    let now = chrono::Local::now().timestamp();
+    let updated = with_connection(|conn| {
+        conn.execute(
+            "INSERT OR REPLACE INTO favorite_slots (slot, item_id, updated_at)
+             SELECT ?1, id, ?3 FROM clipboard WHERE id = ?2",
+            params![slot, item_id, now],
+        )
+    })?;
+
+    if updated == 0 {
+        return Err(format!("剪贴板项目不存在: {}", item_id));
+    }
+    Ok(())
+}
+
+// 清空收藏栏槽位
+pub fn clear_favorite_slot(slot: u32) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute("DELETE FROM favorite_slots WHERE slot = ?1", params![slot])?;
+        Ok(())
+    })
+}
+
+// 获取收藏栏（仅返回引用项目仍存在的槽位）
+pub fn get_favorite_slots() -> Result<Vec<FavoriteSlot>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.content, c.html_content, c.content_type, c.image_id, c.item_order, c.created_at, c.updated_at, c.pinned, c.sensitive, f.slot
+             FROM favorite_slots f JOIN clipboard c ON c.id = f.item_id
+             ORDER BY f.slot",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(FavoriteSlot {
+                slot: row.get(10)?,
+                item: map_clipboard_row(row)?,
+            })
+        })?;
+
+        let mut slots = Vec::new();
+        for row in rows {
+            slots.push(row?);
+        }
+        Ok(slots)
+    })
+}
+
+// 粘贴统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasteStat {
+    pub item: ClipboardItem,
+    pub paste_count: u32,
+    pub last_pasted_at: i64,
+}
+
+// 记录一次剪贴板项目粘贴
+pub fn record_paste(item_id: i64) -> Result<(), String> {
+    let now = chrono::Local::now().timestamp();
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT INTO paste_events (item_id, pasted_at) VALUES (?1, ?2)",
+            params![item_id, now],
+        )?;
+        Ok(())
+    })
+}
+
+// 获取粘贴次数最多的项目，days 为统计的天数范围（None 表示全部）
+pub fn get_paste_stats(limit: usize, days: Option<u32>) -> Result<Vec<PasteStat>, String> {
+    let since = days
+        .map(|d| chrono::Local::now().timestamp() - d as i64 * 24 * 60 * 60)
+        .unwrap_or(0);
+
+    with_connection(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.content, c.html_content, c.content_type, c.image_id, c.item_order, c.created_at, c.updated_at, c.pinned, c.sensitive,
+                    COUNT(p.id) AS paste_count, MAX(p.pasted_at) AS last_pasted_at
+             FROM paste_events p JOIN clipboard c ON c.id = p.item_id
+             WHERE p.pasted_at >= ?1
+             GROUP BY c.id
+             ORDER BY paste_count DESC, last_pasted_at DESC
+             LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![since, limit as i64], |row| {
+            Ok(PasteStat {
+                item: map_clipboard_row(row)?,
+                paste_count: row.get(10)?,
+                last_pasted_at: row.get(11)?,
+            })
+        })?;
+
+        let mut stats = Vec::new();
+        for row in rows {
+            stats.push(row?);
+        }
+        Ok(stats)
+    })
+}
+
+// 累加某天的翻译字符数
+pub fn add_translation_usage(day: &str, chars: u64) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT INTO translation_usage (day, chars) VALUES (?1, ?2)
+             ON CONFLICT(day) DO UPDATE SET chars = chars + excluded.chars",
+            params![day, chars as i64],
+        )?;
+        Ok(())
+    })
+}
+
+// 获取翻译字符数（指定日期, 全部）
+pub fn get_translation_usage_totals(day: &str) -> Result<(u64, u64), String> {
+    with_connection(|conn| {
+        conn.query_row(
+            "SELECT COALESCE(SUM(CASE WHEN day = ?1 THEN chars ELSE 0 END), 0), COALESCE(SUM(chars), 0)
+             FROM translation_usage",
+            params![day],
+            |row| Ok((row.get::<_, i64>(0)? as u64, row.get::<_, i64>(1)? as u64)),
+        )
+    })
+}
+
+// 清空翻译用量记录
+pub fn clear_translation_usage() -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute("DELETE FROM translation_usage", [])?;
+        Ok(())
+    })
+}
+
+// 剪贴板项目来源信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardItemOrigin {
+    pub id: i64,
+    pub source_app: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+// 记录剪贴板项目的来源应用
+pub fn set_clipboard_item_source(id: i64, source_app: Option<&str>) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "UPDATE clipboard SET source_app = ?1 WHERE id = ?2",
+            params![source_app, id],
+        )?;
+        Ok(())
+    })
+}
+
+// 获取剪贴板项目的来源应用和原始复制时间
+pub fn get_clipboard_item_origin(id: i64) -> Result<ClipboardItemOrigin, String> {
+    with_connection(|conn| {
+        conn.query_row(
+            "SELECT id, source_app, created_at, updated_at FROM clipboard WHERE id = ?1",
+            [id],
+            |row| {
+                Ok(ClipboardItemOrigin {
+                    id: row.get(0)?,
+                    source_app: row.get(1)?,
+                    created_at: row.get(2)?,
+                    updated_at: row.get(3)?,
+                })
+            },
+        )
+    })
+}
+
+// 设置剪贴板项目固定状态
+pub fn set_clipboard_item_pinned(id: i64, pinned: bool) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "UPDATE clipboard SET pinned = ?1 WHERE id = ?2",
+            params![pinned as i64, id],
+        )?;
+        Ok(())
+    })
+}
+
+// 设置剪贴板项目敏感标记（粘贴后按设置定时清除剪贴板）
+pub fn set_clipboard_item_sensitive(id: i64, sensitive: bool) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "UPDATE clipboard SET sensitive = ?1 WHERE id = ?2",
+            params![sensitive as i64, id],
+        )?;
+        Ok(())
+    })
+}
+
+// 查询剪贴板项目是否标记为敏感
+pub fn is_clipboard_item_sensitive(id: i64) -> Result<bool, String> {
+    with_connection(|conn| {
+        conn.query_row(
+            "SELECT sensitive FROM clipboard WHERE id = ?1",
+            params![id],
+            |row| row.get::<_, i64>(0),
+        )
+        .map(|sensitive| sensitive != 0)
+    })
+}
+
+// 限制剪贴板历史数量（exclude_images 为 true 时图片不计入，由图片数量限制单独处理；固定的项目不计入也不删除）
+pub fn limit_clipboard_history(max_count: usize, exclude_images: bool) -> Result<(), String> {
     if max_count >= 999999 {
         return Ok(());
     }
     
+    with_connection(|conn| delete_history_over_limit(conn, max_count, exclude_images))?;
+    
+    crate::clipboard_history::cleanup_orphaned_images();
+    
+    Ok(())
+}
+
+// 删除超出限制的记录（保留item_order最小的记录）
+fn delete_history_over_limit(conn: &Connection, max_count: usize, exclude_images: bool) -> SqliteResult<()> {
+    if exclude_images {
+        conn.execute(
+            "DELETE FROM clipboard WHERE content_type != 'image' AND pinned = 0
+              AND id NOT IN (SELECT item_id FROM favorite_slots) AND id NOT IN (
+                SELECT id FROM clipboard WHERE content_type != 'image' AND pinned = 0
+                  AND id NOT IN (SELECT item_id FROM favorite_slots)
+                ORDER BY item_order, updated_at DESC LIMIT ?1
+            )",
+            params![max_count],
+        )?;
+    } else {
+        conn.execute(
+            "DELETE FROM clipboard WHERE pinned = 0
+              AND id NOT IN (SELECT item_id FROM favorite_slots) AND id NOT IN (
+                SELECT id FROM clipboard WHERE pinned = 0
+                  AND id NOT IN (SELECT item_id FROM favorite_slots)
+                ORDER BY item_order, updated_at DESC LIMIT ?1
+            )",
+            params![max_count],
+        )?;
+    }
+    Ok(())
+}
+
+// 限制图片历史数量（固定或占用收藏位的图片不计入也不删除）
+pub fn limit_clipboard_images(max_count: usize) -> Result<(), String> {
     with_connection(|conn| {
-        // 删除超出限制的记录（保留item_order最小的记录）
         conn.execute(
-            "DELETE FROM clipboard WHERE id NOT IN (
-                SELECT id FROM clipboard ORDER BY item_order, updated_at DESC LIMIT ?1
+            "DELETE FROM clipboard WHERE content_type = 'image' AND pinned = 0
+              AND id NOT IN (SELECT item_id FROM favorite_slots) AND id NOT IN (
+                SELECT id FROM clipboard WHERE content_type = 'image' AND pinned = 0
+                  AND id NOT IN (SELECT item_id FROM favorite_slots)
+                ORDER BY item_order, updated_at DESC LIMIT ?1
             )",
             params![max_count],
         )?;
         Ok(())
     })?;
-    
+
     crate::clipboard_history::cleanup_orphaned_images();
-    
+
     Ok(())
 }
 
@@ -880,6 +1639,26 @@ pub fn add_favorite_item(item: &FavoriteItem) -> Result<(), String> {
     })
 }
 
+// 将剪贴板项目批量转为收藏项目（在一个事务中添加收藏并移出历史）
+pub fn move_clipboard_items_to_favorites(ids: &[i64], items: &[FavoriteItem]) -> Result<(), String> {
+    with_connection(|conn| insert_favorites_replacing_history(conn, ids, items))
+}
+
+fn insert_favorites_replacing_history(conn: &Connection, ids: &[i64], items: &[FavoriteItem]) -> SqliteResult<()> {
+    let tx = conn.unchecked_transaction()?;
+    for item in items {
+        tx.execute(
+            "INSERT INTO favorites (id, title, content, html_content, content_type, image_id, group_name, item_order, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![item.id, item.title, item.content, item.html_content, item.content_type.to_string(), item.image_id, item.group_name, item.item_order, item.created_at, item.updated_at],
+        )?;
+    }
+    for id in ids {
+        tx.execute("DELETE FROM clipboard WHERE id = ?1", params![id])?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
 // 获取所有收藏项目
 pub fn get_all_favorite_items() -> Result<Vec<FavoriteItem>, String> {
     with_connection(|conn| {
@@ -1088,6 +1867,42 @@ pub fn get_all_groups() -> Result<Vec<GroupInfo>, String> {
     })
 }
 
+// 获取最近使用的分组（按最近使用时间倒序）
+pub fn get_recent_groups(limit: usize) -> Result<Vec<GroupInfo>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT name, icon, order_index,
+                (SELECT COUNT(*) FROM favorites WHERE favorites.group_name = groups.name)
+             FROM groups
+             WHERE last_used_at IS NOT NULL
+             ORDER BY last_used_at DESC
+             LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            Ok(GroupInfo {
+                name: row.get(0)?,
+                icon: row.get(1)?,
+                order: row.get(2)?,
+                item_count: row.get(3)?,
+            })
+        })?;
+
+        rows.collect()
+    })
+}
+
+// 记录分组的最近使用时间
+pub fn touch_group_usage(group_name: &str) -> Result<(), String> {
+    let now = chrono::Local::now().timestamp_millis();
+    with_connection(|conn| {
+        conn.execute(
+            "UPDATE groups SET last_used_at = ?1 WHERE name = ?2",
+            params![now, group_name],
+        )?;
+        Ok(())
+    })
+}
+
 // 更新分组信息（批量更新指定分组的所有项目）
 pub fn update_group_info(old_name: &str, new_name: &str, new_icon: &str, new_order: i32) -> Result<(), String> {
     let now = chrono::Local::now().timestamp();
@@ -1228,3 +2043,145 @@ pub fn create_group(name: &str, icon: &str) -> Result<(), String> {
         None => Err("数据库未初始化".to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert_image_item(conn: &Connection, image_id: &str) {
+        conn.execute(
+            "INSERT INTO clipboard (content, content_type, image_id, item_order, created_at, updated_at)
+             VALUES (?1, 'image', ?2, 0, 0, 0)",
+            params![format!("image:{}", image_id), image_id],
+        )
+        .unwrap();
+    }
+
+    fn insert_text_item(conn: &Connection, content: &str, item_order: i64, pinned: bool) {
+        conn.execute(
+            "INSERT INTO clipboard (content, content_type, item_order, created_at, updated_at, pinned)
+             VALUES (?1, 'text', ?2, 0, 0, ?3)",
+            params![content, item_order, pinned as i64],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn history_limit_keeps_pinned_items() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        insert_text_item(&conn, "newest", 0, false);
+        insert_text_item(&conn, "newer", 1, false);
+        insert_text_item(&conn, "old", 2, false);
+        insert_text_item(&conn, "old pinned", 3, true);
+
+        delete_history_over_limit(&conn, 2, true).unwrap();
+
+        let mut stmt = conn.prepare("SELECT content FROM clipboard ORDER BY item_order").unwrap();
+        let remaining: Vec<String> = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+        // 固定项目既不占用名额也不会被删除
+        assert_eq!(remaining, vec!["newest", "newer", "old pinned"]);
+    }
+
+    #[test]
+    fn history_limit_keeps_favorite_slot_items() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        insert_text_item(&conn, "newest", 0, false);
+        insert_text_item(&conn, "newer", 1, false);
+        insert_text_item(&conn, "old", 2, false);
+        insert_text_item(&conn, "old in slot", 3, false);
+        let slot_item_id = conn.last_insert_rowid();
+        conn.execute(
+            "INSERT INTO favorite_slots (slot, item_id, updated_at) VALUES (0, ?1, 0)",
+            params![slot_item_id],
+        )
+        .unwrap();
+
+        delete_history_over_limit(&conn, 2, false).unwrap();
+
+        let mut stmt = conn.prepare("SELECT content FROM clipboard ORDER BY item_order").unwrap();
+        let remaining: Vec<String> = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+        // 收藏栏引用的项目同样不占用名额也不会被删除
+        assert_eq!(remaining, vec!["newest", "newer", "old in slot"]);
+    }
+
+    #[test]
+    fn dedup_key_lookup_uses_stored_keys() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        insert_text_item(&conn, "  foo  ", 0, false);
+        let id = conn.last_insert_rowid();
+        insert_image_item(&conn, "img");
+
+        let trimmed = |content: &str| (!content.starts_with("image:")).then(|| content.trim().to_string());
+        fill_dedup_keys(&conn, trimmed).unwrap();
+
+        assert_eq!(query_clipboard_item_by_dedup_key(&conn, "foo").unwrap(), Some(id));
+        assert_eq!(query_clipboard_item_by_dedup_key(&conn, "image:img").unwrap(), None);
+
+        // 内容修改后清空的键会在下次补齐
+        conn.execute("UPDATE clipboard SET content = ' bar', dedup_key = NULL WHERE id = ?1", [id]).unwrap();
+        assert_eq!(query_clipboard_item_by_dedup_key(&conn, "foo").unwrap(), None);
+        fill_dedup_keys(&conn, trimmed).unwrap();
+        assert_eq!(query_clipboard_item_by_dedup_key(&conn, "bar").unwrap(), Some(id));
+    }
+
+    #[test]
+    fn moving_long_text_to_favorites_keeps_full_content() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        let long_text = "长".repeat(MAX_CONTENT_LENGTH_FOR_DISPLAY);
+        insert_text_item(&conn, &long_text, 0, false);
+        let id = conn.last_insert_rowid();
+
+        let items = query_clipboard_items_full(&conn, &[id]).unwrap();
+        let favorite = FavoriteItem {
+            id: "moved".to_string(),
+            title: String::new(),
+            content: items[0].content.clone(),
+            html_content: None,
+            content_type: items[0].content_type.clone(),
+            image_id: None,
+            group_name: "全部".to_string(),
+            item_order: 0,
+            created_at: 0,
+            updated_at: 0,
+        };
+        insert_favorites_replacing_history(&conn, &[id], &[favorite]).unwrap();
+
+        let stored: String = conn
+            .query_row("SELECT content FROM favorites WHERE id = 'moved'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, long_text);
+        let remaining: i64 = conn.query_row("SELECT COUNT(*) FROM clipboard", [], |row| row.get(0)).unwrap();
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn persisted_images_survive_ephemeral_delete() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        insert_image_item(&conn, "persisted");
+
+        create_ephemeral_tables(&conn).unwrap();
+        insert_image_item(&conn, "ephemeral");
+        // 临时模式下的删除只作用于内存表
+        conn.execute("DELETE FROM clipboard WHERE image_id = 'ephemeral'", []).unwrap();
+
+        let image_ids: Vec<String> = collect_image_references(&conn)
+            .unwrap()
+            .into_iter()
+            .filter_map(|(image_id, _)| image_id)
+            .collect();
+        assert_eq!(image_ids, vec!["persisted".to_string()]);
+    }
+}
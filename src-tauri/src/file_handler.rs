@@ -18,11 +18,93 @@ pub struct FileInfo {
 pub struct FileClipboardData {
     pub files: Vec<FileInfo>,
     pub operation: String, // "copy" 或 "cut"
+    // 原始选择的文件数量（超过上限时 files 只保留前一部分），旧记录为 0
+    #[serde(default)]
+    pub total_count: usize,
+    // 原始选择中所有文件的总大小（不含文件夹内容），旧记录为 0
+    #[serde(default)]
+    pub total_size: u64,
 }
 
-// 将文件路径写入剪贴板
-#[cfg(windows)]
+// 文件类型历史项的摘要
+#[derive(Debug, Clone, Serialize)]
+pub struct FilesEntrySummary {
+    pub total_count: usize,
+    pub stored_count: usize,
+    pub total_size: u64,
+    pub truncated: bool,
+}
+
+impl FileClipboardData {
+    // 根据文件路径创建，超过 max_files 时只保存前 max_files 个文件的信息，摘要仍统计全部
+    pub fn from_paths(paths: &[String], max_files: usize) -> Result<Self, String> {
+        let total_size = paths
+            .iter()
+            .filter_map(|path| fs::metadata(path).ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum();
+
+        let files = paths
+            .iter()
+            .take(max_files.max(1))
+            .filter_map(|path| get_file_info(path).ok())
+            .collect::<Vec<_>>();
+
+        if files.is_empty() {
+            return Err("没有可用的文件".to_string());
+        }
+
+        Ok(Self {
+            files,
+            operation: "copy".to_string(),
+            total_count: paths.len(),
+            total_size,
+        })
+    }
+
+    // 生成摘要（兼容没有统计字段的旧记录）
+    pub fn summary(&self) -> FilesEntrySummary {
+        let stored_count = self.files.len();
+        let total_count = self.total_count.max(stored_count);
+        let total_size = if self.total_count == 0 {
+            self.files
+                .iter()
+                .filter(|file| !file.is_directory)
+                .map(|file| file.size)
+                .sum()
+        } else {
+            self.total_size
+        };
+
+        FilesEntrySummary {
+            total_count,
+            stored_count,
+            total_size,
+            truncated: total_count > stored_count,
+        }
+    }
+}
+
+// 解析 files: 条目的摘要
+pub fn summarize_files_entry(content: &str) -> Result<FilesEntrySummary, String> {
+    let files_json = content
+        .strip_prefix("files:")
+        .ok_or("无效的文件数据格式")?;
+    let data: FileClipboardData =
+        serde_json::from_str(files_json).map_err(|e| format!("解析文件数据失败: {}", e))?;
+    Ok(data.summary())
+}
+
+// 将文件路径写入剪贴板（文件列表格式）
 pub fn set_clipboard_files(file_paths: &[String]) -> Result<(), String> {
+    set_clipboard_files_as(file_paths, "hdrop")
+}
+
+// 按指定格式将文件路径写入剪贴板
+// mode: hdrop 文件列表 | text_paths 路径文本 | both 两种格式同时写入
+#[cfg(windows)]
+pub fn set_clipboard_files_as(file_paths: &[String], mode: &str) -> Result<(), String> {
     use std::ffi::OsStr;
     use std::os::windows::ffi::OsStrExt;
     use windows::Win32::Foundation::{HANDLE, HWND};
@@ -30,7 +112,11 @@ pub fn set_clipboard_files(file_paths: &[String]) -> Result<(), String> {
         CloseClipboard, EmptyClipboard, OpenClipboard, SetClipboardData,
     };
     use windows::Win32::System::Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
-    use windows::Win32::System::Ole::CF_HDROP;
+    use windows::Win32::System::Ole::{CF_HDROP, CF_UNICODETEXT};
+
+    if !matches!(mode, "hdrop" | "text_paths" | "both") {
+        return Err(format!("无效的文件剪贴板格式: {}", mode));
+    }
 
     unsafe {
         // 打开剪贴板
@@ -44,6 +130,39 @@ pub fn set_clipboard_files(file_paths: &[String]) -> Result<(), String> {
             return Err("无法清空剪贴板".to_string());
         }
 
+        // 写入路径文本（每行一个路径）
+        if mode != "hdrop" {
+            let wide_text: Vec<u16> = file_paths
+                .join("\r\n")
+                .encode_utf16()
+                .chain(std::iter::once(0))
+                .collect();
+            let hmem = match GlobalAlloc(GMEM_MOVEABLE, wide_text.len() * 2) {
+                Ok(h) => h,
+                Err(_) => {
+                    let _ = CloseClipboard();
+                    return Err("无法分配内存".to_string());
+                }
+            };
+            let ptr = GlobalLock(hmem) as *mut u16;
+            if ptr.is_null() {
+                let _ = CloseClipboard();
+                return Err("无法锁定内存".to_string());
+            }
+            std::ptr::copy_nonoverlapping(wide_text.as_ptr(), ptr, wide_text.len());
+            let _ = GlobalUnlock(hmem);
+
+            if SetClipboardData(CF_UNICODETEXT.0 as u32, HANDLE(hmem.0 as isize)).is_err() {
+                let _ = CloseClipboard();
+                return Err("无法设置剪贴板数据".to_string());
+            }
+
+            if mode == "text_paths" {
+                let _ = CloseClipboard();
+                return Ok(());
+            }
+        }
+
         // 计算所需内存大小
         let mut total_size = std::mem::size_of::<windows::Win32::UI::Shell::DROPFILES>();
         for path in file_paths {
@@ -114,7 +233,7 @@ pub fn set_clipboard_files(file_paths: &[String]) -> Result<(), String> {
 }
 
 #[cfg(not(windows))]
-pub fn set_clipboard_files(_file_paths: &[String]) -> Result<(), String> {
+pub fn set_clipboard_files_as(_file_paths: &[String], _mode: &str) -> Result<(), String> {
     Err("当前平台不支持文件剪贴板操作".to_string())
 }
 
@@ -200,6 +319,28 @@ pub fn get_clipboard_files() -> Result<Vec<String>, String> {
     Err("当前平台不支持文件剪贴板操作".to_string())
 }
 
+// 解析 files: 条目中的文件路径
+pub fn parse_files_entry_paths(content: &str) -> Result<Vec<String>, String> {
+    let files_json = content
+        .strip_prefix("files:")
+        .ok_or("无效的文件数据格式")?;
+    let files_data: serde_json::Value =
+        serde_json::from_str(files_json).map_err(|e| format!("解析文件数据失败: {}", e))?;
+
+    let files = files_data["files"].as_array().ok_or("文件数据格式错误")?;
+
+    Ok(files
+        .iter()
+        .filter_map(|file| file["path"].as_str())
+        .map(|path| path.to_string())
+        .collect())
+}
+
+// 检查每个文件路径是否仍然存在
+pub fn validate_file_paths(paths: &[String]) -> Vec<bool> {
+    paths.iter().map(|path| Path::new(path).exists()).collect()
+}
+
 // 获取文件信息
 pub fn get_file_info(path: &str) -> Result<FileInfo, String> {
     let path_buf = PathBuf::from(path);
@@ -667,3 +808,24 @@ fn generate_unique_path(path: &Path) -> Result<PathBuf, String> {
 
     Err("无法生成唯一文件名".to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_legacy_and_truncated_entries() {
+        let legacy = r#"files:{"files":[{"path":"C:\\a.txt","name":"a.txt","size":10,"is_directory":false,"icon_data":null,"file_type":"TXT"},{"path":"C:\\b","name":"b","size":0,"is_directory":true,"icon_data":null,"file_type":"文件夹"}],"operation":"copy"}"#;
+        let summary = summarize_files_entry(legacy).unwrap();
+        assert_eq!(summary.total_count, 2);
+        assert_eq!(summary.total_size, 10);
+        assert!(!summary.truncated);
+
+        let truncated = r#"files:{"files":[{"path":"C:\\a.txt","name":"a.txt","size":10,"is_directory":false,"icon_data":null,"file_type":"TXT"}],"operation":"copy","total_count":300,"total_size":4096}"#;
+        let summary = summarize_files_entry(truncated).unwrap();
+        assert_eq!(summary.stored_count, 1);
+        assert_eq!(summary.total_count, 300);
+        assert_eq!(summary.total_size, 4096);
+        assert!(summary.truncated);
+    }
+}
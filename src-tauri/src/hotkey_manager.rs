@@ -10,9 +10,23 @@ static MAIN_WINDOW_HANDLE: OnceCell<tauri::WebviewWindow> = OnceCell::new();
 static CURRENT_TOGGLE_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
 static CURRENT_PREVIEW_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
 static CURRENT_SCREENSHOT_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
+static CURRENT_LAST_REGION_SCREENSHOT_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
 static CURRENT_NUMBER_SHORTCUTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static CURRENT_QUICK_TEXT_CAPTURE_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
+static CURRENT_SETTINGS_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
+static CURRENT_CYCLE_WINDOW_SIZE_SHORTCUT: Mutex<Option<String>> = Mutex::new(None);
+static CURRENT_TRANSLATION_LANGUAGE_SHORTCUTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static CURRENT_RELATIVE_PASTE_SHORTCUTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
 static HOTKEYS_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
 
+// 快捷键及其用途
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ShortcutInfo {
+    pub key: String, // 对应的设置项
+    pub shortcut: String,
+    pub purpose: String,
+}
+
 
 // 初始化热键管理器
 pub fn initialize_hotkey_manager(app_handle: tauri::AppHandle, window: tauri::WebviewWindow) {
@@ -130,6 +144,429 @@ pub fn unregister_screenshot_hotkey() {
     }
 }
 
+// 注册重截上次区域的快捷键
+pub fn register_last_region_screenshot_hotkey(shortcut_str: &str) -> Result<(), String> {
+    let app_handle = APP_HANDLE.get().ok_or("热键管理器未初始化")?;
+
+    unregister_last_region_screenshot_hotkey();
+
+    let shortcut = parse_shortcut(shortcut_str)
+        .map_err(|e| format!("解析快捷键失败: {}", e))?;
+
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut.clone(), move |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                handle_last_region_screenshot_hotkey(app);
+            }
+        })
+        .map_err(|e| format!("注册重截上次区域快捷键失败: {}", e))?;
+
+    *CURRENT_LAST_REGION_SCREENSHOT_SHORTCUT.lock().unwrap() = Some(shortcut_str.to_string());
+
+    println!("已注册重截上次区域快捷键: {}", shortcut_str);
+    Ok(())
+}
+
+// 注销重截上次区域的快捷键
+pub fn unregister_last_region_screenshot_hotkey() {
+    if let Some(app_handle) = APP_HANDLE.get() {
+        if let Some(shortcut_str) = CURRENT_LAST_REGION_SCREENSHOT_SHORTCUT.lock().unwrap().take() {
+            if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
+                let _ = app_handle.global_shortcut().unregister(shortcut);
+                println!("已注销重截上次区域快捷键: {}", shortcut_str);
+            }
+        }
+    }
+}
+
+// 更新重截上次区域的快捷键（为空时注销）
+pub fn update_last_region_screenshot_hotkey(shortcut_str: &str) -> Result<(), String> {
+    if shortcut_str.is_empty() {
+        unregister_last_region_screenshot_hotkey();
+        return Ok(());
+    }
+    register_last_region_screenshot_hotkey(shortcut_str)
+}
+
+// 注册选中文本保存为常用文本的快捷键
+pub fn register_quick_text_capture_hotkey(shortcut_str: &str) -> Result<(), String> {
+    let app_handle = APP_HANDLE.get().ok_or("热键管理器未初始化")?;
+
+    unregister_quick_text_capture_hotkey();
+
+    let shortcut = parse_shortcut(shortcut_str)
+        .map_err(|e| format!("解析快捷键失败: {}", e))?;
+
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut.clone(), move |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                handle_quick_text_capture_hotkey(app);
+            }
+        })
+        .map_err(|e| format!("注册常用文本采集快捷键失败: {}", e))?;
+
+    *CURRENT_QUICK_TEXT_CAPTURE_SHORTCUT.lock().unwrap() = Some(shortcut_str.to_string());
+
+    println!("已注册常用文本采集快捷键: {}", shortcut_str);
+    Ok(())
+}
+
+// 注销选中文本保存为常用文本的快捷键
+pub fn unregister_quick_text_capture_hotkey() {
+    if let Some(app_handle) = APP_HANDLE.get() {
+        if let Some(shortcut_str) = CURRENT_QUICK_TEXT_CAPTURE_SHORTCUT.lock().unwrap().take() {
+            if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
+                let _ = app_handle.global_shortcut().unregister(shortcut);
+                println!("已注销常用文本采集快捷键: {}", shortcut_str);
+            }
+        }
+    }
+}
+
+// 更新选中文本保存为常用文本的快捷键（为空时注销）
+pub fn update_quick_text_capture_hotkey(shortcut_str: &str) -> Result<(), String> {
+    if shortcut_str.is_empty() {
+        unregister_quick_text_capture_hotkey();
+        return Ok(());
+    }
+    register_quick_text_capture_hotkey(shortcut_str)
+}
+
+// 注册打开设置窗口的快捷键
+pub fn register_settings_hotkey(shortcut_str: &str) -> Result<(), String> {
+    let app_handle = APP_HANDLE.get().ok_or("热键管理器未初始化")?;
+
+    unregister_settings_hotkey();
+
+    let shortcut = parse_shortcut(shortcut_str)
+        .map_err(|e| format!("解析快捷键失败: {}", e))?;
+
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut.clone(), move |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                handle_settings_hotkey(app);
+            }
+        })
+        .map_err(|e| format!("注册设置窗口快捷键失败: {}", e))?;
+
+    *CURRENT_SETTINGS_SHORTCUT.lock().unwrap() = Some(shortcut_str.to_string());
+
+    println!("已注册设置窗口快捷键: {}", shortcut_str);
+    Ok(())
+}
+
+// 注销打开设置窗口的快捷键
+pub fn unregister_settings_hotkey() {
+    if let Some(app_handle) = APP_HANDLE.get() {
+        if let Some(shortcut_str) = CURRENT_SETTINGS_SHORTCUT.lock().unwrap().take() {
+            if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
+                let _ = app_handle.global_shortcut().unregister(shortcut);
+                println!("已注销设置窗口快捷键: {}", shortcut_str);
+            }
+        }
+    }
+}
+
+// 更新打开设置窗口的快捷键（为空时注销）
+pub fn update_settings_hotkey(shortcut_str: &str) -> Result<(), String> {
+    if shortcut_str.is_empty() {
+        unregister_settings_hotkey();
+        return Ok(());
+    }
+    register_settings_hotkey(shortcut_str)
+}
+
+// 注册切换窗口大小预设的快捷键
+pub fn register_cycle_window_size_hotkey(shortcut_str: &str) -> Result<(), String> {
+    let app_handle = APP_HANDLE.get().ok_or("热键管理器未初始化")?;
+
+    unregister_cycle_window_size_hotkey();
+
+    let shortcut = parse_shortcut(shortcut_str)
+        .map_err(|e| format!("解析快捷键失败: {}", e))?;
+
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut.clone(), move |_app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                if let Some(window) = MAIN_WINDOW_HANDLE.get() {
+                    if let Err(e) = crate::window_management::cycle_window_size(window) {
+                        eprintln!("切换窗口大小失败: {}", e);
+                    }
+                }
+            }
+        })
+        .map_err(|e| format!("注册切换窗口大小快捷键失败: {}", e))?;
+
+    *CURRENT_CYCLE_WINDOW_SIZE_SHORTCUT.lock().unwrap() = Some(shortcut_str.to_string());
+
+    println!("已注册切换窗口大小快捷键: {}", shortcut_str);
+    Ok(())
+}
+
+// 注销切换窗口大小预设的快捷键
+pub fn unregister_cycle_window_size_hotkey() {
+    if let Some(app_handle) = APP_HANDLE.get() {
+        if let Some(shortcut_str) = CURRENT_CYCLE_WINDOW_SIZE_SHORTCUT.lock().unwrap().take() {
+            if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
+                let _ = app_handle.global_shortcut().unregister(shortcut);
+                println!("已注销切换窗口大小快捷键: {}", shortcut_str);
+            }
+        }
+    }
+}
+
+// 更新切换窗口大小预设的快捷键（为空时注销）
+pub fn update_cycle_window_size_hotkey(shortcut_str: &str) -> Result<(), String> {
+    if shortcut_str.is_empty() {
+        unregister_cycle_window_size_hotkey();
+        return Ok(());
+    }
+    register_cycle_window_size_hotkey(shortcut_str)
+}
+
+// 注册一组快捷键，记录成功注册的快捷键以便整体注销
+fn register_shortcut_list<F>(
+    entries: Vec<(String, F)>,
+    current: &Mutex<Vec<String>>,
+    label: &str,
+) -> Result<(), String>
+where
+    F: Fn(&tauri::AppHandle) + Send + Sync + 'static,
+{
+    let app_handle = APP_HANDLE.get().ok_or("热键管理器未初始化")?;
+
+    unregister_shortcut_list(current);
+
+    let mut registered_shortcuts = Vec::new();
+    for (shortcut_str, handler) in entries {
+        let shortcut = match parse_shortcut(&shortcut_str) {
+            Ok(shortcut) => shortcut,
+            Err(e) => {
+                eprintln!("解析{}失败 {}: {}", label, shortcut_str, e);
+                continue;
+            }
+        };
+
+        match app_handle
+            .global_shortcut()
+            .on_shortcut(shortcut, move |app, _shortcut, event| {
+                if event.state == ShortcutState::Pressed {
+                    handler(app);
+                }
+            })
+        {
+            Ok(_) => registered_shortcuts.push(shortcut_str),
+            Err(e) => eprintln!("注册{}失败 {}: {}", label, shortcut_str, e),
+        }
+    }
+
+    println!("已注册 {} 个{}", registered_shortcuts.len(), label);
+    *current.lock().unwrap() = registered_shortcuts;
+    Ok(())
+}
+
+// 注销一组快捷键
+fn unregister_shortcut_list(current: &Mutex<Vec<String>>) {
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let shortcuts = std::mem::take(&mut *current.lock().unwrap());
+        for shortcut_str in shortcuts {
+            if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
+                let _ = app_handle.global_shortcut().unregister(shortcut);
+            }
+        }
+    }
+}
+
+// 注册翻译到指定语言的快捷键
+pub fn register_translation_language_hotkeys(
+    hotkeys: &[crate::settings::TranslationLanguageHotkey],
+) -> Result<(), String> {
+    let entries = hotkeys
+        .iter()
+        .filter(|hotkey| !hotkey.shortcut.is_empty() && !hotkey.language.is_empty())
+        .map(|hotkey| {
+            let language = hotkey.language.clone();
+            (hotkey.shortcut.clone(), move |app: &tauri::AppHandle| {
+                handle_translation_language_hotkey(app, language.clone())
+            })
+        })
+        .collect();
+    register_shortcut_list(entries, &CURRENT_TRANSLATION_LANGUAGE_SHORTCUTS, "翻译语言快捷键")
+}
+
+// 注销翻译到指定语言的快捷键
+pub fn unregister_translation_language_hotkeys() {
+    unregister_shortcut_list(&CURRENT_TRANSLATION_LANGUAGE_SHORTCUTS);
+}
+
+// 注册粘贴之前第 N 条记录的快捷键
+pub fn register_relative_paste_hotkeys(
+    hotkeys: &[crate::settings::RelativePasteHotkey],
+) -> Result<(), String> {
+    let entries = hotkeys
+        .iter()
+        .filter(|hotkey| !hotkey.shortcut.is_empty())
+        .map(|hotkey| {
+            let offset = hotkey.offset;
+            (hotkey.shortcut.clone(), move |_app: &tauri::AppHandle| {
+                handle_relative_paste_hotkey(offset)
+            })
+        })
+        .collect();
+    register_shortcut_list(entries, &CURRENT_RELATIVE_PASTE_SHORTCUTS, "相对历史粘贴快捷键")
+}
+
+// 注销粘贴之前第 N 条记录的快捷键
+pub fn unregister_relative_paste_hotkeys() {
+    unregister_shortcut_list(&CURRENT_RELATIVE_PASTE_SHORTCUTS);
+}
+
+// 处理相对历史粘贴快捷键（不打开主窗口）
+fn handle_relative_paste_hotkey(offset: usize) {
+    if let Some(window) = MAIN_WINDOW_HANDLE.get() {
+        let window_clone = window.clone();
+        std::thread::spawn(move || {
+            #[cfg(windows)]
+            {
+                use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+                let hwnd = unsafe { GetForegroundWindow() };
+                crate::window_management::set_last_focus_hwnd(hwnd.0);
+            }
+
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = crate::services::paste_service::paste_relative_history(offset, window_clone).await {
+                    eprintln!("相对历史粘贴失败: {}", e);
+                }
+            });
+        });
+    }
+}
+
+// 列出设置中的全部快捷键：RegisterHotKey 注册的全局快捷键和键盘钩子处理的快捷键
+pub fn collect_settings_shortcuts(settings: &crate::settings::AppSettings) -> Vec<ShortcutInfo> {
+    let mut shortcuts = Vec::new();
+    let mut push = |key: &str, shortcut: &str, purpose: String| {
+        if !shortcut.trim().is_empty() {
+            shortcuts.push(ShortcutInfo {
+                key: key.to_string(),
+                shortcut: shortcut.trim().to_string(),
+                purpose,
+            });
+        }
+    };
+
+    // 全局快捷键（主窗口与预览窗口快捷键为空时使用默认值）
+    let toggle_shortcut = if settings.toggle_shortcut.is_empty() { "Alt+V" } else { &settings.toggle_shortcut };
+    let preview_shortcut = if settings.preview_shortcut.is_empty() { "Ctrl+`" } else { &settings.preview_shortcut };
+    push("toggleShortcut", toggle_shortcut, "显示/隐藏主窗口".to_string());
+    push("previewShortcut", preview_shortcut, "预览窗口".to_string());
+    push("screenshot_shortcut", &settings.screenshot_shortcut, "截屏".to_string());
+    push(
+        "screenshot_last_region_shortcut",
+        &settings.screenshot_last_region_shortcut,
+        "重截上次区域".to_string(),
+    );
+    push("quickTextCaptureShortcut", &settings.quick_text_capture_shortcut, "采集为常用文本".to_string());
+    push("settingsShortcut", &settings.settings_shortcut, "打开设置窗口".to_string());
+    push("cycleWindowSizeShortcut", &settings.cycle_window_size_shortcut, "切换窗口大小".to_string());
+
+    if settings.number_shortcuts {
+        let modifier = if settings.number_shortcuts_modifier.is_empty() {
+            "Ctrl"
+        } else {
+            &settings.number_shortcuts_modifier
+        };
+        for num in 1..=9 {
+            push("numberShortcuts", &format!("{}+{}", modifier, num), format!("粘贴第 {} 条记录", num));
+        }
+    }
+
+    for hotkey in &settings.ai_language_hotkeys {
+        if !hotkey.language.is_empty() {
+            push("aiLanguageHotkeys", &hotkey.shortcut, format!("翻译为 {}", hotkey.language));
+        }
+    }
+
+    for hotkey in &settings.relative_paste_hotkeys {
+        push("relativePasteHotkeys", &hotkey.shortcut, format!("粘贴之前第 {} 条记录", hotkey.offset));
+    }
+
+    // 键盘钩子处理的快捷键（窗口激活时生效，固定快捷键可设为全局）
+    let hook_shortcuts = [
+        ("navigateUpShortcut", &settings.navigate_up_shortcut, "向上选择"),
+        ("navigateDownShortcut", &settings.navigate_down_shortcut, "向下选择"),
+        ("tabLeftShortcut", &settings.tab_left_shortcut, "切换到左侧标签"),
+        ("tabRightShortcut", &settings.tab_right_shortcut, "切换到右侧标签"),
+        ("focusSearchShortcut", &settings.focus_search_shortcut, "聚焦搜索框"),
+        ("hideWindowShortcut", &settings.hide_window_shortcut, "隐藏窗口"),
+        ("executeItemShortcut", &settings.execute_item_shortcut, "执行选中项"),
+        ("previousGroupShortcut", &settings.previous_group_shortcut, "上一个分组"),
+        ("nextGroupShortcut", &settings.next_group_shortcut, "下一个分组"),
+        ("togglePinShortcut", &settings.toggle_pin_shortcut, "切换窗口固定"),
+    ];
+    for (key, shortcut, purpose) in hook_shortcuts {
+        push(key, shortcut, purpose.to_string());
+    }
+
+    shortcuts
+}
+
+// 检查快捷键是否与设置中的其他快捷键冲突，exclude_key 为正在编辑的设置项，返回冲突项的用途
+pub fn find_shortcut_conflict(
+    settings: &crate::settings::AppSettings,
+    shortcut_str: &str,
+    exclude_key: Option<&str>,
+) -> Option<String> {
+    if shortcut_str.trim().is_empty() {
+        return None;
+    }
+
+    collect_settings_shortcuts(settings)
+        .into_iter()
+        .filter(|info| Some(info.key.as_str()) != exclude_key)
+        .find(|info| shortcuts_equal(&info.shortcut, shortcut_str))
+        .map(|info| info.purpose)
+}
+
+// 保存前校验快捷键冲突，只报告本次新增或修改的快捷键引起的冲突
+pub fn validate_settings_shortcuts(
+    settings: &crate::settings::AppSettings,
+    previous: Option<&crate::settings::AppSettings>,
+) -> Result<(), String> {
+    let shortcuts = collect_settings_shortcuts(settings);
+    let previous_shortcuts = previous.map(collect_settings_shortcuts).unwrap_or_default();
+    let is_unchanged = |info: &ShortcutInfo| {
+        previous_shortcuts
+            .iter()
+            .any(|old| old.key == info.key && old.purpose == info.purpose && old.shortcut == info.shortcut)
+    };
+
+    for (i, a) in shortcuts.iter().enumerate() {
+        for b in &shortcuts[i + 1..] {
+            if !shortcuts_equal(&a.shortcut, &b.shortcut) {
+                continue;
+            }
+            if is_unchanged(a) && is_unchanged(b) {
+                continue;
+            }
+            return Err(format!("快捷键冲突: {}与{}都使用了 {}", a.purpose, b.purpose, a.shortcut));
+        }
+    }
+    Ok(())
+}
+
+// 判断两个快捷键是否相同（按解析结果比较，忽略大小写和别名差异）
+fn shortcuts_equal(a: &str, b: &str) -> bool {
+    match (parse_shortcut(a), parse_shortcut(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.trim().eq_ignore_ascii_case(b.trim()),
+    }
+}
+
 // 注册数字快捷键 (1-9)
 pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
     let app_handle = APP_HANDLE.get().ok_or("热键管理器未初始化")?;
@@ -179,7 +616,7 @@ pub fn unregister_number_shortcuts() {
     }
 }
 
-// 处理数字快捷键
+// 处理数字快捷键（始终按最新优先计数，与 history_order 设置无关）
 fn handle_number_shortcut(_app: &tauri::AppHandle, index: usize) {
     if let Some(window) = MAIN_WINDOW_HANDLE.get() {
         let window_clone = window.clone();
@@ -220,7 +657,12 @@ pub fn unregister_all_hotkeys() {
     unregister_toggle_hotkey();
     unregister_preview_hotkey();
     unregister_screenshot_hotkey();
+    unregister_last_region_screenshot_hotkey();
     unregister_number_shortcuts();
+    unregister_quick_text_capture_hotkey();
+    unregister_settings_hotkey();
+    unregister_translation_language_hotkeys();
+    unregister_relative_paste_hotkeys();
 }
 
 // 更新主窗口切换快捷键
@@ -256,6 +698,27 @@ pub fn enable_hotkeys() -> Result<(), String> {
     if !settings.screenshot_shortcut.is_empty() {
         register_screenshot_hotkey(&settings.screenshot_shortcut)?;
     }
+
+    // 注册重截上次区域快捷键
+    if !settings.screenshot_last_region_shortcut.is_empty() {
+        register_last_region_screenshot_hotkey(&settings.screenshot_last_region_shortcut)?;
+    }
+    
+    // 注册常用文本采集快捷键
+    if !settings.quick_text_capture_shortcut.is_empty() {
+        register_quick_text_capture_hotkey(&settings.quick_text_capture_shortcut)?;
+    }
+    
+    // 注册设置窗口快捷键
+    if !settings.settings_shortcut.is_empty() {
+        register_settings_hotkey(&settings.settings_shortcut)?;
+    }
+    
+    // 注册翻译语言快捷键
+    register_translation_language_hotkeys(&settings.ai_language_hotkeys)?;
+
+    // 注册相对历史粘贴快捷键
+    register_relative_paste_hotkeys(&settings.relative_paste_hotkeys)?;
     
     // 注册数字快捷键
     #[cfg(windows)]
@@ -374,6 +837,12 @@ fn handle_screenshot_hotkey(app: &tauri::AppHandle) {
         }
     }
 
+    // 截取大尺寸屏幕尚未完成时，再次按下快捷键取消本次截屏
+    if crate::screenshot::ScreenshotWindowManager::is_capturing() {
+        crate::screenshot::ScreenshotWindowManager::cancel_capture();
+        return;
+    }
+
     let app_handle = app.clone();
     std::thread::spawn(move || {
         if let Err(e) = crate::commands::start_builtin_screenshot(app_handle) {
@@ -382,6 +851,96 @@ fn handle_screenshot_hotkey(app: &tauri::AppHandle) {
     });
 }
 
+// 处理重截上次区域热键（不显示截屏窗口）
+fn handle_last_region_screenshot_hotkey(app: &tauri::AppHandle) {
+    if !crate::settings::get_global_settings().screenshot_enabled {
+        return;
+    }
+
+    let app_handle = app.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = crate::screenshot::take_last_region_screenshot(app_handle) {
+            eprintln!("重截上次区域失败: {}", e);
+        }
+    });
+}
+
+// 处理设置窗口热键（已打开时仅恢复并聚焦）
+fn handle_settings_hotkey(app: &tauri::AppHandle) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = crate::commands::open_settings_window(app_handle).await {
+            eprintln!("打开设置窗口失败: {}", e);
+        }
+    });
+}
+
+// 处理翻译到指定语言的热键
+fn handle_translation_language_hotkey(_app: &tauri::AppHandle, language: String) {
+    let settings = crate::settings::get_global_settings();
+    if !settings.enable_ai || !settings.ai_translation_enabled {
+        return;
+    }
+
+    #[cfg(windows)]
+    if settings.app_filter_enabled && !crate::app_filter::is_current_app_allowed() {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = crate::services::translation_service::translate_clipboard_to(language).await {
+            eprintln!("翻译失败: {}", e);
+        }
+    });
+}
+
+// 处理常用文本采集热键
+fn handle_quick_text_capture_hotkey(app: &tauri::AppHandle) {
+    let settings = crate::settings::get_global_settings();
+
+    if settings.app_filter_enabled {
+        #[cfg(windows)]
+        if !crate::app_filter::is_current_app_allowed() {
+            return;
+        }
+    }
+
+    let app_handle = app.clone();
+    std::thread::spawn(move || {
+        use tauri::{Emitter, Manager};
+        use tauri_plugin_notification::NotificationExt;
+
+        let group_name = if settings.quick_text_capture_group.is_empty() {
+            crate::quick_texts::resolve_default_group()
+        } else {
+            settings.quick_text_capture_group.clone()
+        };
+
+        let body = match crate::services::quick_text_service::QuickTextService::add_from_selection(group_name) {
+            Ok(item) => {
+                if let Some(main_window) = app_handle.get_webview_window("main") {
+                    let _ = main_window.emit("refreshQuickTexts", ());
+                }
+                if let Some(preview_window) = app_handle.get_webview_window("preview") {
+                    let _ = preview_window.emit("quick-texts-updated", ());
+                }
+                format!("已添加到常用文本: {}", item.title)
+            }
+            Err(e) => {
+                eprintln!("采集常用文本失败: {}", e);
+                format!("添加常用文本失败: {}", e)
+            }
+        };
+
+        let _ = app_handle
+            .notification()
+            .builder()
+            .title("QuickClipboard")
+            .body(&body)
+            .show();
+    });
+}
+
 fn parse_shortcut(shortcut_str: &str) -> Result<Shortcut, String> {
     
     let normalized = shortcut_str
@@ -6,7 +6,11 @@ use image::{ImageEncoder, codecs::png::PngEncoder};
 
 // 图片存储配置
 const IMAGES_DIR: &str = "clipboard_images";
+const THUMBS_DIR: &str = "thumbs";
 const MAX_IMAGE_DATA_COUNT: i64 = 50;
+// 以文件形式粘贴图片时使用的临时目录，超过保留时间的文件会被清理
+const PASTE_TEMP_DIR: &str = "quickclipboard_paste";
+const PASTE_TEMP_MAX_AGE_SECS: u64 = 3600;
 
 pub struct ImageManager {
     images_dir: PathBuf,
@@ -30,11 +34,34 @@ impl ImageManager {
             }
         }
 
+        cleanup_paste_temp_files();
+
         Ok(ImageManager { 
             images_dir,
         })
     }
 
+    // 将图片复制为临时文件（用于只接受文件拖放的应用）
+    pub fn export_temp_file(&self, image_id: &str) -> Result<String, String> {
+        let source_path = self.images_dir.join(format!("{}.png", image_id));
+        if !source_path.exists() {
+            return Err(format!("图片文件不存在: {}", image_id));
+        }
+
+        cleanup_paste_temp_files();
+
+        let temp_dir = std::env::temp_dir().join(PASTE_TEMP_DIR);
+        fs::create_dir_all(&temp_dir)
+            .map_err(|e| format!("创建临时目录失败: {}", e))?;
+
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let temp_path = temp_dir.join(format!("image_{}_{}.png", timestamp, image_id));
+        fs::copy(&source_path, &temp_path)
+            .map_err(|e| format!("写入临时图片文件失败: {}", e))?;
+
+        Ok(temp_path.to_string_lossy().to_string())
+    }
+
     // 从Windows剪贴板原始数据保存图片
     pub fn save_image_from_raw_data(&self, width: u32, height: u32, dib_data: Vec<u8>, png_data: Vec<u8>) -> Result<String, String> {
         let image_id = self.calculate_image_id(&png_data);
@@ -140,6 +167,71 @@ impl ImageManager {
         Ok(file_path.to_string_lossy().to_string())
     }
 
+    // 获取缩略图文件路径（按设置的尺寸和格式生成并缓存）
+    pub fn get_thumbnail_path(&self, image_id: &str) -> Result<String, String> {
+        let settings = crate::settings::get_global_settings();
+        let max_dimension = settings.thumbnail_max_dimension;
+        let (format, extension) = match settings.thumbnail_format.as_str() {
+            "webp" => (image::ImageFormat::WebP, "webp"),
+            "png" => (image::ImageFormat::Png, "png"),
+            _ => (image::ImageFormat::Jpeg, "jpg"),
+        };
+
+        let thumbs_dir = self.images_dir.join(THUMBS_DIR);
+        let thumb_path = thumbs_dir.join(format!("{}_{}.{}", image_id, max_dimension, extension));
+        if thumb_path.exists() {
+            return Ok(thumb_path.to_string_lossy().to_string());
+        }
+
+        let source_path = self.images_dir.join(format!("{}.png", image_id));
+        if !source_path.exists() {
+            return Err(format!("图片文件不存在: {}", image_id));
+        }
+
+        fs::create_dir_all(&thumbs_dir)
+            .map_err(|e| format!("创建缩略图目录失败: {}", e))?;
+
+        let img = image::open(&source_path)
+            .map_err(|e| format!("读取图片失败: {}", e))?;
+        let thumbnail = img.thumbnail(max_dimension, max_dimension);
+
+        // JPEG 不支持透明通道
+        let thumbnail = if format == image::ImageFormat::Jpeg {
+            image::DynamicImage::ImageRgb8(thumbnail.to_rgb8())
+        } else {
+            image::DynamicImage::ImageRgba8(thumbnail.to_rgba8())
+        };
+
+        thumbnail
+            .save_with_format(&thumb_path, format)
+            .map_err(|e| format!("生成缩略图失败: {}", e))?;
+
+        Ok(thumb_path.to_string_lossy().to_string())
+    }
+
+    // 清空缩略图缓存（缩略图设置变化后重新生成）
+    pub fn clear_thumbnails(&self) -> Result<(), String> {
+        let thumbs_dir = self.images_dir.join(THUMBS_DIR);
+        if thumbs_dir.exists() {
+            fs::remove_dir_all(&thumbs_dir)
+                .map_err(|e| format!("清理缩略图缓存失败: {}", e))?;
+        }
+        Ok(())
+    }
+
+    // 删除指定图片的缩略图
+    fn delete_thumbnails(&self, image_id: &str) {
+        let thumbs_dir = self.images_dir.join(THUMBS_DIR);
+        if let Ok(entries) = fs::read_dir(&thumbs_dir) {
+            let prefix = format!("{}_", image_id);
+            for entry in entries.flatten() {
+                if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                    let _ = fs::remove_file(entry.path());
+                }
+            }
+        }
+    }
+
     // 获取图片data URL（用于粘贴）
     pub fn get_image_data_url(&self, image_id: &str) -> Result<String, String> {
         let file_path = self.images_dir.join(format!("{}.png", image_id));
@@ -206,6 +298,8 @@ impl ImageManager {
             )
         });
         
+        self.delete_thumbnails(image_id);
+
         let file_path = self.images_dir.join(format!("{}.png", image_id));
         if file_path.exists() {
             fs::remove_file(&file_path)
@@ -279,6 +373,36 @@ pub fn get_image_manager() -> Result<&'static Mutex<ImageManager>, String> {
     IMAGE_MANAGER.as_ref().map_err(|e| e.clone())
 }
 
+// 是否为粘贴时导出的临时图片文件
+pub fn is_paste_temp_file(file_path: &str) -> bool {
+    let temp_dir = std::env::temp_dir().join(PASTE_TEMP_DIR);
+    match (temp_dir.canonicalize(), std::path::Path::new(file_path).canonicalize()) {
+        (Ok(temp_dir), Ok(file_path)) => file_path.starts_with(temp_dir),
+        _ => false,
+    }
+}
+
+// 清理过期的粘贴临时文件
+fn cleanup_paste_temp_files() {
+    let temp_dir = std::env::temp_dir().join(PASTE_TEMP_DIR);
+    let Ok(entries) = fs::read_dir(&temp_dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let expired = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .map(|elapsed| elapsed.as_secs() > PASTE_TEMP_MAX_AGE_SECS)
+            .unwrap_or(false);
+        if expired {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
 fn save_image_data(image_id: String, width: u32, height: u32, bgra_data: Vec<u8>, png_data: Vec<u8>) {
     std::thread::spawn(move || {
         let result = crate::database::with_connection(|conn| {
@@ -378,3 +502,45 @@ fn rgba_to_bgra(rgba: &[u8]) -> Vec<u8> {
     
     bgra
 }
+
+// 将多张图片按网格拼接为一张（每格大小取最大图片尺寸，图片在格内居中，白色背景）
+pub fn compose_contact_sheet(images: &[image::RgbaImage], columns: u32, padding: u32) -> image::RgbaImage {
+    let columns = columns.clamp(1, images.len().max(1) as u32);
+    let rows = (images.len() as u32).div_ceil(columns);
+    let cell_width = images.iter().map(|img| img.width()).max().unwrap_or(0);
+    let cell_height = images.iter().map(|img| img.height()).max().unwrap_or(0);
+
+    let sheet_width = columns * cell_width + (columns + 1) * padding;
+    let sheet_height = rows * cell_height + (rows + 1) * padding;
+    let mut sheet = image::RgbaImage::from_pixel(sheet_width, sheet_height, image::Rgba([255, 255, 255, 255]));
+
+    for (i, img) in images.iter().enumerate() {
+        let column = i as u32 % columns;
+        let row = i as u32 / columns;
+        let x = padding + column * (cell_width + padding) + (cell_width - img.width()) / 2;
+        let y = padding + row * (cell_height + padding) + (cell_height - img.height()) / 2;
+        image::imageops::overlay(&mut sheet, img, x as i64, y as i64);
+    }
+
+    sheet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_contact_sheet_size() {
+        let images = vec![
+            image::RgbaImage::new(10, 20),
+            image::RgbaImage::new(30, 10),
+            image::RgbaImage::new(5, 5),
+        ];
+
+        let sheet = compose_contact_sheet(&images, 2, 4);
+        assert_eq!(sheet.dimensions(), (2 * 30 + 3 * 4, 2 * 20 + 3 * 4));
+
+        let single_row = compose_contact_sheet(&images, 10, 0);
+        assert_eq!(single_row.dimensions(), (3 * 30, 20));
+    }
+}
@@ -2,7 +2,7 @@ use once_cell::sync::OnceCell;
 use rdev::{grab, listen, Event, EventType, Key};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
-use tauri::{Emitter, WebviewWindow, AppHandle};
+use tauri::{Emitter, Manager, WebviewWindow, AppHandle};
 
 // 全局状态
 pub static MAIN_WINDOW_HANDLE: OnceCell<WebviewWindow> = OnceCell::new();
@@ -11,6 +11,10 @@ static MONITORING_THREAD_HANDLE: Mutex<Option<std::thread::JoinHandle<()>>> = Mu
 static MOUSE_LISTENER_ACTIVE: AtomicBool = AtomicBool::new(false);
 static MOUSE_LISTENER_THREAD_HANDLE: Mutex<Option<std::thread::JoinHandle<()>>> = Mutex::new(None);
 
+// 功能开关：全局键盘钩子和鼠标钩子是否处理事件
+static KEYBOARD_HOOK_ENABLED: AtomicBool = AtomicBool::new(true);
+static MOUSE_HOOK_ENABLED: AtomicBool = AtomicBool::new(true);
+
 // 导航键启用状态
 static NAVIGATION_KEYS_ENABLED: AtomicBool = AtomicBool::new(false);
 
@@ -34,16 +38,42 @@ struct NavigationAction {
 
 // 启动输入监控系统
 pub fn start_input_monitoring(app_handle: AppHandle, main_window: WebviewWindow) {
+    let settings = crate::settings::get_global_settings();
+    KEYBOARD_HOOK_ENABLED.store(settings.enable_keyboard_hook, Ordering::SeqCst);
+    MOUSE_HOOK_ENABLED.store(settings.enable_mouse_hook, Ordering::SeqCst);
+
     if MONITORING_ACTIVE.load(Ordering::SeqCst) {
         return;
     }
 
     MONITORING_ACTIVE.store(true, Ordering::SeqCst);
 
-    // 启动grab线程（用于按键和需要拦截的事件）
+    // 被功能开关关闭的钩子不安装
+    if settings.enable_keyboard_hook {
+        start_keyboard_hook(app_handle, main_window);
+    } else {
+        println!("键盘钩子已被设置禁用，跳过安装");
+    }
+    if settings.enable_mouse_hook {
+        start_mouse_position_listener();
+    } else {
+        println!("鼠标钩子已被设置禁用，跳过安装");
+    }
+}
+
+// 启动grab线程（用于按键和需要拦截的事件）
+fn start_keyboard_hook(app_handle: AppHandle, main_window: WebviewWindow) {
+    let mut handle = match MONITORING_THREAD_HANDLE.lock() {
+        Ok(handle) => handle,
+        Err(_) => return,
+    };
+    if handle.is_some() {
+        return;
+    }
+
     let monitoring_handle = std::thread::spawn(move || {
         let callback = move |event: Event| -> Option<Event> {
-            if !MONITORING_ACTIVE.load(Ordering::SeqCst) {
+            if !MONITORING_ACTIVE.load(Ordering::SeqCst) || !KEYBOARD_HOOK_ENABLED.load(Ordering::SeqCst) {
                 return Some(event);
             }
             
@@ -55,10 +85,26 @@ pub fn start_input_monitoring(app_handle: AppHandle, main_window: WebviewWindow)
         }
     });
 
-    if let Ok(mut handle) = MONITORING_THREAD_HANDLE.lock() {
-        *handle = Some(monitoring_handle);
+    *handle = Some(monitoring_handle);
+}
+
+// 运行时启用/禁用键盘钩子，启动时未安装的钩子在首次启用时安装
+// 已安装的钩子无法卸载，禁用后只直接放行所有事件
+pub fn set_keyboard_hook_enabled(enabled: bool) {
+    KEYBOARD_HOOK_ENABLED.store(enabled, Ordering::SeqCst);
+    if enabled && MONITORING_ACTIVE.load(Ordering::SeqCst) {
+        if let Some(window) = MAIN_WINDOW_HANDLE.get() {
+            start_keyboard_hook(window.app_handle().clone(), window.clone());
+        }
+    }
+}
+
+// 运行时启用/禁用鼠标钩子
+pub fn set_mouse_hook_enabled(enabled: bool) {
+    MOUSE_HOOK_ENABLED.store(enabled, Ordering::SeqCst);
+    if enabled && MONITORING_ACTIVE.load(Ordering::SeqCst) {
+        start_mouse_position_listener();
     }
-    start_mouse_position_listener();
 }
 
 // 启动鼠标事件监听
@@ -71,7 +117,7 @@ fn start_mouse_position_listener() {
 
     let listener_handle = std::thread::spawn(|| {
         if let Err(error) = listen(move |event| {
-            if !MOUSE_LISTENER_ACTIVE.load(Ordering::SeqCst) {
+            if !MOUSE_LISTENER_ACTIVE.load(Ordering::SeqCst) || !MOUSE_HOOK_ENABLED.load(Ordering::SeqCst) {
                 return;
             }
 
@@ -210,6 +256,61 @@ fn handle_key_press_with_grab(
         }
     }
 
+    // 全局固定快捷键（窗口未激活时也可切换固定状态）
+    if settings.toggle_pin_shortcut_global
+        && !settings.toggle_pin_shortcut.is_empty()
+        && check_shortcut_match(key, &settings.toggle_pin_shortcut)
+    {
+        // 设置页面正在录制快捷键时不拦截
+        #[cfg(windows)]
+        if crate::global_state::SHORTCUT_RECORDING.load(Ordering::SeqCst) {
+            return Some(event);
+        }
+        handle_global_toggle_pin(app_handle);
+        return None;
+    }
+
+    // 右键菜单显示时 Esc 关闭菜单（菜单窗口未能获得焦点时也能关闭）
+    if matches!(key, Key::Escape) && crate::plugins::context_menu::close_menu_if_visible() {
+        return None;
+    }
+
+    // 确认模式下，回车粘贴当前预览项，Esc 取消
+    if crate::preview_window::is_preview_awaiting_confirm() {
+        match key {
+            Key::Return | Key::KpReturn => {
+                std::thread::spawn(|| {
+                    let _ = tauri::async_runtime::block_on(
+                        crate::preview_window::confirm_preview_paste(),
+                    );
+                });
+                return None;
+            }
+            Key::Escape => {
+                std::thread::spawn(|| {
+                    let _ = tauri::async_runtime::block_on(
+                        crate::preview_window::cancel_preview(),
+                    );
+                });
+                return None;
+            }
+            _ => {}
+        }
+    }
+
+    // 预览窗口显示时，上下方向键用于切换预览项
+    if crate::preview_window::is_preview_window_visible() {
+        let direction = match key {
+            Key::UpArrow => Some("up"),
+            Key::DownArrow => Some("down"),
+            _ => None,
+        };
+        if let Some(direction) = direction {
+            let _ = crate::preview_window::handle_preview_arrow_key(direction);
+            return None;
+        }
+    }
+
     // 检查主窗口是否可见
     let is_main_window_visible = main_window.is_visible().unwrap_or(false);
 
@@ -473,10 +574,14 @@ fn handle_mouse_button_press(button: rdev::Button) {
 }
 
 // 处理鼠标滚轮
-fn handle_mouse_wheel(_delta_x: i64, delta_y: i64) -> bool {
+fn handle_mouse_wheel(delta_x: i64, delta_y: i64) -> bool {
     if crate::preview_window::is_preview_window_visible() {
-        let direction = if delta_y > 0 { "up" } else { "down" };
-        let _ = crate::preview_window::handle_preview_scroll(direction);
+        if delta_y != 0 {
+            let _ = crate::preview_window::handle_preview_scroll(delta_y);
+        }
+        if delta_x != 0 {
+            let _ = crate::preview_window::handle_preview_horizontal_scroll(delta_x);
+        }
         return true;
     }
     false
@@ -505,16 +610,133 @@ fn handle_middle_button_press() {
         _ => !ctrl && !alt && !shift && !meta,
     };
 
-    if modifier_matches {
-        if let Some(window) = MAIN_WINDOW_HANDLE.get() {
-            let window_clone = window.clone();
-            std::thread::spawn(move || {
-                crate::window_management::show_webview_window(window_clone);
-            });
+    if !modifier_matches {
+        return;
+    }
+
+    match settings.mouse_middle_button_action.as_str() {
+        "paste_recent" => handle_middle_button_paste_recent(),
+        "paste_menu" => handle_middle_button_paste_menu(),
+        _ => {
+            if let Some(window) = MAIN_WINDOW_HANDLE.get() {
+                let window_clone = window.clone();
+                std::thread::spawn(move || {
+                    crate::window_management::show_webview_window(window_clone);
+                });
+            }
         }
     }
 }
 
+// 记录点击时的前台窗口，作为粘贴目标
+fn capture_paste_target_window() {
+    #[cfg(windows)]
+    {
+        use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+        let hwnd = unsafe { GetForegroundWindow() };
+        crate::window_management::set_last_focus_hwnd(hwnd.0);
+    }
+}
+
+// 中键直接粘贴最近一条记录
+fn handle_middle_button_paste_recent() {
+    let window_clone = match MAIN_WINDOW_HANDLE.get() {
+        Some(window) => window.clone(),
+        None => return,
+    };
+
+    std::thread::spawn(move || {
+        capture_paste_target_window();
+
+        let latest_id = crate::database::get_clipboard_history(Some(1))
+            .ok()
+            .and_then(|items| items.first().map(|item| item.id));
+
+        if let Some(id) = latest_id {
+            let _ = tauri::async_runtime::block_on(
+                crate::services::paste_service::paste_history_item_by_id(id, window_clone),
+            );
+        }
+    });
+}
+
+// 中键弹出最近记录菜单，选择后粘贴
+fn handle_middle_button_paste_menu() {
+    let window_clone = match MAIN_WINDOW_HANDLE.get() {
+        Some(window) => window.clone(),
+        None => return,
+    };
+
+    std::thread::spawn(move || {
+        capture_paste_target_window();
+
+        let items = crate::database::get_clipboard_history(Some(9)).unwrap_or_default();
+        if items.is_empty() {
+            return;
+        }
+
+        let menu_items: Vec<crate::plugins::context_menu::window::MenuItem> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| crate::plugins::context_menu::window::MenuItem {
+                id: item.id.to_string(),
+                label: format!(
+                    "{}. {}",
+                    index + 1,
+                    crate::utils::content_utils::generate_content_summary(&item.content, 40)
+                ),
+                icon: None,
+                favicon: None,
+                shortcut: None,
+                disabled: false,
+                separator: false,
+                children: None,
+            })
+            .collect();
+
+        // 鼠标位置为物理坐标，菜单需要逻辑坐标
+        let (cursor_x, cursor_y) = get_mouse_position().unwrap_or((0, 0));
+        let scale_factor = window_clone.scale_factor().unwrap_or(1.0);
+        let options = crate::plugins::context_menu::window::ContextMenuOptions {
+            items: menu_items,
+            x: (cursor_x as f64 / scale_factor).round() as i32,
+            y: (cursor_y as f64 / scale_factor).round() as i32,
+            width: Some(280),
+            theme: Some(crate::system_theme::resolve_theme(&crate::settings::get_global_settings().theme)),
+            session_id: 0,
+        };
+
+        let app_handle = window_clone.app_handle().clone();
+        let _ = tauri::async_runtime::block_on(async move {
+            let selected = crate::plugins::context_menu::window::show_menu(app_handle, options).await?;
+            let id = match selected.and_then(|id| id.parse::<i64>().ok()) {
+                Some(id) => id,
+                None => return Ok::<(), String>(()),
+            };
+
+            // 菜单窗口会抢占焦点，粘贴前恢复到点击时的窗口
+            let _ = crate::window_management::restore_last_focus();
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            crate::services::paste_service::paste_history_item_by_id(id, window_clone).await
+        });
+    });
+}
+
+// 处理全局固定快捷键，切换后发送通知提示新状态
+fn handle_global_toggle_pin(app_handle: &AppHandle) {
+    use tauri_plugin_notification::NotificationExt;
+
+    let pinned = crate::services::window_service::WindowService::toggle_pinned(app_handle);
+    let body = if pinned { "窗口已固定" } else { "窗口已取消固定" };
+
+    let _ = app_handle
+        .notification()
+        .builder()
+        .title("QuickClipboard")
+        .body(body)
+        .show();
+}
+
 // 处理点击外部关闭窗口
 fn handle_click_outside() {
     let is_window_pinned = crate::state_manager::is_window_pinned();
@@ -5,6 +5,7 @@ mod ai_config;
 mod ai_translator;
 mod app_filter;
 mod audio_scanner;
+mod clipboard_auto_clear;
 mod clipboard_content;
 mod clipboard_history;
 mod clipboard_monitor;
@@ -19,8 +20,10 @@ mod groups;
 mod hotkey_manager;
 mod image_manager;
 mod input_monitor;
+mod logger;
 mod registry_manager;
 mod mouse_utils;
+mod ocr;
 mod paste_utils;
 mod preview_window;
 mod pin_image_window;
@@ -34,7 +37,9 @@ mod memory_manager;
 mod services;
 mod settings;
 mod sound_manager;
+mod system_theme;
 mod text_input_simulator;
+mod translation_window;
 mod tray;
 mod updater;
 mod utils;
@@ -196,6 +201,10 @@ fn check_win_v_configuration(app_handle: &tauri::AppHandle) -> Result<(), String
 // =================== Tauri 应用入口 ===================
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // 初始化日志记录器
+    logger::init();
+    logger::apply_settings(&settings::get_global_settings());
+
     // 输出启动横幅
     print_startup_banner();
 
@@ -262,25 +271,10 @@ pub fn run() {
             "toggle-clipboard-monitor" => {
                 let new_enabled = !crate::clipboard_history::is_monitoring_enabled();
                 crate::clipboard_history::set_monitoring_enabled(new_enabled);
-                // 持久化到设置
+                // 持久化到设置（同时向所有窗口广播设置变更）
                 let mut app_settings = crate::settings::get_global_settings();
                 app_settings.clipboard_monitor = new_enabled;
                 let _ = crate::settings::update_global_settings(app_settings);
-                // 广播设置变更，确保主窗口与设置窗口同步
-                if let Some(main_window) = app.get_webview_window("main") {
-                    use tauri::Emitter;
-                    let _ = main_window.emit(
-                        "settings-changed",
-                        crate::settings::get_global_settings().to_json(),
-                    );
-                }
-                if let Some(settings_window) = app.get_webview_window("settings") {
-                    use tauri::Emitter;
-                    let _ = settings_window.emit(
-                        "settings-changed",
-                        crate::settings::get_global_settings().to_json(),
-                    );
-                }
                 if let Some(item) = crate::tray::TOGGLE_MONITOR_ITEM.get() {
                     let _ = item.set_text(if new_enabled { "禁用剪贴板监听" } else { "启用剪贴板监听" });
                 }
@@ -302,6 +296,12 @@ pub fn run() {
                 println!("数据库初始化失败: {}", e);
             }
 
+            // 清理过期的回收站记录
+            let trash_retention_days = settings::get_global_settings().trash_retention_days;
+            if let Err(e) = database::purge_expired_trash(trash_retention_days) {
+                println!("清理过期回收站记录失败: {}", e);
+            }
+
             // 首先尝试加载历史记录
             clipboard_history::load_history();
             // 加载常用文本
@@ -324,6 +324,12 @@ pub fn run() {
                 hotkey_manager::initialize_hotkey_manager(app.handle().clone(), main_window.clone());
             }
 
+            // 初始化翻译事件通知
+            ai_translator::init_translation_events(app.handle().clone());
+
+            // 初始化设置变更广播
+            crate::settings::init_settings_events(app.handle().clone());
+
             // 开发模式下自动打开开发者工具
             #[cfg(debug_assertions)]
             {
@@ -364,19 +370,32 @@ pub fn run() {
             // 初始化贴图窗口
             pin_image_window::init_pin_image_window();
 
-            // 初始化截屏窗口
-            if let Err(e) = crate::screenshot::ScreenshotWindowManager::init_screenshot_window(app.handle()) {
-                println!("截屏窗口初始化失败: {}", e);
+            // 初始化截屏窗口（截屏功能被禁用时跳过）
+            if settings::get_global_settings().enable_screenshot {
+                if let Err(e) = crate::screenshot::ScreenshotWindowManager::init_screenshot_window(app.handle()) {
+                    println!("截屏窗口初始化失败: {}", e);
+                }
             }
 
             // 加载并应用设置
             let app_settings = settings::get_global_settings();
+            let start_hidden = app_settings.start_hidden;
+            let startup_delay_ms = app_settings.startup_delay_ms;
 
             // 检查管理员运行设置
             if app_settings.run_as_admin && !admin_privileges::is_running_as_admin() {
                 println!("设置要求以管理员权限运行，但当前不是管理员权限，正在重启...");
                 if let Err(e) = admin_privileges::restart_as_admin() {
                     println!("以管理员权限重启失败: {}", e);
+                    if admin_privileges::is_elevation_cancelled(&e) {
+                        // 等前端监听就绪后再提示，避免用户不清楚管理员功能为何不可用
+                        let app_handle = app.handle().clone();
+                        std::thread::spawn(move || {
+                            use tauri::Emitter;
+                            std::thread::sleep(std::time::Duration::from_secs(3));
+                            let _ = app_handle.emit("elevation-cancelled", ());
+                        });
+                    }
                 } else {
                     return Ok(());
                 }
@@ -429,12 +448,50 @@ pub fn run() {
                 }
 
                 // 配置截屏快捷键
-                if app_settings.screenshot_enabled && !app_settings.screenshot_shortcut.is_empty() {
+                if app_settings.enable_screenshot && app_settings.screenshot_enabled && !app_settings.screenshot_shortcut.is_empty() {
                     if let Err(e) = hotkey_manager::register_screenshot_hotkey(&app_settings.screenshot_shortcut) {
                         eprintln!("注册截屏快捷键失败: {}", e);
                     }
                 }
 
+                // 配置常用文本采集快捷键
+                if !app_settings.quick_text_capture_shortcut.is_empty() {
+                    if let Err(e) = hotkey_manager::register_quick_text_capture_hotkey(&app_settings.quick_text_capture_shortcut) {
+                        eprintln!("注册常用文本采集快捷键失败: {}", e);
+                    }
+                }
+
+                // 配置设置窗口快捷键
+                if !app_settings.settings_shortcut.is_empty() {
+                    if let Err(e) = hotkey_manager::register_settings_hotkey(&app_settings.settings_shortcut) {
+                        eprintln!("注册设置窗口快捷键失败: {}", e);
+                    }
+                }
+
+                // 配置重截上次区域快捷键
+                if !app_settings.screenshot_last_region_shortcut.is_empty() {
+                    if let Err(e) = hotkey_manager::register_last_region_screenshot_hotkey(&app_settings.screenshot_last_region_shortcut) {
+                        eprintln!("注册重截上次区域快捷键失败: {}", e);
+                    }
+                }
+
+                // 配置切换窗口大小快捷键
+                if !app_settings.cycle_window_size_shortcut.is_empty() {
+                    if let Err(e) = hotkey_manager::register_cycle_window_size_hotkey(&app_settings.cycle_window_size_shortcut) {
+                        eprintln!("注册切换窗口大小快捷键失败: {}", e);
+                    }
+                }
+
+                // 配置翻译语言快捷键
+                if let Err(e) = hotkey_manager::register_translation_language_hotkeys(&app_settings.ai_language_hotkeys) {
+                    eprintln!("注册翻译语言快捷键失败: {}", e);
+                }
+
+                // 配置相对历史粘贴快捷键
+                if let Err(e) = hotkey_manager::register_relative_paste_hotkeys(&app_settings.relative_paste_hotkeys) {
+                    eprintln!("注册相对历史粘贴快捷键失败: {}", e);
+                }
+
                 // 配置数字快捷键
                 #[cfg(windows)]
                 if app_settings.number_shortcuts {
@@ -460,40 +517,85 @@ pub fn run() {
                 copy_sound_path: app_settings.copy_sound_path,
                 paste_sound_path: app_settings.paste_sound_path,
                 preset: "default".to_string(),
+                quiet_start: app_settings.sound_quiet_start.clone(),
+                quiet_end: app_settings.sound_quiet_end.clone(),
             };
             sound_manager::update_sound_settings(sound_settings);
 
             // 启动剪贴板监听器
             clipboard_monitor::start_clipboard_monitor(app.handle().clone());
 
-            // 注册托盘图标和事件
-            tray::setup_tray(&app.app_handle())?;
+            // 应用主题并监听系统深浅色切换
+            system_theme::apply_theme(app.handle());
+            system_theme::start_system_theme_watcher(app.handle().clone());
+
+            // 注册托盘图标和事件（设置了启动延迟时等待系统外壳就绪，失败时重试）
+            if startup_delay_ms == 0 {
+                if let Err(e) = tray::setup_tray(&app.app_handle()) {
+                    println!("注册托盘失败，稍后重试: {}", e);
+                    tray::setup_tray_with_retry(app.handle().clone(), std::time::Duration::from_secs(1), 3);
+                }
+            } else {
+                tray::setup_tray_with_retry(
+                    app.handle().clone(),
+                    std::time::Duration::from_millis(startup_delay_ms as u64),
+                    3,
+                );
+            }
 
             // 初始化状态管理器
             state_manager::init_state_manager();
 
             // 设置窗口关闭事件处理 - 隐藏到托盘而不是退出
+            window_management::remember_scale_factor(&main_window);
             let main_window_clone = main_window.clone();
-            main_window.on_window_event(move |event| {
-                if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+            main_window.on_window_event(move |event| match event {
+                tauri::WindowEvent::CloseRequested { api, .. } => {
                     // 阻止默认的关闭行为
                     api.prevent_close();
                     // 隐藏窗口到托盘
                     let _ = main_window_clone.hide();
                 }
+                // 移动到不同 DPI 的显示器时重新应用窗口效果
+                tauri::WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                    window_management::handle_scale_factor_changed(&main_window_clone, *scale_factor);
+                }
+                // 拖入文件时添加为文件类型的历史记录
+                tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) if !paths.is_empty() => {
+                    let files: Vec<String> = paths
+                        .iter()
+                        .map(|path| path.to_string_lossy().to_string())
+                        .collect();
+                    let app_handle = main_window_clone.app_handle().clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = commands::add_dropped_files(app_handle, files).await {
+                            log::warn!("添加拖入文件失败: {}", e);
+                        }
+                    });
+                }
+                _ => {}
             });
 
             // 输入监控系统
             #[cfg(desktop)]
             {
-                input_monitor::start_input_monitoring(app.handle().clone(), main_window.clone());
+                if startup_delay_ms == 0 {
+                    input_monitor::start_input_monitoring(app.handle().clone(), main_window.clone());
+                } else {
+                    let app_handle = app.handle().clone();
+                    let main_window = main_window.clone();
+                    std::thread::spawn(move || {
+                        std::thread::sleep(std::time::Duration::from_millis(startup_delay_ms as u64));
+                        input_monitor::start_input_monitoring(app_handle, main_window);
+                    });
+                }
             }
 
             // 发送启动通知和检查Win+V配置
             let app_handle = app.handle().clone();
             std::thread::spawn(move || {
                 // 等待一小段时间确保应用完全启动
-                std::thread::sleep(std::time::Duration::from_millis(1500));
+                std::thread::sleep(std::time::Duration::from_millis(1500 + startup_delay_ms as u64));
 
                 // 发送启动通知
                 let _ = send_startup_notification_internal(&app_handle);
@@ -513,6 +615,13 @@ pub fn run() {
             crate::plugins::context_menu::init();
             crate::plugins::context_menu::set_app_handle(app.app_handle().clone());
 
+            // 启动时隐藏则只保留托盘入口，否则显示主窗口
+            if start_hidden {
+                let _ = main_window.hide();
+            } else {
+                window_management::show_webview_window(main_window.clone());
+            }
+
             // 标记后端初始化完成
             BACKEND_INITIALIZED.store(true, Ordering::Relaxed);
 
@@ -521,9 +630,11 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet,
             get_clipboard_text,
+            get_clipboard_formats,
             set_clipboard_text,
             set_clipboard_text_with_html,
             get_clipboard_history,
+            get_clipboard_history_page,
             refresh_clipboard,
             set_window_pinned,
             get_window_pinned,
@@ -541,6 +652,7 @@ pub fn run() {
             set_startup_launch,
             set_history_limit,
             get_groups,
+            get_recent_groups,
             add_group,
             update_group,
             hide_main_window_if_auto_shown,
@@ -549,10 +661,13 @@ pub fn run() {
             move_quick_text_to_group,
             move_quick_text_item,
             add_clipboard_to_group,
+            move_clipboard_items_to_group,
             open_settings_window,
             get_settings,
             reload_settings,
             save_settings,
+            list_settings_backups,
+            restore_settings_backup,
             browse_sound_file,
             browse_image_file,
             test_sound,
@@ -567,18 +682,47 @@ pub fn run() {
             set_preview_index,
             cancel_preview,
             delete_clipboard_item,
+            delete_clipboard_items,
+            split_clipboard_item_by_lines,
+            make_contact_sheet,
+            paste_relative_history,
+            restore_last_deleted,
+            empty_trash,
             update_clipboard_item,
             emit_clipboard_updated,
             emit_quick_texts_updated,
             clear_clipboard_history,
+            deduplicate_history,
+            set_clipboard_item_pinned,
+            set_clipboard_item_sensitive,
+            get_clipboard_item_origin,
+            set_favorite_slot,
+            diff_clipboard_items,
+            get_paste_stats,
+            get_favorites,
+            clear_favorite_slot,
+            run_pipeline,
+            get_available_transforms,
+            validate_files_entry,
+            get_files_entry_summary,
+            get_monitor_status,
+            open_clipboard_item_in_external_editor,
             cleanup_unused_images,
             open_text_editor_window,
+            set_editor_topmost,
+            get_editor_topmost,
+            preview_quick_text_expansion,
             notify_preview_tab_change,
             get_main_window_state,
             update_theme_setting,
+            get_system_theme,
             get_app_version,
             get_admin_status,
             restart_as_admin,
+            request_elevation,
+            set_input_hook_enabled,
+            set_append_mode,
+            append_to_clipboard,
             is_backend_initialized,
             send_system_notification,
             send_startup_notification,
@@ -594,20 +738,30 @@ pub fn run() {
             commands::translate_and_paste_text,
             commands::translate_and_input_on_copy,
             commands::translate_text_smart,
+            commands::translate_clipboard_to,
+            translation_window::close_translation_window,
+            translation_window::get_translation_window_state,
             commands::is_currently_pasting,
             commands::check_ai_translation_config,
             commands::get_available_ai_models,
             commands::test_ai_config,
             commands::cancel_translation,
+            commands::get_translation_usage,
+            commands::reset_translation_usage,
             commands::enable_ai_translation_cancel_shortcut,
             commands::disable_ai_translation_cancel_shortcut,
             commands::copy_files_to_directory,
             commands::get_file_info,
+            commands::add_dropped_files,
             commands::get_clipboard_files,
             commands::set_clipboard_files,
+            commands::set_clipboard_files_as,
+            commands::get_available_ocr_languages,
+            commands::check_ocr_language,
             commands::move_clipboard_item_to_front,
             commands::move_clipboard_item,
             commands::paste_content,
+            commands::copy_clipboard_item,
             commands::open_file_location,
             commands::open_file_with_default_program,
             
@@ -619,10 +773,12 @@ pub fn run() {
             commands::read_image_file,
             commands::export_data,
             commands::import_data,
+            commands::export_all_data,
+            commands::import_all_data,
             commands::restart_app,
             commands::clear_clipboard_history_dm,
             commands::reset_all_data,
-            commands::reset_settings_to_default,
+            commands::reset_settings_to_defaults,
             commands::get_app_data_dir,
             commands::is_portable_mode,
             commands::get_storage_info,
@@ -633,6 +789,7 @@ pub fn run() {
             commands::save_window_size,
             commands::get_saved_window_position,
             commands::get_saved_window_size,
+            commands::cycle_window_size,
             commands::init_edge_snap,
             commands::check_window_edge_snap,
             commands::restore_window_from_snap,
@@ -642,9 +799,12 @@ pub fn run() {
             commands::refresh_all_windows,
             commands::get_screen_size,
             commands::set_shortcut_recording,
+            commands::get_registered_shortcuts,
+            commands::check_shortcut_conflict,
             commands::start_custom_drag,
             commands::stop_custom_drag,
             commands::get_image_file_path,
+            commands::get_image_thumbnail_path,
             commands::file_exists,
             commands::create_pin_image_window,
             commands::pin_image_from_file,
@@ -661,6 +821,9 @@ pub fn run() {
             crate::screenshot::show_screenshot_window,
             crate::screenshot::hide_screenshot_window,
             crate::screenshot::toggle_screenshot_window,
+            crate::screenshot::cancel_screenshot_capture,
+            crate::screenshot::remember_screenshot_region,
+            crate::screenshot::take_last_region_screenshot,
             crate::screenshot::is_screenshot_window_visible,
             crate::screenshot::get_all_monitors,
             crate::screenshot::get_css_monitors,
@@ -675,6 +838,11 @@ pub fn run() {
             crate::screenshot::stop_scrolling_screenshot,
             crate::screenshot::cancel_scrolling_screenshot,
             crate::screenshot::update_scrolling_panel_rect,
+            crate::screenshot::get_scrolling_screenshot_status,
+            crate::screenshot::rescan_last_scroll_frame,
+            crate::screenshot::add_manual_scroll_frame,
+            crate::screenshot::save_region_screenshot,
+            crate::screenshot::auto_save_region_screenshot,
             
             // 自动选区相关命令
             crate::screenshot::start_auto_selection,
@@ -695,6 +863,7 @@ pub fn run() {
             
             // 右键菜单插件命令
             crate::plugins::context_menu::commands::show_context_menu,
+            crate::plugins::context_menu::commands::show_context_menu_with_path,
             crate::plugins::context_menu::commands::get_context_menu_options,
             crate::plugins::context_menu::commands::submit_context_menu,
             crate::plugins::context_menu::commands::close_all_context_menus
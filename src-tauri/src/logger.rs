@@ -0,0 +1,162 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use once_cell::sync::Lazy;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::settings::{AppSettings, SettingsStorage};
+
+// 日志文件名及大小上限（超出后轮换为 .old）
+const LOG_FILE_NAME: &str = "quickclipboard.log";
+const MAX_LOG_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+// 当前打开的日志文件，未开启写入文件时为 None
+static LOG_FILE: Lazy<Mutex<Option<LogFile>>> = Lazy::new(|| Mutex::new(None));
+
+// 日志文件及其当前大小（写入时累加，超出上限即轮换）
+struct LogFile {
+    file: File,
+    path: PathBuf,
+    size: u64,
+}
+
+struct AppLogger;
+
+static LOGGER: AppLogger = AppLogger;
+
+impl Log for AppLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        if metadata.level() > log::max_level() {
+            return false;
+        }
+        // 依赖库只输出警告及以上，避免刷屏
+        metadata.target().starts_with(env!("CARGO_CRATE_NAME")) || metadata.level() <= Level::Warn
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!(
+            "{} [{}] {}: {}",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        println!("{}", line);
+
+        if let Ok(mut current) = LOG_FILE.lock() {
+            let mut rotate_path = None;
+            if let Some(log_file) = current.as_mut() {
+                if writeln!(log_file.file, "{}", line).is_ok() {
+                    log_file.size += line.len() as u64 + 1;
+                }
+                if log_file.size > MAX_LOG_FILE_SIZE {
+                    rotate_path = Some(log_file.path.clone());
+                }
+            }
+
+            // 先关闭旧文件再重命名，持有锁时不能再调用 log 宏
+            if let Some(path) = rotate_path {
+                *current = None;
+                match open_log_path(&path) {
+                    Ok(log_file) => *current = Some(log_file),
+                    Err(e) => eprintln!("轮换日志文件失败: {}", e),
+                }
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut current) = LOG_FILE.lock() {
+            if let Some(log_file) = current.as_mut() {
+                let _ = log_file.file.flush();
+            }
+        }
+    }
+}
+
+// 安装全局日志记录器（只需调用一次）
+pub fn init() {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(LevelFilter::Info);
+    }
+}
+
+// 按设置更新日志级别和文件输出
+pub fn apply_settings(settings: &AppSettings) {
+    log::set_max_level(parse_level(&settings.log_level));
+
+    let file = if settings.log_to_file {
+        match open_log_file(settings) {
+            Ok(file) => Some(file),
+            Err(e) => {
+                log::warn!("打开日志文件失败: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    if let Ok(mut current) = LOG_FILE.lock() {
+        *current = file;
+    }
+}
+
+pub fn parse_level(level: &str) -> LevelFilter {
+    match level {
+        "off" => LevelFilter::Off,
+        "error" => LevelFilter::Error,
+        "warn" => LevelFilter::Warn,
+        "debug" => LevelFilter::Debug,
+        "trace" => LevelFilter::Trace,
+        _ => LevelFilter::Info,
+    }
+}
+
+fn open_log_file(settings: &AppSettings) -> Result<LogFile, String> {
+    let log_dir = SettingsStorage::get_data_directory(settings)?.join("logs");
+    fs::create_dir_all(&log_dir).map_err(|e| format!("创建日志目录失败: {}", e))?;
+
+    open_log_path(&log_dir.join(LOG_FILE_NAME))
+}
+
+// 打开日志文件，已超出大小上限时先轮换为 .old
+fn open_log_path(log_path: &Path) -> Result<LogFile, String> {
+    let mut size = fs::metadata(log_path).map(|m| m.len()).unwrap_or(0);
+    if size > MAX_LOG_FILE_SIZE {
+        let _ = fs::rename(log_path, log_path.with_extension("log.old"));
+        size = 0;
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)
+        .map_err(|e| format!("打开日志文件失败: {}", e))?;
+
+    Ok(LogFile {
+        file,
+        path: log_path.to_path_buf(),
+        size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(parse_level("off"), LevelFilter::Off);
+        assert_eq!(parse_level("warn"), LevelFilter::Warn);
+        assert_eq!(parse_level("debug"), LevelFilter::Debug);
+        assert_eq!(parse_level("trace"), LevelFilter::Trace);
+        assert_eq!(parse_level("info"), LevelFilter::Info);
+        assert_eq!(parse_level("unknown"), LevelFilter::Info);
+    }
+}
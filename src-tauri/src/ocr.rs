@@ -0,0 +1,66 @@
+// OCR 语言管理 - 截屏 OCR 使用 Tesseract.js，可用语言即随应用提供的 Tesseract 语言包
+
+// 默认识别语言：简体中文 + 英文
+const DEFAULT_TESSERACT_LANGUAGE: &str = "chi_sim+eng";
+
+// BCP-47 语言标签 -> Tesseract 语言包（按顺序匹配，繁体需排在简体之前）
+const TESSERACT_LANGUAGES: &[(&str, &str)] = &[
+    ("zh-Hant", "chi_tra"),
+    ("zh-TW", "chi_tra"),
+    ("zh-HK", "chi_tra"),
+    ("zh-Hans", "chi_sim"),
+    ("zh", "chi_sim"),
+    ("en", "eng"),
+    ("ja", "jpn"),
+    ("ko", "kor"),
+    ("fr", "fra"),
+    ("de", "deu"),
+    ("es", "spa"),
+    ("it", "ita"),
+    ("pt", "por"),
+    ("ru", "rus"),
+];
+
+// 获取可用的 OCR 识别语言（BCP-47 语言标签）
+pub fn get_available_ocr_languages() -> Result<Vec<String>, String> {
+    Ok(TESSERACT_LANGUAGES.iter().map(|(tag, _)| tag.to_string()).collect())
+}
+
+// 解析要使用的 OCR 语言，返回 Tesseract 语言包（非英文时同时识别英文）
+// 为空时使用默认的简体中文和英文，不支持的语言返回可用语言列表
+pub fn resolve_ocr_language(requested: &str) -> Result<String, String> {
+    let requested = requested.trim();
+    if requested.is_empty() {
+        return Ok(DEFAULT_TESSERACT_LANGUAGE.to_string());
+    }
+
+    let normalized = requested.to_ascii_lowercase();
+    let matched = TESSERACT_LANGUAGES.iter().find(|(tag, _)| {
+        let tag = tag.to_ascii_lowercase();
+        normalized == tag || normalized.starts_with(&format!("{}-", tag))
+    });
+
+    match matched {
+        Some((_, "eng")) => Ok("eng".to_string()),
+        Some((_, pack)) => Ok(format!("{}+eng", pack)),
+        None => Err(format!(
+            "OCR 不支持语言 {}，请在设置中改为以下语言之一: {}",
+            requested,
+            get_available_ocr_languages()?.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_ocr_language() {
+        assert_eq!(resolve_ocr_language("").unwrap(), "chi_sim+eng");
+        assert_eq!(resolve_ocr_language("zh-Hans-CN").unwrap(), "chi_sim+eng");
+        assert_eq!(resolve_ocr_language("zh-TW").unwrap(), "chi_tra+eng");
+        assert_eq!(resolve_ocr_language("en-US").unwrap(), "eng");
+        assert!(resolve_ocr_language("ar-SA").is_err());
+    }
+}
@@ -106,3 +106,94 @@ pub fn windows_paste() -> bool {
 pub fn windows_paste() -> bool {
     false
 }
+
+// 模拟 Ctrl+C 复制当前选中内容
+#[cfg(windows)]
+pub fn windows_copy() -> bool {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_KEYBOARD, KEYBDINPUT, KEYBD_EVENT_FLAGS, KEYEVENTF_KEYUP,
+        VIRTUAL_KEY, VK_C, VK_CONTROL, VK_LWIN, VK_MENU, VK_SHIFT,
+    };
+
+    fn key_input(vk: VIRTUAL_KEY, key_up: bool) -> INPUT {
+        let mut input = INPUT::default();
+        input.r#type = INPUT_KEYBOARD;
+        input.Anonymous.ki = KEYBDINPUT {
+            wVk: vk,
+            wScan: 0,
+            dwFlags: if key_up { KEYEVENTF_KEYUP } else { KEYBD_EVENT_FLAGS(0) },
+            time: 0,
+            dwExtraInfo: 0,
+        };
+        input
+    }
+
+    unsafe {
+        let (ctrl_pressed, alt_pressed, shift_pressed, win_pressed) =
+            crate::input_monitor::get_modifier_keys_state();
+
+        let mut inputs: Vec<INPUT> = Vec::new();
+
+        // 先释放触发快捷键时按住的其他修饰键，避免变成 Ctrl+Shift+C 等组合
+        if alt_pressed {
+            inputs.push(key_input(VK_MENU, true));
+        }
+        if shift_pressed {
+            inputs.push(key_input(VK_SHIFT, true));
+        }
+        if win_pressed {
+            inputs.push(key_input(VK_LWIN, true));
+        }
+
+        if !ctrl_pressed {
+            inputs.push(key_input(VK_CONTROL, false));
+        }
+        inputs.push(key_input(VK_C, false));
+        inputs.push(key_input(VK_C, true));
+        if !ctrl_pressed {
+            inputs.push(key_input(VK_CONTROL, true));
+        }
+
+        let result = SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+        result != 0
+    }
+}
+
+// 非Windows平台的空实现
+#[cfg(not(windows))]
+pub fn windows_copy() -> bool {
+    false
+}
+
+// 获取剪贴板序列号（剪贴板内容每次变化都会递增）
+#[cfg(windows)]
+pub fn get_clipboard_sequence_number() -> u32 {
+    unsafe { windows::Win32::System::DataExchange::GetClipboardSequenceNumber() }
+}
+
+// 非Windows平台的空实现
+#[cfg(not(windows))]
+pub fn get_clipboard_sequence_number() -> u32 {
+    0
+}
+
+// 模拟复制并等待剪贴板更新，超时返回 false
+pub fn copy_selection_and_wait(timeout_ms: u64) -> bool {
+    let sequence_before = get_clipboard_sequence_number();
+
+    if !windows_copy() {
+        return false;
+    }
+
+    let start = std::time::Instant::now();
+    while start.elapsed() < std::time::Duration::from_millis(timeout_ms) {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        if get_clipboard_sequence_number() != sequence_before {
+            // 部分应用会分多次写入剪贴板格式，稍作等待
+            std::thread::sleep(std::time::Duration::from_millis(30));
+            return true;
+        }
+    }
+
+    false
+}
@@ -1,6 +1,6 @@
 // 右键菜单命令
 use tauri::{AppHandle, Manager};
-use super::window::{ContextMenuOptions, MenuItem, show_menu};
+use super::window::{ContextMenuOptions, MenuItem, find_item_path, show_menu, show_menu_with_path};
 
 // 前端获取菜单配置
 #[tauri::command]
@@ -8,11 +8,18 @@ pub fn get_context_menu_options() -> Result<ContextMenuOptions, String> {
     super::get_options().ok_or_else(|| "配置未初始化".to_string())
 }
 
-// 前端提交菜单选择结果
+// 前端提交菜单选择结果，未提供路径时根据菜单配置查找
 #[tauri::command]
-pub fn submit_context_menu(item_id: Option<String>) -> Result<(), String> {
+pub fn submit_context_menu(item_id: Option<String>, item_path: Option<Vec<String>>) -> Result<(), String> {
     let session_id = super::get_active_menu_session();
-    super::set_result(item_id);
+    let path = item_path.filter(|path| !path.is_empty()).or_else(|| {
+        item_id.map(|id| {
+            super::get_options()
+                .and_then(|options| find_item_path(&options.items, &id))
+                .unwrap_or_else(|| vec![id])
+        })
+    });
+    super::set_result(path);
     
     // 延迟标记菜单为不可见，避免点击菜单项时主窗口被误隐藏
     std::thread::spawn(move || {
@@ -46,6 +53,28 @@ pub async fn show_context_menu(
     show_menu(app, options).await
 }
 
+// 显示右键菜单并返回所选项的完整 ID 路径（子菜单项为 [父项ID, ..., 子项ID]）
+#[tauri::command]
+pub async fn show_context_menu_with_path(
+    app: AppHandle,
+    items: Vec<MenuItem>,
+    x: i32,
+    y: i32,
+    width: Option<i32>,
+    theme: Option<String>,
+) -> Result<Option<Vec<String>>, String> {
+    let options = ContextMenuOptions {
+        items,
+        x,
+        y,
+        width,
+        theme,
+        session_id: 0,
+    };
+
+    show_menu_with_path(app, options).await
+}
+
 // 隐藏所有右键菜单窗口
 #[tauri::command]
 pub fn close_all_context_menus(app: AppHandle) -> Result<(), String> {
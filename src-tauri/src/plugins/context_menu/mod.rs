@@ -7,8 +7,8 @@ use once_cell::sync::OnceCell;
 use std::sync::{Mutex, atomic::{AtomicBool, AtomicU64, Ordering}};
 use tauri::{AppHandle, Manager};
 
-// 存储菜单选择结果
-static MENU_RESULT: OnceCell<Mutex<Option<String>>> = OnceCell::new();
+// 存储菜单选择结果（从顶层到所选项的 ID 路径）
+static MENU_RESULT: OnceCell<Mutex<Option<Vec<String>>>> = OnceCell::new();
 
 // 存储菜单配置
 static MENU_OPTIONS: OnceCell<Mutex<Option<window::ContextMenuOptions>>> = OnceCell::new();
@@ -31,15 +31,15 @@ pub fn init() {
     MENU_OPTIONS.get_or_init(|| Mutex::new(None));
 }
 
-// 获取菜单选择结果
-pub(crate) fn get_result() -> Option<String> {
+// 获取菜单选择结果（ID 路径）
+pub(crate) fn get_result() -> Option<Vec<String>> {
     MENU_RESULT.get()
         .and_then(|r| r.lock().ok())
         .and_then(|r| r.clone())
 }
 
-// 设置菜单选择结果
-pub(crate) fn set_result(value: Option<String>) {
+// 设置菜单选择结果（ID 路径）
+pub(crate) fn set_result(value: Option<Vec<String>>) {
     if let Some(result_mutex) = MENU_RESULT.get() {
         if let Ok(mut result) = result_mutex.lock() {
             *result = value;
@@ -128,3 +128,26 @@ pub fn is_menu_visible() -> bool {
     MENU_VISIBLE.load(Ordering::Relaxed)
 }
 
+// 菜单显示时关闭菜单（视为取消选择），返回是否执行了关闭
+// 菜单窗口有焦点时由前端自行处理 Esc（先逐级关闭子菜单）
+pub fn close_menu_if_visible() -> bool {
+    if !is_menu_visible() {
+        return false;
+    }
+
+    let session_id = get_active_menu_session();
+    let app = APP_HANDLE.get()
+        .and_then(|h| h.lock().ok())
+        .and_then(|h| h.clone());
+    if let Some(window) = app.and_then(|app| app.get_webview_window("context-menu")) {
+        if window.is_focused().unwrap_or(false) {
+            return false;
+        }
+        let _ = window.hide();
+    }
+
+    clear_active_menu_session(session_id);
+    clear_options_for_session(session_id);
+    true
+}
+
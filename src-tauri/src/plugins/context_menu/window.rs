@@ -15,6 +15,9 @@ pub struct MenuItem {
     // Favicon URL
     #[serde(skip_serializing_if = "Option::is_none")]
     pub favicon: Option<String>,
+    // 快捷键提示文本（仅显示）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shortcut: Option<String>,
     // 是否禁用
     #[serde(default)]
     pub disabled: bool,
@@ -45,11 +48,36 @@ pub struct ContextMenuOptions {
     pub session_id: u64,
 }
 
-// 创建并显示右键菜单窗口
+// 在菜单树中查找指定 ID 的路径（深度优先，返回第一个匹配项）
+pub fn find_item_path(items: &[MenuItem], id: &str) -> Option<Vec<String>> {
+    for item in items.iter().filter(|item| !item.separator) {
+        if item.id == id {
+            return Some(vec![item.id.clone()]);
+        }
+        if let Some(children) = &item.children {
+            if let Some(mut path) = find_item_path(children, id) {
+                path.insert(0, item.id.clone());
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+// 创建并显示右键菜单窗口，返回所选项 ID（兼容平铺菜单）
 pub async fn show_menu(
     app: AppHandle,
-    mut options: ContextMenuOptions,
+    options: ContextMenuOptions,
 ) -> Result<Option<String>, String> {
+    let path = show_menu_with_path(app, options).await?;
+    Ok(path.and_then(|mut path| path.pop()))
+}
+
+// 创建并显示右键菜单窗口，返回所选项的完整 ID 路径
+pub async fn show_menu_with_path(
+    app: AppHandle,
+    mut options: ContextMenuOptions,
+) -> Result<Option<Vec<String>>, String> {
     use tauri::{LogicalPosition, LogicalSize};
     
     const MENU_WINDOW_LABEL: &str = "context-menu";
@@ -191,3 +219,36 @@ pub async fn show_menu(
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, children: Option<Vec<MenuItem>>) -> MenuItem {
+        MenuItem {
+            id: id.to_string(),
+            label: id.to_string(),
+            icon: None,
+            favicon: None,
+            shortcut: None,
+            disabled: false,
+            separator: false,
+            children,
+        }
+    }
+
+    #[test]
+    fn test_find_item_path() {
+        let items = vec![
+            item("copy", None),
+            item("move", Some(vec![item("group-a", None), item("group-b", None)])),
+        ];
+
+        assert_eq!(find_item_path(&items, "copy"), Some(vec!["copy".to_string()]));
+        assert_eq!(
+            find_item_path(&items, "group-b"),
+            Some(vec!["move".to_string(), "group-b".to_string()])
+        );
+        assert_eq!(find_item_path(&items, "missing"), None);
+    }
+}
@@ -1,12 +1,17 @@
 use once_cell::sync::OnceCell;
 use serde_json::json;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering};
 use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize, WebviewWindow, WebviewWindowBuilder};
 
 // 预览窗口状态
 pub static PREVIEW_WINDOW_VISIBLE: AtomicBool = AtomicBool::new(false);
 pub static PREVIEW_CURRENT_INDEX: AtomicUsize = AtomicUsize::new(0);
+// 确认模式下，松开快捷键后等待回车确认粘贴
+static PREVIEW_AWAITING_CONFIRM: AtomicBool = AtomicBool::new(false);
+// 滚轮累计量（达到设置的步长才移动一项）
+static PREVIEW_SCROLL_ACCUMULATOR: AtomicI64 = AtomicI64::new(0);
+static PREVIEW_GROUP_SCROLL_ACCUMULATOR: AtomicI64 = AtomicI64::new(0);
 static PREVIEW_WINDOW_HANDLE: OnceCell<Mutex<Option<WebviewWindow>>> = OnceCell::new();
 
 // 主窗口状态
@@ -18,6 +23,16 @@ struct MainWindowState {
     group_id: String,
 }
 
+// 预览项元数据（序号与快捷键粘贴的索引保持一致）
+#[derive(Debug, Clone, serde::Serialize)]
+struct PreviewItemMeta {
+    index: usize,
+    id: String,
+    content_kind: String,
+    // 数字快捷键编号（1-9），仅剪贴板历史且启用数字快捷键时存在
+    shortcut_number: Option<usize>,
+}
+
 // 预览窗口尺寸配置
 struct PreviewWindowSize {
     width: u32,
@@ -123,12 +138,25 @@ pub async fn show_preview_window(app: AppHandle) -> Result<(), String> {
 
         PREVIEW_WINDOW_VISIBLE.store(true, Ordering::SeqCst);
         PREVIEW_CURRENT_INDEX.store(0, Ordering::SeqCst);
+        PREVIEW_AWAITING_CONFIRM.store(false, Ordering::SeqCst);
+        PREVIEW_SCROLL_ACCUMULATOR.store(0, Ordering::SeqCst);
+        PREVIEW_GROUP_SCROLL_ACCUMULATOR.store(0, Ordering::SeqCst);
 
         crate::global_state::PREVIEW_CANCELLED_BY_USER.store(false, std::sync::atomic::Ordering::SeqCst);
 
-        let _ = window.emit("preview-index-changed", json!({ "index": 0 }));
+        let _ = window.emit("preview-index-changed", json!({
+            "index": 0,
+            "items": collect_preview_items(),
+        }));
         let _ = window.emit("clipboard-history-updated", ());
 
+        // 非管理员模式下，管理员权限窗口中收不到滚轮事件，提示用户改用方向键
+        if !crate::admin_privileges::is_running_as_admin()
+            && crate::admin_privileges::is_foreground_window_elevated()
+        {
+            let _ = window.emit("preview-scroll-unavailable", ());
+        }
+
         #[cfg(debug_assertions)]
         window.open_devtools();
     }
@@ -144,6 +172,7 @@ pub async fn hide_preview_window() -> Result<(), String> {
     if let Some(window) = window_guard.as_ref() {
         window.hide().map_err(|e| format!("隐藏预览窗口失败: {}", e))?;
         PREVIEW_WINDOW_VISIBLE.store(false, Ordering::SeqCst);
+        PREVIEW_AWAITING_CONFIRM.store(false, Ordering::SeqCst);
 
     }
 
@@ -185,7 +214,7 @@ async fn create_preview_window(app: AppHandle) -> Result<WebviewWindow, String>
 }
 
 // 定位预览窗口到鼠标位置
-fn position_preview_window(window: &WebviewWindow) -> Result<(), String> {
+pub(crate) fn position_preview_window(window: &WebviewWindow) -> Result<(), String> {
     // 获取窗口实际尺寸
     let window_size = window.outer_size().map_err(|e| format!("获取窗口尺寸失败: {}", e))?;
     let win_width = window_size.width as i32;
@@ -240,15 +269,84 @@ fn position_preview_window(window: &WebviewWindow) -> Result<(), String> {
     Ok(())
 }
 
-// 处理预览窗口滚动
-pub fn handle_preview_scroll(direction: &str) -> Result<(), String> {
+// 累计滚轮量，返回需要移动的步数（正数向上，负数向下）
+fn accumulate_wheel_delta(accumulator: &AtomicI64, delta: i64, step: u32) -> i64 {
+    let step = step.max(1) as i64;
+    let total = accumulator.load(Ordering::SeqCst) + delta;
+    let moves = total / step;
+    accumulator.store(total - moves * step, Ordering::SeqCst);
+    moves
+}
+
+// 处理预览窗口滚动（按设置的步长累计滚轮量）
+pub fn handle_preview_scroll(delta_y: i64) -> Result<(), String> {
+    if !PREVIEW_WINDOW_VISIBLE.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let settings = crate::settings::get_global_settings();
+    let moves = accumulate_wheel_delta(&PREVIEW_SCROLL_ACCUMULATOR, delta_y, settings.preview_scroll_step);
+    let direction = if moves > 0 { "up" } else { "down" };
+
+    for _ in 0..moves.abs() {
+        move_preview_index(direction, "wheel")?;
+    }
+
+    Ok(())
+}
+
+// 处理预览窗口横向滚动（网格布局下切换分组）
+pub fn handle_preview_horizontal_scroll(delta_x: i64) -> Result<(), String> {
+    if !PREVIEW_WINDOW_VISIBLE.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let settings = crate::settings::get_global_settings();
+    if settings.preview_layout != "grid" {
+        return Ok(());
+    }
+
+    let moves = accumulate_wheel_delta(&PREVIEW_GROUP_SCROLL_ACCUMULATOR, delta_x, settings.preview_scroll_step);
+    if moves == 0 {
+        return Ok(());
+    }
+
+    // 数据源顺序：剪贴板历史，然后是各个常用文本分组
+    let mut sources = vec![("clipboard".to_string(), "clipboard".to_string())];
+    sources.extend(
+        crate::database::get_all_groups()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|group| ("quick-texts".to_string(), group.name)),
+    );
+
+    let state = get_current_state();
+    let current = sources
+        .iter()
+        .position(|(tab, group_id)| *tab == state.tab && *group_id == state.group_id)
+        .unwrap_or(0) as i64;
+    let next = (current + moves).rem_euclid(sources.len() as i64) as usize;
+
+    let (tab, group_id) = sources[next].clone();
+    update_preview_source(tab, group_id)
+}
+
+// 处理预览窗口方向键（滚轮不可用时的备用方式）
+pub fn handle_preview_arrow_key(direction: &str) -> Result<(), String> {
     if !PREVIEW_WINDOW_VISIBLE.load(Ordering::SeqCst) {
         return Ok(());
     }
+    if move_preview_index(direction, "keyboard")? {
+        crate::sound_manager::play_nav_sound();
+    }
+    Ok(())
+}
 
+// 按方向移动预览索引，source 为 wheel / keyboard，返回索引是否改变
+fn move_preview_index(direction: &str, source: &str) -> Result<bool, String> {
     let data_length = get_current_data_source_length();
     if data_length == 0 {
-        return Ok(());
+        return Ok(false);
     }
 
     let current_index = PREVIEW_CURRENT_INDEX.load(Ordering::SeqCst);
@@ -269,12 +367,13 @@ pub fn handle_preview_scroll(direction: &str) -> Result<(), String> {
         if let Some(window) = window_guard.as_ref() {
             let _ = window.emit("preview-scroll", json!({
                 "direction": direction,
-                "newIndex": new_index
+                "newIndex": new_index,
+                "source": source
             }));
         }
     }
 
-    Ok(())
+    Ok(new_index != current_index)
 }
 
 // 获取当前预览索引
@@ -325,7 +424,8 @@ pub fn update_preview_source(tab: String, group_id: String) -> Result<(), String
             "preview-source-changed",
             serde_json::json!({
                 "tab": tab,
-                "groupId": group_id
+                "groupId": group_id,
+                "items": collect_preview_items(),
             }),
         );
     }
@@ -333,6 +433,65 @@ pub fn update_preview_source(tab: String, group_id: String) -> Result<(), String
     Ok(())
 }
 
+// 收集当前数据源的预览项元数据
+fn collect_preview_items() -> Vec<PreviewItemMeta> {
+    let state = get_current_state();
+    let number_shortcuts = state.tab == "clipboard"
+        && crate::settings::get_global_settings().number_shortcuts;
+
+    let entries: Vec<(String, String)> = if state.tab == "quick-texts" {
+        let quick_texts = if state.group_id == "all" || state.group_id == "clipboard" || state.group_id == "全部" {
+            crate::quick_texts::get_all_quick_texts()
+        } else {
+            crate::quick_texts::get_quick_texts_by_group(&state.group_id)
+        };
+        quick_texts
+            .into_iter()
+            .map(|item| (item.id, item.content_type.to_string()))
+            .collect()
+    } else {
+        // 与数字快捷键使用相同的数据源和排序
+        crate::database::get_clipboard_history(None)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| (item.id.to_string(), item.content_type.to_string()))
+            .collect()
+    };
+
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, (id, content_kind))| PreviewItemMeta {
+            index,
+            id,
+            content_kind,
+            shortcut_number: if number_shortcuts && index < 9 {
+                Some(index + 1)
+            } else {
+                None
+            },
+        })
+        .collect()
+}
+
+// 获取主窗口当前数据源状态
+fn get_current_state() -> MainWindowState {
+    let state_handle = MAIN_WINDOW_STATE.get_or_init(|| {
+        Mutex::new(MainWindowState {
+            tab: "clipboard".to_string(),
+            group_id: "clipboard".to_string(),
+        })
+    });
+
+    state_handle
+        .lock()
+        .map(|state| state.clone())
+        .unwrap_or(MainWindowState {
+            tab: "clipboard".to_string(),
+            group_id: "clipboard".to_string(),
+        })
+}
+
 // 获取当前数据源的长度
 fn get_current_data_source_length() -> usize {
     let state_handle = MAIN_WINDOW_STATE.get_or_init(|| {
@@ -399,13 +558,43 @@ pub async fn cancel_preview() -> Result<(), String> {
     hide_preview_window().await
 }
 
-// 粘贴当前预览项
+// 是否正在等待用户确认粘贴
+pub fn is_preview_awaiting_confirm() -> bool {
+    PREVIEW_AWAITING_CONFIRM.load(Ordering::SeqCst)
+}
+
+// 粘贴当前预览项（快捷键松开时调用，确认模式下仅选中并保持窗口显示）
 pub async fn paste_current_preview_item() -> Result<(), String> {
     let settings = crate::settings::get_global_settings();
     if !settings.preview_auto_paste {
         return hide_preview_window().await;
     }
 
+    if settings.preview_confirm_mode == "confirm" {
+        PREVIEW_AWAITING_CONFIRM.store(true, Ordering::SeqCst);
+        let window_handle = PREVIEW_WINDOW_HANDLE.get().unwrap();
+        let window_guard = window_handle.lock().unwrap();
+        if let Some(window) = window_guard.as_ref() {
+            let _ = window.emit("preview-awaiting-confirm", json!({
+                "index": get_preview_index(),
+            }));
+        }
+        return Ok(());
+    }
+
+    paste_selected_preview_item().await
+}
+
+// 确认粘贴当前预览项（确认模式下按回车）
+pub async fn confirm_preview_paste() -> Result<(), String> {
+    if !PREVIEW_AWAITING_CONFIRM.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+    paste_selected_preview_item().await
+}
+
+// 隐藏预览窗口并粘贴当前选中项
+async fn paste_selected_preview_item() -> Result<(), String> {
     let index = get_preview_index();
 
     // 隐藏预览窗口
@@ -461,3 +650,19 @@ pub async fn paste_current_preview_item() -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulate_wheel_delta() {
+        let accumulator = AtomicI64::new(0);
+        assert_eq!(accumulate_wheel_delta(&accumulator, 1, 3), 0);
+        assert_eq!(accumulate_wheel_delta(&accumulator, 1, 3), 0);
+        assert_eq!(accumulate_wheel_delta(&accumulator, 1, 3), 1);
+        assert_eq!(accumulate_wheel_delta(&accumulator, -7, 3), -2);
+        assert_eq!(accumulator.load(Ordering::SeqCst), -1);
+        assert_eq!(accumulate_wheel_delta(&accumulator, 2, 1), 1);
+    }
+}
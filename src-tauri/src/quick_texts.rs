@@ -31,6 +31,24 @@ pub fn get_quick_texts_by_group(group_name: &str) -> Vec<FavoriteItem> {
     }
 }
 
+// 获取设置中的默认分组，分组已被删除时回退到"全部"
+pub fn resolve_default_group() -> String {
+    let default_group = crate::settings::get_global_settings().default_group_id;
+    if default_group.is_empty() || default_group == "全部" {
+        return "全部".to_string();
+    }
+
+    let exists = database::get_all_groups()
+        .map(|groups| groups.iter().any(|g| g.name == default_group))
+        .unwrap_or(false);
+    if exists {
+        default_group
+    } else {
+        println!("默认分组 '{}' 不存在，使用'全部'分组", default_group);
+        "全部".to_string()
+    }
+}
+
 // 添加常用文本
 pub fn add_quick_text(
     title: String,
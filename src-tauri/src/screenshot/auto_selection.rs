@@ -155,6 +155,11 @@ impl AutoSelectionManager {
             return Ok(());
         }
 
+        // 关闭智能选区时不启动检测，只能手动拖拽选区
+        if !crate::settings::get_global_settings().screenshot_smart_selection_enabled {
+            return Ok(());
+        }
+
         let screenshot_hwnd = if let Some(window) = app.get_webview_window("screenshot") {
             window.hwnd().ok().map(|h| h.0 as isize)
         } else {
@@ -225,7 +230,7 @@ impl AutoSelectionManager {
             let settings = crate::settings::get_global_settings();
             let current_mode = DetectionMode::from_string(&settings.screenshot_element_detection);
             
-            if current_mode == DetectionMode::None {
+            if current_mode == DetectionMode::None || !settings.screenshot_smart_selection_enabled {
                 if last_bounds.is_some() {
                     last_bounds = None;
                     if let Some(app_guard) = app_handle.try_lock() {
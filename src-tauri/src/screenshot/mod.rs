@@ -4,9 +4,11 @@ pub mod scrolling_screenshot;
 pub mod screen_utils;
 pub mod image_stitcher;
 pub mod auto_selection;
+pub mod screenshot_file;
 
 // 公共接口
 pub use screenshot_window::*;
 pub use scrolling_screenshot::*;
 pub use auto_selection::*;
+pub use screenshot_file::*;
 
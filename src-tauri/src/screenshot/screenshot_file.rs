@@ -0,0 +1,258 @@
+use std::path::{Path, PathBuf};
+
+// 默认文件名模板
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "QC截屏_{date}_{time}";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScreenshotKind {
+    Region,
+    Scrolling,
+}
+
+impl ScreenshotKind {
+    // 未配置保存目录时使用的数据目录子目录
+    fn default_subdir(&self) -> &'static str {
+        match self {
+            ScreenshotKind::Region => "clipboard_images/screenshots",
+            ScreenshotKind::Scrolling => "clipboard_images/scrolling_screenshots",
+        }
+    }
+}
+
+// 获取截屏保存目录（不存在时创建）
+pub fn get_screenshot_save_dir(kind: ScreenshotKind) -> Result<PathBuf, String> {
+    let settings = crate::settings::get_global_settings();
+    let custom_dir = settings.screenshot_save_dir.trim();
+
+    let dir = if custom_dir.is_empty() {
+        crate::settings::get_data_directory()?.join(kind.default_subdir())
+    } else {
+        PathBuf::from(custom_dir)
+    };
+
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建截屏保存目录失败: {}", e))?;
+    Ok(dir)
+}
+
+// 按模板生成不重复的截屏文件路径
+pub fn build_screenshot_path(kind: ScreenshotKind, extension: &str) -> Result<PathBuf, String> {
+    let dir = get_screenshot_save_dir(kind)?;
+    let template = crate::settings::get_global_settings().screenshot_filename_template;
+    let now = chrono::Local::now();
+    let date = now.format("%Y%m%d").to_string();
+    let time = now.format("%H%M%S").to_string();
+
+    Ok(next_available_path(&dir, &template, &date, &time, extension))
+}
+
+fn next_available_path(dir: &Path, template: &str, date: &str, time: &str, extension: &str) -> PathBuf {
+    let template = if template.trim().is_empty() {
+        DEFAULT_FILENAME_TEMPLATE
+    } else {
+        template
+    };
+
+    // 模板不含 {n} 时，仅在重名时追加序号
+    let has_counter = template.contains("{n}");
+    let mut n = 1;
+    loop {
+        let mut name = render_filename_template(template, date, time, n);
+        if !has_counter && n > 1 {
+            name = format!("{}_{}", name, n);
+        }
+
+        let path = dir.join(format!("{}.{}", name, extension));
+        if !path.exists() {
+            return path;
+        }
+        n += 1;
+    }
+}
+
+// 替换模板中的 {date}、{time}、{n}，并去除文件名中的非法字符
+pub fn render_filename_template(template: &str, date: &str, time: &str, n: u32) -> String {
+    let name: String = template
+        .replace("{date}", date)
+        .replace("{time}", time)
+        .replace("{n}", &n.to_string())
+        .chars()
+        .map(|c| match c {
+            '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    let name = name.trim().trim_end_matches('.').to_string();
+    if name.is_empty() {
+        render_filename_template(DEFAULT_FILENAME_TEMPLATE, date, time, n)
+    } else {
+        name
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScreenshotFormat {
+    Png,
+    Jpeg,
+    WebP,
+}
+
+impl ScreenshotFormat {
+    // 按设置选择格式；auto 时长截屏始终保存 PNG，选区截屏质量为 100 保存 PNG，否则保存 JPEG
+    fn from_settings(format: &str, quality: u8, kind: ScreenshotKind) -> Self {
+        match format {
+            "png" => ScreenshotFormat::Png,
+            "jpeg" => ScreenshotFormat::Jpeg,
+            "webp" => ScreenshotFormat::WebP,
+            _ if kind == ScreenshotKind::Scrolling || quality >= 100 => ScreenshotFormat::Png,
+            _ => ScreenshotFormat::Jpeg,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ScreenshotFormat::Png => "png",
+            ScreenshotFormat::Jpeg => "jpg",
+            ScreenshotFormat::WebP => "webp",
+        }
+    }
+}
+
+fn encode_image(image: &image::DynamicImage, format: ScreenshotFormat, quality: u8) -> Result<Vec<u8>, String> {
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    match format {
+        ScreenshotFormat::Png => image
+            .write_to(&mut buffer, image::ImageFormat::Png)
+            .map_err(|e| format!("PNG编码失败: {}", e))?,
+        ScreenshotFormat::Jpeg => {
+            // JPEG 不支持透明通道
+            let rgb = image::DynamicImage::ImageRgb8(image.to_rgb8());
+            image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buffer, quality)
+                .encode_image(&rgb)
+                .map_err(|e| format!("JPEG编码失败: {}", e))?
+        }
+        ScreenshotFormat::WebP => {
+            // image 库的 WebP 编码器只支持无损，按质量有损编码需使用 libwebp
+            let rgba = image.to_rgba8();
+            let data = webp::Encoder::from_rgba(rgba.as_raw(), rgba.width(), rgba.height())
+                .encode_simple(false, quality as f32)
+                .map_err(|e| format!("WebP编码失败: {:?}", e))?;
+            return Ok(data.to_vec());
+        }
+    }
+    Ok(buffer.into_inner())
+}
+
+// 按截屏格式和质量设置保存图片，所选格式编码失败时回退为 PNG
+pub fn save_screenshot_image(image: &image::DynamicImage, kind: ScreenshotKind) -> Result<PathBuf, String> {
+    let settings = crate::settings::get_global_settings();
+    let quality = settings.screenshot_quality.clamp(1, 100);
+    let mut format = ScreenshotFormat::from_settings(&settings.screenshot_format, quality, kind);
+
+    let data = match encode_image(image, format, quality) {
+        Ok(data) => data,
+        Err(e) if format != ScreenshotFormat::Png => {
+            eprintln!("{}，改用PNG保存", e);
+            format = ScreenshotFormat::Png;
+            encode_image(image, format, quality)?
+        }
+        Err(e) => return Err(e),
+    };
+
+    let file_path = build_screenshot_path(kind, format.extension())?;
+    std::fs::write(&file_path, data).map_err(|e| format!("保存图片文件失败: {}", e))?;
+
+    println!("截屏已保存: {}", file_path.display());
+    Ok(file_path)
+}
+
+fn decode_data_url_image(data_url: &str) -> Result<image::DynamicImage, String> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let base64_data = data_url
+        .split_once(',')
+        .map(|(_, data)| data)
+        .ok_or("无效的data URL格式")?;
+    let image_data = general_purpose::STANDARD
+        .decode(base64_data)
+        .map_err(|e| format!("Base64解码失败: {}", e))?;
+
+    image::load_from_memory(&image_data).map_err(|e| format!("解析图片失败: {}", e))
+}
+
+// 保存选区截屏，开启相应设置时同时把文件路径记录为一条文件历史
+pub fn save_region_screenshot_image(app: &tauri::AppHandle, image: &image::DynamicImage) -> Result<PathBuf, String> {
+    let file_path = save_screenshot_image(image, ScreenshotKind::Region)?;
+
+    if crate::settings::get_global_settings().screenshot_also_store_path {
+        if let Err(e) = store_path_history_entry(app, &file_path) {
+            log::warn!("记录截屏文件路径失败: {}", e);
+        }
+    }
+
+    Ok(file_path)
+}
+
+// 将截屏文件作为文件类型条目加入历史
+fn store_path_history_entry(app: &tauri::AppHandle, file_path: &Path) -> Result<(), String> {
+    crate::clipboard_history::add_files_entry(&[file_path.to_string_lossy().to_string()])?;
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = crate::commands::emit_clipboard_updated(app_handle).await;
+    });
+    Ok(())
+}
+
+// 将前端导出的选区图片保存到文件，返回保存路径
+#[tauri::command]
+pub fn save_region_screenshot(app: tauri::AppHandle, data_url: String) -> Result<String, String> {
+    let image = decode_data_url_image(&data_url)?;
+    let file_path = save_region_screenshot_image(&app, &image)?;
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+// 选区截屏复制到剪贴板后调用，开启自动保存时同时写入文件
+#[tauri::command]
+pub fn auto_save_region_screenshot(app: tauri::AppHandle, data_url: String) -> Result<Option<String>, String> {
+    if !crate::settings::get_global_settings().screenshot_auto_save {
+        return Ok(None);
+    }
+    save_region_screenshot(app, data_url).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_filename_template() {
+        assert_eq!(
+            render_filename_template("shot_{date}_{time}_{n}", "20240102", "030405", 7),
+            "shot_20240102_030405_7"
+        );
+        assert_eq!(render_filename_template("a/b:c", "d", "t", 1), "a_b_c");
+        assert_eq!(
+            render_filename_template("  ", "20240102", "030405", 1),
+            "QC截屏_20240102_030405"
+        );
+    }
+
+    #[test]
+    fn test_screenshot_format_from_settings() {
+        let region = ScreenshotKind::Region;
+        assert_eq!(ScreenshotFormat::from_settings("auto", 100, region), ScreenshotFormat::Png);
+        assert_eq!(ScreenshotFormat::from_settings("auto", 90, region), ScreenshotFormat::Jpeg);
+        assert_eq!(ScreenshotFormat::from_settings("webp", 90, region), ScreenshotFormat::WebP);
+        assert_eq!(ScreenshotFormat::from_settings("png", 50, region), ScreenshotFormat::Png);
+        assert_eq!(
+            ScreenshotFormat::from_settings("auto", 85, ScreenshotKind::Scrolling),
+            ScreenshotFormat::Png
+        );
+        assert_eq!(
+            ScreenshotFormat::from_settings("jpeg", 85, ScreenshotKind::Scrolling),
+            ScreenshotFormat::Jpeg
+        );
+    }
+}
@@ -1,19 +1,48 @@
-use windows::Win32::Graphics::Gdi::{GetDC, CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, BitBlt, GetDIBits, DeleteDC, ReleaseDC, DeleteObject, BITMAPINFOHEADER, BITMAPINFO, BI_RGB, DIB_RGB_COLORS, SRCCOPY};
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Graphics::Gdi::{HBITMAP, HDC, HGDIOBJ, GetDC, CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, BitBlt, GetDIBits, DeleteDC, ReleaseDC, DeleteObject, BITMAPINFOHEADER, BITMAPINFO, BI_RGB, DIB_RGB_COLORS, SRCCOPY};
 use windows::Win32::UI::WindowsAndMessaging::GetDesktopWindow;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use tauri::{Emitter, Manager};
 use std::io::Write;
 use std::net::{TcpListener, TcpStream};
 use std::thread;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use once_cell::sync::{Lazy, OnceCell};
 use serde_json;
 
 static SCREENSHOT_WINDOW_VISIBLE: AtomicBool = AtomicBool::new(false);
+static CAPTURE_CANCELLED: AtomicBool = AtomicBool::new(false);
+static CAPTURE_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+// 常驻的本地图片服务端口，只在首次截屏时启动一次
+static IMAGE_SERVER_PORT: OnceCell<u16> = OnceCell::new();
+// 当前可供前端获取的最新截屏（id, BMP数据），新截屏会替换旧数据
+static LATEST_IMAGE: Lazy<Mutex<Option<(u64, Arc<Vec<u8>>)>>> = Lazy::new(|| Mutex::new(None));
+static NEXT_IMAGE_ID: AtomicU64 = AtomicU64::new(1);
+
+// BMP 文件头 + 信息头长度
+const BMP_HEADER_SIZE: usize = 54;
 
 pub struct ScreenshotWindowManager;
 
 impl ScreenshotWindowManager {
     pub fn show_screenshot_window(app: &tauri::AppHandle) -> Result<(), String> {
+        // 截取前先占用显示状态，截屏期间的重复触发直接忽略
+        if SCREENSHOT_WINDOW_VISIBLE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let result = Self::capture_and_show(app);
+        if result.is_err() {
+            Self::reset_state();
+        }
+        result
+    }
+
+    fn capture_and_show(app: &tauri::AppHandle) -> Result<(), String> {
         let screenshot_window = app
             .get_webview_window("screenshot")
             .ok_or_else(|| "截屏窗口未找到".to_string())?;
@@ -34,12 +63,12 @@ impl ScreenshotWindowManager {
             .set_focus()
             .map_err(|e| format!("设置截屏窗口焦点失败: {}", e))?;
 
-        SCREENSHOT_WINDOW_VISIBLE.store(true, Ordering::Relaxed);
+        Self::start_state_watchdog(app.clone());
 
         let window_for_data = screenshot_window.clone();
         
         std::thread::spawn(move || {
-            match Self::serve_screenshot_via_http(&bmp_data, capture_width, capture_height) {
+            match Self::serve_screenshot_via_http(bmp_data, capture_width, capture_height) {
                 Ok(image_url) => {
                     let payload = serde_json::json!({
                         "width": capture_width,
@@ -71,10 +100,7 @@ impl ScreenshotWindowManager {
             .hide()
             .map_err(|e| format!("隐藏截屏窗口失败: {}", e))?;
 
-        SCREENSHOT_WINDOW_VISIBLE.store(false, Ordering::Relaxed);
-
-        // 清除自动选区缓存
-        super::auto_selection::AUTO_SELECTION_MANAGER.clear_cache();
+        Self::reset_state();
 
         // 重载
         let _ = screenshot_window.eval("window.location.reload()");
@@ -83,6 +109,14 @@ impl ScreenshotWindowManager {
     }
 
     pub fn toggle_screenshot_window(app: &tauri::AppHandle) -> Result<(), String> {
+        // 截取尚未完成时再次触发视为取消
+        if Self::is_capturing() {
+            Self::cancel_capture();
+            return Ok(());
+        }
+        if Self::is_state_stale(app) {
+            Self::reset_state();
+        }
         if SCREENSHOT_WINDOW_VISIBLE.load(Ordering::Relaxed) {
             Self::hide_screenshot_window(app)
         } else {
@@ -94,6 +128,56 @@ impl ScreenshotWindowManager {
         SCREENSHOT_WINDOW_VISIBLE.load(Ordering::Relaxed)
     }
 
+    // 是否正在截取全屏（窗口尚未显示）
+    pub fn is_capturing() -> bool {
+        CAPTURE_IN_PROGRESS.load(Ordering::Acquire)
+    }
+
+    // 取消正在进行的全屏截取
+    pub fn cancel_capture() {
+        if Self::is_capturing() {
+            CAPTURE_CANCELLED.store(true, Ordering::Relaxed);
+        }
+    }
+
+    // 重置截屏状态（窗口异常关闭时也需调用）
+    fn reset_state() {
+        SCREENSHOT_WINDOW_VISIBLE.store(false, Ordering::Relaxed);
+        // 释放已提供给前端的截屏数据
+        *LATEST_IMAGE.lock().unwrap() = None;
+        // 清除自动选区缓存
+        super::auto_selection::AUTO_SELECTION_MANAGER.clear_cache();
+    }
+
+    // 状态标记为显示但窗口已不存在或已隐藏时，视为残留状态
+    fn is_state_stale(app: &tauri::AppHandle) -> bool {
+        if !SCREENSHOT_WINDOW_VISIBLE.load(Ordering::Relaxed) {
+            return false;
+        }
+        // 截取期间窗口尚未显示，不算残留
+        if Self::is_capturing() {
+            return false;
+        }
+        match app.get_webview_window("screenshot") {
+            Some(window) => !window.is_visible().unwrap_or(false),
+            None => true,
+        }
+    }
+
+    // 截屏期间定期检查窗口状态，发现残留状态时强制清理
+    fn start_state_watchdog(app: tauri::AppHandle) {
+        thread::spawn(move || {
+            while SCREENSHOT_WINDOW_VISIBLE.load(Ordering::Relaxed) {
+                thread::sleep(std::time::Duration::from_millis(1000));
+                if Self::is_state_stale(&app) {
+                    println!("截屏窗口已关闭但状态残留，强制清理");
+                    Self::reset_state();
+                    break;
+                }
+            }
+        });
+    }
+
     fn set_fullscreen_size(
         _app: &tauri::AppHandle,
         window: &tauri::WebviewWindow,
@@ -128,10 +212,16 @@ impl ScreenshotWindowManager {
 
         let screenshot_window_clone = screenshot_window.clone();
         screenshot_window.on_window_event(move |event| {
-            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                api.prevent_close();
-                let _ = screenshot_window_clone.hide();
-                SCREENSHOT_WINDOW_VISIBLE.store(false, Ordering::Relaxed);
+            match event {
+                tauri::WindowEvent::CloseRequested { api, .. } => {
+                    api.prevent_close();
+                    let _ = screenshot_window_clone.hide();
+                    ScreenshotWindowManager::reset_state();
+                }
+                tauri::WindowEvent::Destroyed => {
+                    ScreenshotWindowManager::reset_state();
+                }
+                _ => {}
             }
         });
 
@@ -176,8 +266,12 @@ pub fn constrain_selection_bounds(
     Ok((constrained_x, constrained_y))
 }
 
-#[tauri::command]
+// 在后台线程执行，避免截取大尺寸屏幕时阻塞界面
+#[tauri::command(async)]
 pub fn show_screenshot_window(app: tauri::AppHandle) -> Result<(), String> {
+    if ScreenshotWindowManager::is_state_stale(&app) {
+        ScreenshotWindowManager::reset_state();
+    }
     if SCREENSHOT_WINDOW_VISIBLE.load(Ordering::Relaxed) {
         return Ok(());
     }
@@ -189,11 +283,17 @@ pub fn hide_screenshot_window(app: tauri::AppHandle) -> Result<(), String> {
     ScreenshotWindowManager::hide_screenshot_window(&app)
 }
 
-#[tauri::command]
+#[tauri::command(async)]
 pub fn toggle_screenshot_window(app: tauri::AppHandle) -> Result<(), String> {
     ScreenshotWindowManager::toggle_screenshot_window(&app)
 }
 
+// 取消正在进行的全屏截取
+#[tauri::command]
+pub fn cancel_screenshot_capture() {
+    ScreenshotWindowManager::cancel_capture();
+}
+
 #[tauri::command]
 pub fn is_screenshot_window_visible() -> bool {
     ScreenshotWindowManager::is_screenshot_window_visible()
@@ -204,12 +304,111 @@ pub fn get_all_monitors(window: tauri::WebviewWindow) -> Result<Vec<super::scree
     ScreenshotWindowManager::get_all_monitors(&window)
 }
 
+// 记录最近一次确认的截屏区域（传入 CSS 坐标，按物理像素保存）
+#[tauri::command]
+pub fn remember_screenshot_region(
+    window: tauri::WebviewWindow,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+) -> Result<(), String> {
+    let scale_factor = window.scale_factor().unwrap_or(1.0);
+    let origin = window
+        .outer_position()
+        .map_err(|e| format!("获取窗口位置失败: {}", e))?;
+
+    let physical_width = (width * scale_factor).round() as u32;
+    let physical_height = (height * scale_factor).round() as u32;
+    if physical_width == 0 || physical_height == 0 {
+        return Err("截屏区域尺寸无效".to_string());
+    }
+
+    crate::settings::save_screenshot_region(
+        origin.x + (x * scale_factor).round() as i32,
+        origin.y + (y * scale_factor).round() as i32,
+        physical_width,
+        physical_height,
+    )
+}
+
+// 不显示截屏窗口，直接重新截取上次的区域并复制到剪贴板
+#[tauri::command(async)]
+pub fn take_last_region_screenshot(app: tauri::AppHandle) -> Result<(), String> {
+    let (x, y, width, height) = crate::settings::get_global_settings()
+        .last_screenshot_region
+        .ok_or("没有记录的截屏区域")?;
+
+    // 显示器布局变化后区域可能已失效
+    let (screen_x, screen_y, screen_w, screen_h) =
+        super::screen_utils::ScreenUtils::get_virtual_screen_size()?;
+    let inside = width > 0
+        && height > 0
+        && x >= screen_x
+        && y >= screen_y
+        && x as i64 + width as i64 <= screen_x as i64 + screen_w as i64
+        && y as i64 + height as i64 <= screen_y as i64 + screen_h as i64;
+    if !inside {
+        return Err("记录的截屏区域已超出屏幕范围".to_string());
+    }
+
+    let mut bgra = unsafe {
+        ScreenshotWindowManager::capture_bgra_with_gdi(x, y, width as i32, height as i32)?
+    };
+    // 屏幕截取的 alpha 通道无意义，统一设为不透明
+    for alpha in bgra.iter_mut().skip(3).step_by(4) {
+        *alpha = 255;
+    }
+
+    let image = image::DynamicImage::ImageRgba8(
+        super::image_stitcher::ImageStitcher::bgra_to_rgba_image(&bgra, width, height),
+    );
+    let mut png_bytes = std::io::Cursor::new(Vec::new());
+    image
+        .write_to(&mut png_bytes, image::ImageFormat::Png)
+        .map_err(|e| format!("PNG编码失败: {}", e))?;
+
+    crate::clipboard_content::set_windows_clipboard_image(&bgra, png_bytes.get_ref(), width, height)?;
+
+    if crate::settings::get_global_settings().screenshot_auto_save {
+        super::screenshot_file::save_region_screenshot_image(&app, &image)?;
+    }
+
+    Ok(())
+}
+
 // 设置鼠标到指定的物理像素位置（用于方向键精确移动）
 #[tauri::command]
 pub fn set_cursor_position_physical(x: i32, y: i32) -> Result<(), String> {
     crate::mouse_utils::set_cursor_position(x, y)
 }
 
+// GDI 截屏资源，析构时按创建的逆序释放
+struct GdiCaptureResources {
+    desktop_wnd: HWND,
+    desktop_dc: HDC,
+    mem_dc: Option<HDC>,
+    bitmap: Option<HBITMAP>,
+    old_bitmap: Option<HGDIOBJ>,
+}
+
+impl Drop for GdiCaptureResources {
+    fn drop(&mut self) {
+        unsafe {
+            if let (Some(mem_dc), Some(old_bitmap)) = (self.mem_dc, self.old_bitmap) {
+                let _ = SelectObject(mem_dc, old_bitmap);
+            }
+            if let Some(bitmap) = self.bitmap {
+                let _ = DeleteObject(bitmap);
+            }
+            if let Some(mem_dc) = self.mem_dc {
+                let _ = DeleteDC(mem_dc);
+            }
+            let _ = ReleaseDC(self.desktop_wnd, self.desktop_dc);
+        }
+    }
+}
+
 pub struct ScreenshotCapture {
     pub data: Vec<u8>,
     pub width: u32,
@@ -217,39 +416,97 @@ pub struct ScreenshotCapture {
 }
 
 impl ScreenshotWindowManager {
+    // 在后台线程截取全屏，等待期间可取消，超时后放弃结果
     fn capture_screenshot_sync(window: &tauri::WebviewWindow) -> Result<ScreenshotCapture, String> {
         let (x, y, w, h) = super::screen_utils::ScreenUtils::get_virtual_screen_size_from_window(window)?;
-        unsafe { Self::capture_with_gdi(x, y, w, h) }
+        let timeout = std::time::Duration::from_secs(
+            crate::settings::get_global_settings().screenshot_capture_timeout_secs as u64,
+        );
+        CAPTURE_CANCELLED.store(false, Ordering::Relaxed);
+        CAPTURE_IN_PROGRESS.store(true, Ordering::Release);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        thread::spawn(move || {
+            let _ = tx.send(unsafe { Self::capture_with_gdi(x, y, w, h) });
+        });
+
+        let started = std::time::Instant::now();
+        let result = loop {
+            match rx.recv_timeout(std::time::Duration::from_millis(20)) {
+                Ok(result) => break result,
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                    break Err("截屏线程异常退出".to_string());
+                }
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+            }
+
+            if CAPTURE_CANCELLED.load(Ordering::Relaxed) {
+                break Err("截屏已取消".to_string());
+            }
+            if started.elapsed() > timeout {
+                break Err("截屏超时".to_string());
+            }
+        };
+
+        CAPTURE_IN_PROGRESS.store(false, Ordering::Release);
+        result
     }
 
+    // 像素直接写入 BMP 缓冲区的数据区，省去整屏数据的额外拷贝
     unsafe fn capture_with_gdi(x: i32, y: i32, width: i32, height: i32) -> Result<ScreenshotCapture, String> {
+        let pixel_data_size = (width as usize) * (height as usize) * 4;
+        let mut bmp_data = vec![0u8; BMP_HEADER_SIZE + pixel_data_size];
+        Self::write_bmp_header(&mut bmp_data[..BMP_HEADER_SIZE], width as u32, height as u32);
+        Self::capture_bgra_into(x, y, width, height, &mut bmp_data[BMP_HEADER_SIZE..])?;
+
+        Ok(ScreenshotCapture {
+            data: bmp_data,
+            width: width as u32,
+            height: height as u32,
+        })
+    }
+
+    // 截取指定物理像素区域，返回 BGRA 像素数据
+    unsafe fn capture_bgra_with_gdi(x: i32, y: i32, width: i32, height: i32) -> Result<Vec<u8>, String> {
+        let mut pixel_data = vec![0u8; (width as usize) * (height as usize) * 4];
+        Self::capture_bgra_into(x, y, width, height, &mut pixel_data)?;
+        Ok(pixel_data)
+    }
+
+    // 截取指定物理像素区域，BGRA 像素写入 out（长度需为 width * height * 4）
+    unsafe fn capture_bgra_into(x: i32, y: i32, width: i32, height: i32, out: &mut [u8]) -> Result<(), String> {
+        if out.len() != (width as usize) * (height as usize) * 4 {
+            return Err("像素缓冲区大小不匹配".to_string());
+        }
+
         let desktop_wnd = GetDesktopWindow();
         let desktop_dc = GetDC(desktop_wnd);
         if desktop_dc.is_invalid() {
             return Err("获取桌面DC失败".to_string());
         }
+        // 任何返回路径（包括 panic）都会释放 GDI 资源
+        let mut resources = GdiCaptureResources {
+            desktop_wnd,
+            desktop_dc,
+            mem_dc: None,
+            bitmap: None,
+            old_bitmap: None,
+        };
 
         let mem_dc = CreateCompatibleDC(desktop_dc);
         if mem_dc.is_invalid() {
-            ReleaseDC(desktop_wnd, desktop_dc);
             return Err("创建兼容DC失败".to_string());
         }
+        resources.mem_dc = Some(mem_dc);
 
         let bitmap = CreateCompatibleBitmap(desktop_dc, width, height);
         if bitmap.is_invalid() {
-            let _ = DeleteDC(mem_dc);
-            let _ = ReleaseDC(desktop_wnd, desktop_dc);
             return Err("创建位图失败".to_string());
         }
+        resources.bitmap = Some(bitmap);
+        resources.old_bitmap = Some(SelectObject(mem_dc, bitmap));
 
-        let old_bitmap = SelectObject(mem_dc, bitmap);
-        let success = BitBlt(mem_dc, 0, 0, width, height, desktop_dc, x, y, SRCCOPY);
-
-        if success.is_err() {
-            let _ = SelectObject(mem_dc, old_bitmap);
-            let _ = DeleteObject(bitmap);
-            let _ = DeleteDC(mem_dc);
-            let _ = ReleaseDC(desktop_wnd, desktop_dc);
+        if BitBlt(mem_dc, 0, 0, width, height, desktop_dc, x, y, SRCCOPY).is_err() {
             return Err("截屏失败".to_string());
         }
 
@@ -270,44 +527,33 @@ impl ScreenshotWindowManager {
             bmiColors: [Default::default(); 1],
         };
 
-        let pixel_count = (width * height) as usize;
-        let mut pixel_data: Vec<u8> = vec![0; pixel_count * 4];
-
         let lines = GetDIBits(
             mem_dc,
             bitmap,
             0,
             height as u32,
-            Some(pixel_data.as_mut_ptr() as *mut _),
+            Some(out.as_mut_ptr() as *mut _),
             &mut bitmap_info,
             DIB_RGB_COLORS
         );
 
         // 清理资源
-        let _ = SelectObject(mem_dc, old_bitmap);
-        let _ = DeleteObject(bitmap);
-        let _ = DeleteDC(mem_dc);
-        let _ = ReleaseDC(desktop_wnd, desktop_dc);
+        drop(resources);
 
         if lines == 0 {
             return Err("获取位图数据失败".to_string());
         }
 
-        let bmp_data = Self::create_bmp_from_bgra(&pixel_data, width as u32, height as u32);
-
-        Ok(ScreenshotCapture {
-            data: bmp_data,
-            width: width as u32,
-            height: height as u32,
-        })
+        Ok(())
     }
 
-    fn create_bmp_from_bgra(pixel_data: &[u8], width: u32, height: u32) -> Vec<u8> {
-        let pixel_data_size = pixel_data.len() as u32;
-        let file_size = 54 + pixel_data_size;
-        
-        let mut bmp_data = Vec::with_capacity(file_size as usize);
-        
+    // 写入 32 位自顶向下 BMP 的文件头和信息头
+    fn write_bmp_header(header: &mut [u8], width: u32, height: u32) {
+        let pixel_data_size = width * height * 4;
+        let file_size = BMP_HEADER_SIZE as u32 + pixel_data_size;
+
+        let mut bmp_data = Vec::with_capacity(BMP_HEADER_SIZE);
+
         bmp_data.extend_from_slice(b"BM");
         bmp_data.extend_from_slice(&file_size.to_le_bytes());
         bmp_data.extend_from_slice(&0u16.to_le_bytes());
@@ -325,34 +571,76 @@ impl ScreenshotWindowManager {
         bmp_data.extend_from_slice(&0u32.to_le_bytes());
         bmp_data.extend_from_slice(&0u32.to_le_bytes());
         bmp_data.extend_from_slice(&0u32.to_le_bytes());
-        
-        bmp_data.extend_from_slice(pixel_data);
-        
-        bmp_data
+
+        header.copy_from_slice(&bmp_data);
     }
 
-    fn serve_screenshot_via_http(bmp_data: &[u8], _width: u32, _height: u32) -> Result<String, String> {
-        let listener = TcpListener::bind("127.0.0.1:0")
-            .map_err(|e| format!("绑定端口失败: {}", e))?;
-        let port = listener.local_addr().unwrap().port();
-        
-        let image_data = Arc::new(bmp_data.to_vec());
-        
-        thread::spawn(move || {
-            if let Ok((stream, _)) = listener.accept() {
-                Self::handle_http_request(stream, &image_data);
-            }
-        });
-        
-        Ok(format!("http://127.0.0.1:{}/screenshot.bmp", port))
+    fn serve_screenshot_via_http(bmp_data: Vec<u8>, _width: u32, _height: u32) -> Result<String, String> {
+        let port = Self::ensure_image_server()?;
+
+        let id = NEXT_IMAGE_ID.fetch_add(1, Ordering::Relaxed);
+        *LATEST_IMAGE.lock().unwrap() = Some((id, Arc::new(bmp_data)));
+
+        Ok(format!("http://127.0.0.1:{}/preview?id={}", port, id))
+    }
+
+    // 启动（或复用）常驻图片服务，避免每次截屏都新开端口和线程
+    fn ensure_image_server() -> Result<u16, String> {
+        IMAGE_SERVER_PORT
+            .get_or_try_init(|| {
+                let listener = TcpListener::bind("127.0.0.1:0")
+                    .map_err(|e| format!("绑定端口失败: {}", e))?;
+                let port = listener
+                    .local_addr()
+                    .map_err(|e| format!("获取端口失败: {}", e))?
+                    .port();
+
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        Self::handle_http_request(stream);
+                    }
+                });
+
+                Ok(port)
+            })
+            .copied()
     }
-    
-    fn handle_http_request(mut stream: TcpStream, image_data: &[u8]) {
+
+    // 解析请求行中的图片id，如 "GET /preview?id=3 HTTP/1.1"
+    fn parse_requested_image_id(request: &str) -> Option<u64> {
+        let path = request.lines().next()?.split_whitespace().nth(1)?;
+        let query = path.strip_prefix("/preview?")?;
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("id="))
+            .and_then(|id| id.parse().ok())
+    }
+
+    fn handle_http_request(mut stream: TcpStream) {
         use std::io::Read;
-        
+
+        let _ = stream.set_read_timeout(Some(std::time::Duration::from_secs(2)));
         let mut buffer = [0; 1024];
-        let _ = stream.read(&mut buffer);
-        
+        let read = stream.read(&mut buffer).unwrap_or(0);
+        let request = String::from_utf8_lossy(&buffer[..read]);
+
+        // 只提供最新一张截屏，过期的id直接返回404
+        let image_data = Self::parse_requested_image_id(&request).and_then(|id| {
+            LATEST_IMAGE
+                .lock()
+                .unwrap()
+                .as_ref()
+                .filter(|(latest_id, _)| *latest_id == id)
+                .map(|(_, data)| data.clone())
+        });
+
+        let Some(image_data) = image_data else {
+            let _ = stream.write_all(
+                b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nAccess-Control-Allow-Origin: *\r\nConnection: close\r\n\r\n",
+            );
+            return;
+        };
+
         let response = format!(
             "HTTP/1.1 200 OK\r\n\
             Content-Type: image/bmp\r\n\
@@ -367,8 +655,36 @@ impl ScreenshotWindowManager {
         );
         
         let _ = stream.write_all(response.as_bytes());
-        let _ = stream.write_all(image_data);
+        let _ = stream.write_all(&image_data);
         let _ = stream.flush();
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_requested_image_id() {
+        assert_eq!(
+            ScreenshotWindowManager::parse_requested_image_id("GET /preview?id=42 HTTP/1.1\r\nHost: x\r\n"),
+            Some(42)
+        );
+        assert_eq!(ScreenshotWindowManager::parse_requested_image_id("GET /preview?t=1&id=7 HTTP/1.1"), Some(7));
+        assert_eq!(ScreenshotWindowManager::parse_requested_image_id("GET /screenshot.bmp HTTP/1.1"), None);
+        assert_eq!(ScreenshotWindowManager::parse_requested_image_id(""), None);
+    }
+
+    #[test]
+    fn test_write_bmp_header() {
+        let mut header = [0u8; BMP_HEADER_SIZE];
+        ScreenshotWindowManager::write_bmp_header(&mut header, 3, 2);
+
+        assert_eq!(&header[0..2], b"BM");
+        assert_eq!(u32::from_le_bytes(header[2..6].try_into().unwrap()), 54 + 24);
+        assert_eq!(u32::from_le_bytes(header[10..14].try_into().unwrap()), 54);
+        assert_eq!(i32::from_le_bytes(header[22..26].try_into().unwrap()), -2);
+        assert_eq!(u32::from_le_bytes(header[34..38].try_into().unwrap()), 24);
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
@@ -14,6 +14,59 @@ use image::RgbaImage;
 use super::image_stitcher::{ImageStitcher, CapturedFrame};
 
 const VERTICAL_PADDING: u32 = 40;
+// 连续截取失败达到该次数时通知前端
+const CAPTURE_FAILURE_THRESHOLD: usize = 10;
+
+// 手动拼接操作（由截取线程处理）
+const FRAME_ACTION_NONE: u8 = 0;
+const FRAME_ACTION_RESCAN: u8 = 1;
+const FRAME_ACTION_MANUAL: u8 = 2;
+// 可连续回退的最大帧数（每帧需保留一张拼接前的参考帧）
+const MAX_RESCAN_DEPTH: usize = 20;
+
+// 已拼接的一帧及其拼接前的参考帧，用于回退
+struct StitchStep {
+    data_len: usize,
+    height: u32,
+    reference: RgbaImage,
+    reference_height: u32,
+}
+
+// 可回退的拼接记录（只保留最近 MAX_RESCAN_DEPTH 帧）
+#[derive(Default)]
+struct StitchHistory {
+    steps: Vec<StitchStep>,
+}
+
+impl StitchHistory {
+    fn push(&mut self, frame: &CapturedFrame, reference: RgbaImage, reference_height: u32) {
+        if self.steps.len() >= MAX_RESCAN_DEPTH {
+            self.steps.remove(0);
+        }
+        self.steps.push(StitchStep {
+            data_len: frame.data.len(),
+            height: frame.height,
+            reference,
+            reference_height,
+        });
+    }
+
+    fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    fn clear(&mut self) {
+        self.steps.clear();
+    }
+
+    // 从拼接图末尾移除最近一帧，返回该帧的记录
+    fn rescan(&mut self, stitched: &mut Vec<u8>, stitched_height: &mut u32) -> Option<StitchStep> {
+        let step = self.steps.pop()?;
+        stitched.truncate(stitched.len().saturating_sub(step.data_len));
+        *stitched_height = stitched_height.saturating_sub(step.height);
+        Some(step)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ScrollingState {
@@ -35,6 +88,19 @@ pub struct ScrollingScreenshotManager {
     stitched_height: Arc<Mutex<u32>>,
     temp_dir: Arc<Mutex<Option<std::path::PathBuf>>>,
     pending_frames: Arc<Mutex<Vec<CapturedFrame>>>,  // 累积帧批量发送
+    frame_count: Arc<AtomicUsize>,  // 已拼接的帧数
+    frame_action: Arc<AtomicU8>,  // 待处理的手动拼接操作
+    rescan_depth: Arc<AtomicUsize>,  // 当前可回退的帧数
+}
+
+// 长截屏状态（供前端重连后恢复）
+#[derive(Debug, Clone, Serialize)]
+pub struct ScrollingStatus {
+    pub state: String,
+    pub is_active: bool,
+    pub frame_count: usize,
+    pub width: u32,
+    pub total_height: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +133,9 @@ impl ScrollingScreenshotManager {
             stitched_height: Arc::new(Mutex::new(0)),
             temp_dir: Arc::new(Mutex::new(None)),
             pending_frames: Arc::new(Mutex::new(Vec::new())),
+            frame_count: Arc::new(AtomicUsize::new(0)),
+            frame_action: Arc::new(AtomicU8::new(FRAME_ACTION_NONE)),
+            rescan_depth: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -94,6 +163,9 @@ impl ScrollingScreenshotManager {
         *self.stitched_width.lock().unwrap() = 0;
         *self.stitched_height.lock().unwrap() = 0;
         self.pending_frames.lock().unwrap().clear();
+        self.frame_count.store(0, Ordering::Relaxed);
+        self.frame_action.store(FRAME_ACTION_NONE, Ordering::Relaxed);
+        self.rescan_depth.store(0, Ordering::Relaxed);
         
         let app_data_dir = crate::settings::get_data_directory()?;
         let temp_dir = app_data_dir.join("scrolling_temp");
@@ -176,6 +248,50 @@ impl ScrollingScreenshotManager {
         Ok(result)
     }
 
+    // 丢弃最近拼接的一帧，下一次截取重新匹配
+    pub fn rescan_last_frame(&self) -> Result<(), String> {
+        self.ensure_capturing()?;
+        if self.rescan_depth.load(Ordering::Relaxed) == 0 {
+            return Err("没有可回退的帧".to_string());
+        }
+        self.frame_action.store(FRAME_ACTION_RESCAN, Ordering::Relaxed);
+        Ok(())
+    }
+
+    // 忽略变化检测，强制追加当前区域内容
+    pub fn add_manual_frame(&self) -> Result<(), String> {
+        self.ensure_capturing()?;
+        self.frame_action.store(FRAME_ACTION_MANUAL, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn ensure_capturing(&self) -> Result<(), String> {
+        if !self.is_active.load(Ordering::Relaxed) {
+            return Err("未初始化长截屏".to_string());
+        }
+        match *self.state.lock().unwrap() {
+            ScrollingState::Running | ScrollingState::Paused => Ok(()),
+            _ => Err("长截屏未在运行中".to_string()),
+        }
+    }
+
+    pub fn get_status(&self) -> ScrollingStatus {
+        let state = match *self.state.lock().unwrap() {
+            ScrollingState::Idle => "idle",
+            ScrollingState::Running => "running",
+            ScrollingState::Paused => "paused",
+            ScrollingState::Stopped => "stopped",
+        };
+
+        ScrollingStatus {
+            state: state.to_string(),
+            is_active: self.is_active.load(Ordering::Relaxed),
+            frame_count: self.frame_count.load(Ordering::Relaxed),
+            width: *self.stitched_width.lock().unwrap(),
+            total_height: *self.stitched_height.lock().unwrap(),
+        }
+    }
+
     pub fn cancel(&self) -> Result<(), String> {
         *self.state.lock().unwrap() = ScrollingState::Stopped;
         self.is_active.store(false, Ordering::Relaxed);
@@ -184,21 +300,11 @@ impl ScrollingScreenshotManager {
     }
 
     fn save_to_clipboard_async(data: &[u8], width: u32, height: u32) -> Result<(), String> {
-        let png_bytes = ImageStitcher::bgra_to_png(data, width, height);
-        
-        let app_data_dir = crate::settings::get_data_directory()?;
-        let scrolling_dir = app_data_dir.join("clipboard_images/scrolling_screenshots");
-        std::fs::create_dir_all(&scrolling_dir)
-            .map_err(|e| format!("创建长截屏目录失败: {}", e))?;
-        
-        let now = chrono::Local::now();
-        let timestamp = now.format("%Y%m%d_%H%M%S").to_string();
-        let millis = now.timestamp_subsec_millis();
-        let filename = format!("QC长截屏_{}_{:03}.png", timestamp, millis);
-        let file_path = scrolling_dir.join(&filename);
-        
-        std::fs::write(&file_path, &png_bytes)
-            .map_err(|e| format!("保存图片文件失败: {}", e))?;
+        let rgba = ImageStitcher::bgra_to_rgba_image(data, width, height);
+        let file_path = super::screenshot_file::save_screenshot_image(
+            &image::DynamicImage::ImageRgba8(rgba),
+            super::screenshot_file::ScreenshotKind::Scrolling,
+        )?;
         
         let file_path_str = file_path.to_string_lossy().to_string();
         crate::file_handler::set_clipboard_files(&[file_path_str])?;
@@ -261,11 +367,23 @@ impl ScrollingScreenshotManager {
         let stitched_height = Arc::clone(&self.stitched_height);
         let temp_dir = Arc::clone(&self.temp_dir);
         let pending_frames = Arc::clone(&self.pending_frames);
+        let frame_count = Arc::clone(&self.frame_count);
+        let frame_action = Arc::clone(&self.frame_action);
+        let rescan_depth = Arc::clone(&self.rescan_depth);
+
+        // 采集间隔：画面变化时用最小间隔，无变化越久越接近最大间隔
+        let settings = crate::settings::get_global_settings();
+        let min_interval = settings.scrolling_min_interval_ms.clamp(10, 1000) as u64;
+        let max_interval = (settings.scrolling_max_interval_ms.clamp(10, 1000) as u64).max(min_interval);
+        let mid_interval = (min_interval + max_interval) / 2;
 
         thread::spawn(move || {
             let mut no_change_count = 0;
+            let mut capture_failure_count: usize = 0;
             let mut last_extended_rgba: Option<RgbaImage> = None;
             let mut last_content_height: u32 = 0;
+            // 已拼接帧的回退记录，回退后用参考帧重新匹配
+            let mut stitch_history = StitchHistory::default();
             let mut last_preview_time = std::time::Instant::now();
 
 
@@ -275,7 +393,10 @@ impl ScrollingScreenshotManager {
                     break;
                 }
 
-                if current_state == ScrollingState::Paused {
+                // 暂停时仍处理手动拼接操作
+                if current_state == ScrollingState::Paused
+                    && frame_action.load(Ordering::Relaxed) == FRAME_ACTION_NONE
+                {
                     thread::sleep(Duration::from_millis(100));
                     continue;
                 }
@@ -300,13 +421,15 @@ impl ScrollingScreenshotManager {
                     
                     match Self::capture_region(content_left, extended_top, content_width, extended_height) {
                         Ok(frame_data) => {
+                            capture_failure_count = 0;
                             let current_extended_rgba = ImageStitcher::bgra_to_rgba_image(&frame_data, content_width as u32, extended_height as u32);
                             let mut should_update_preview = false;
                             let mut is_first_frame = false;
                             
                             {
                                 let mut frames_lock = captured_frames.lock().unwrap();
-                                if frames_lock.is_empty() {
+                                let action = frame_action.swap(FRAME_ACTION_NONE, Ordering::Relaxed);
+                                if last_extended_rgba.is_none() {
                                     let first_frame_data = ImageStitcher::extract_region(
                                         &frame_data,
                                         content_width as u32,
@@ -325,14 +448,74 @@ impl ScrollingScreenshotManager {
                                     };
                                     
                                     frames_lock.clear();
+                                    stitch_history.clear();
+                                    rescan_depth.store(0, Ordering::Relaxed);
                                     frames_lock.push(first_frame.clone());
                                     pending_frames.lock().unwrap().push(first_frame);
+                                    frame_count.store(1, Ordering::Relaxed);
                                     
                                     last_extended_rgba = Some(current_extended_rgba);
                                     last_content_height = content_height as u32;
                                     should_update_preview = true;
                                     is_first_frame = true;
 
+                                } else if action == FRAME_ACTION_RESCAN {
+                                    let removed = match stitched_image.lock().unwrap().as_mut() {
+                                        Some(img) => stitch_history.rescan(img, &mut stitched_height.lock().unwrap()),
+                                        None => None,
+                                    };
+                                    rescan_depth.store(stitch_history.len(), Ordering::Relaxed);
+
+                                    if let Some(step) = removed {
+                                        let new_height = *stitched_height.lock().unwrap();
+                                        frame_count.fetch_sub(1, Ordering::Relaxed);
+
+                                        // 尚未发送的帧直接丢弃，已发送的通知前端裁剪预览
+                                        let mut pending_lock = pending_frames.lock().unwrap();
+                                        if pending_lock.pop().is_none() {
+                                            if let Some(app) = app_handle.lock().unwrap().as_ref() {
+                                                let _ = app.emit("scrolling-screenshot-frame-removed", serde_json::json!({
+                                                    "removed_height": step.height,
+                                                    "total_height": new_height,
+                                                }));
+                                            }
+                                        }
+                                        drop(pending_lock);
+
+                                        last_extended_rgba = Some(step.reference);
+                                        last_content_height = step.reference_height;
+                                        no_change_count = 0;
+                                    }
+                                } else if action == FRAME_ACTION_MANUAL {
+                                    let new_data = ImageStitcher::extract_region(
+                                        &frame_data,
+                                        content_width as u32,
+                                        VERTICAL_PADDING,
+                                        content_height as u32,
+                                    );
+
+                                    let frame = CapturedFrame {
+                                        data: new_data,
+                                        width: content_width as u32,
+                                        height: content_height as u32,
+                                    };
+                                    if let Some(rgba) = last_extended_rgba.take() {
+                                        stitch_history.push(&frame, rgba, last_content_height);
+                                        rescan_depth.store(stitch_history.len(), Ordering::Relaxed);
+                                    }
+
+                                    Self::append_stitched_frame(
+                                        &stitched_image,
+                                        &stitched_height,
+                                        &mut frames_lock,
+                                        &pending_frames,
+                                        frame,
+                                    );
+                                    frame_count.fetch_add(1, Ordering::Relaxed);
+                                    last_extended_rgba = Some(current_extended_rgba);
+                                    last_content_height = content_height as u32;
+                                    no_change_count = 0;
+                                    should_update_preview = true;
                                 } else if let Some(last_rgba) = &last_extended_rgba {
                                     if let Some(stitch_result) = ImageStitcher::should_stitch_frame_ex(
                                         &last_rgba, &current_extended_rgba,
@@ -348,24 +531,22 @@ impl ScrollingScreenshotManager {
                                             stitch_result.new_content_height
                                         );
                                         
-                                        let mut stitched = stitched_image.lock().unwrap();
-                                        if let Some(ref mut img) = *stitched {
-                                            img.extend_from_slice(&new_data);
-                                        }
-                                        drop(stitched);
-                                        
-                                        let new_height = *stitched_height.lock().unwrap() + stitch_result.new_content_height;
-                                        *stitched_height.lock().unwrap() = new_height;
-                                        
-                                        let new_frame = CapturedFrame {
+                                        let frame = CapturedFrame {
                                             data: new_data,
                                             width: content_width as u32,
                                             height: stitch_result.new_content_height,
                                         };
-                                        
-                                        frames_lock.clear();
-                                        frames_lock.push(new_frame.clone());
-                                        pending_frames.lock().unwrap().push(new_frame);
+                                        stitch_history.push(&frame, last_rgba.clone(), last_content_height);
+                                        rescan_depth.store(stitch_history.len(), Ordering::Relaxed);
+
+                                        Self::append_stitched_frame(
+                                            &stitched_image,
+                                            &stitched_height,
+                                            &mut frames_lock,
+                                            &pending_frames,
+                                            frame,
+                                        );
+                                        frame_count.fetch_add(1, Ordering::Relaxed);
                                         
                                         last_extended_rgba = Some(current_extended_rgba);
                                         last_content_height = content_height as u32;
@@ -417,14 +598,24 @@ impl ScrollingScreenshotManager {
                             }
 
                             if no_change_count > 20 {
-                                thread::sleep(Duration::from_millis(60));
+                                thread::sleep(Duration::from_millis(max_interval));
                             } else if no_change_count > 10 {
-                                thread::sleep(Duration::from_millis(35));
+                                thread::sleep(Duration::from_millis(mid_interval));
                             } else {
-                                thread::sleep(Duration::from_millis(25));
+                                thread::sleep(Duration::from_millis(min_interval));
                             }
                         }
-                        Err(_) => {
+                        Err(e) => {
+                            capture_failure_count += 1;
+                            // 连续失败时只通知一次，恢复成功后重新计数
+                            if capture_failure_count == CAPTURE_FAILURE_THRESHOLD {
+                                if let Some(app) = app_handle.lock().unwrap().as_ref() {
+                                    let _ = app.emit("scrolling-screenshot-error", serde_json::json!({
+                                        "message": e,
+                                        "failure_count": capture_failure_count,
+                                    }));
+                                }
+                            }
                             thread::sleep(Duration::from_millis(80));
                         }
                     }
@@ -619,6 +810,24 @@ impl ScrollingScreenshotManager {
         Ok(())
     }
 
+    // 追加新帧到拼接图
+    fn append_stitched_frame(
+        stitched_image: &Mutex<Option<Vec<u8>>>,
+        stitched_height: &Mutex<u32>,
+        frames: &mut Vec<CapturedFrame>,
+        pending_frames: &Mutex<Vec<CapturedFrame>>,
+        frame: CapturedFrame,
+    ) {
+        if let Some(ref mut img) = *stitched_image.lock().unwrap() {
+            img.extend_from_slice(&frame.data);
+        }
+        *stitched_height.lock().unwrap() += frame.height;
+
+        frames.clear();
+        frames.push(frame.clone());
+        pending_frames.lock().unwrap().push(frame);
+    }
+
     fn cleanup(&self) {
         self.captured_frames.lock().unwrap().clear();
         *self.selection.lock().unwrap() = None;
@@ -627,6 +836,9 @@ impl ScrollingScreenshotManager {
         *self.stitched_width.lock().unwrap() = 0;
         *self.stitched_height.lock().unwrap() = 0;
         self.pending_frames.lock().unwrap().clear();
+        self.frame_count.store(0, Ordering::Relaxed);
+        self.frame_action.store(FRAME_ACTION_NONE, Ordering::Relaxed);
+        self.rescan_depth.store(0, Ordering::Relaxed);
         
         if let Some(temp_dir) = self.temp_dir.lock().unwrap().as_ref() {
             if let Ok(entries) = std::fs::read_dir(temp_dir) {
@@ -679,7 +891,61 @@ pub fn cancel_scrolling_screenshot() -> Result<(), String> {
     SCROLLING_SCREENSHOT_MANAGER.cancel()
 }
 
+#[tauri::command]
+pub fn rescan_last_scroll_frame() -> Result<(), String> {
+    SCROLLING_SCREENSHOT_MANAGER.rescan_last_frame()
+}
+
+#[tauri::command]
+pub fn add_manual_scroll_frame() -> Result<(), String> {
+    SCROLLING_SCREENSHOT_MANAGER.add_manual_frame()
+}
+
+#[tauri::command]
+pub fn get_scrolling_screenshot_status() -> ScrollingStatus {
+    SCROLLING_SCREENSHOT_MANAGER.get_status()
+}
+
 #[tauri::command]
 pub fn update_scrolling_panel_rect(panel: PanelRect) -> Result<(), String> {
     SCROLLING_SCREENSHOT_MANAGER.update_panel_rect(panel)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(height: u32, value: u8) -> CapturedFrame {
+        CapturedFrame {
+            data: vec![value; (height * 4) as usize],
+            width: 1,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_consecutive_rescans_remove_frames_in_order() {
+        let mut history = StitchHistory::default();
+        let mut stitched = frame(10, 0).data;
+        let mut height = 10;
+
+        for (reference_height, next) in [(10, frame(5, 1)), (20, frame(3, 2))] {
+            history.push(&next, RgbaImage::new(1, 1), reference_height);
+            stitched.extend_from_slice(&next.data);
+            height += next.height;
+        }
+
+        let step = history.rescan(&mut stitched, &mut height).unwrap();
+        assert_eq!(step.reference_height, 20);
+        assert_eq!(height, 15);
+        assert_eq!(stitched.len(), 15 * 4);
+
+        let step = history.rescan(&mut stitched, &mut height).unwrap();
+        assert_eq!(step.reference_height, 10);
+        assert_eq!(height, 10);
+        assert!(stitched.iter().all(|&b| b == 0));
+
+        assert!(history.rescan(&mut stitched, &mut height).is_none());
+        assert_eq!(history.len(), 0);
+    }
+}
@@ -5,6 +5,7 @@ impl AIService {
     // 获取可用的AI模型列表
     pub async fn get_available_ai_models() -> Result<Vec<String>, String> {
         let settings = crate::settings::get_global_settings();
+        crate::ai_config::ensure_ai_enabled(&settings)?;
         let ai_config = crate::ai_config::create_ai_config_from_settings(&settings);
 
         if !ai_config.is_valid() {
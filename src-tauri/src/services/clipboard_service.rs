@@ -1,6 +1,25 @@
 use arboard::Clipboard;
 use crate::clipboard_content::{image_to_data_url, set_clipboard_content, set_clipboard_content_with_html};
 use crate::clipboard_history::{self, ClipboardItem};
+use crate::database::ContentType;
+use crate::utils::text_diff::{diff_lines, DiffChunk};
+
+// 拼图时图片之间的间距（像素）
+const CONTACT_SHEET_PADDING: u32 = 16;
+
+// 外部编辑器进程退出后，临时文件持续多久未修改才视为编辑结束
+// （部分编辑器的启动进程会立即退出，由已打开的实例继续编辑）
+const EXTERNAL_EDIT_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
+// 分页历史记录
+#[derive(serde::Serialize)]
+pub struct ClipboardHistoryPage {
+    pub items: Vec<ClipboardItem>,
+    pub total: usize,
+    pub has_more: bool,
+    // 为 true 时 items 按最旧优先排列，按索引操作时需换算为最新优先的索引
+    pub oldest_first: bool,
+}
 
 // 剪贴板服务 - 处理剪贴板相关的业务逻辑
 pub struct ClipboardService;
@@ -27,8 +46,217 @@ impl ClipboardService {
         set_clipboard_content(data_url)
     }
 
+    // 分页获取剪贴板历史记录
+    pub fn get_history_page(offset: usize, count: usize) -> Result<ClipboardHistoryPage, String> {
+        let oldest_first = Self::is_oldest_first();
+        let (items, total) = crate::database::get_clipboard_history_page(offset, count, oldest_first)?;
+        let has_more = offset + items.len() < total;
+        Ok(ClipboardHistoryPage { items, total, has_more, oldest_first })
+    }
+
+    // 比较两条文本历史记录的差异（按历史索引）
+    pub fn diff_items(a: usize, b: usize) -> Result<Vec<DiffChunk>, String> {
+        let history = Self::get_history();
+        let old_text = Self::get_full_text(&history, a)?;
+        let new_text = Self::get_full_text(&history, b)?;
+        Ok(diff_lines(&old_text, &new_text))
+    }
+
+    // 将多行文本项目按行拆分为多条新记录（跳过空行），返回新记录ID（按行顺序）
+    pub fn split_item_by_lines(index: usize, delete_original: bool) -> Result<Vec<i64>, String> {
+        let history = Self::get_history();
+        let text = Self::get_full_text(&history, index)?;
+        let lines: Vec<&str> = text
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+
+        if lines.len() < 2 {
+            return Err("该项目不包含多行内容，无需拆分".to_string());
+        }
+
+        // 倒序插入，使第一行显示在最上方
+        let mut ids = Vec::with_capacity(lines.len());
+        for line in lines.iter().rev() {
+            ids.push(crate::database::add_clipboard_item_smart(line.to_string(), None)?);
+        }
+        ids.reverse();
+
+        if delete_original {
+            crate::database::delete_clipboard_item(history[index].id)?;
+        }
+
+        if let Err(e) = clipboard_history::enforce_history_limits() {
+            println!("限制历史记录数量失败: {}", e);
+        }
+
+        Ok(ids)
+    }
+
+    // 将多条图片历史拼接为一张网格图并存为新记录，返回新记录ID
+    pub fn make_contact_sheet(indices: Vec<usize>, columns: u32) -> Result<i64, String> {
+        if indices.len() < 2 {
+            return Err("请至少选择两张图片".to_string());
+        }
+
+        let history = Self::get_history();
+        let image_manager = crate::image_manager::get_image_manager()?;
+
+        let mut images = Vec::with_capacity(indices.len());
+        for index in &indices {
+            let item = history
+                .get(*index)
+                .ok_or_else(|| format!("索引 {} 超出范围", index))?;
+            let image_id = match (&item.content_type, &item.image_id) {
+                (ContentType::Image, Some(image_id)) => image_id.clone(),
+                _ => return Err(format!("第 {} 项不是图片", index + 1)),
+            };
+
+            let file_path = image_manager.lock().unwrap().get_image_file_path(&image_id)?;
+            let img = image::open(&file_path)
+                .map_err(|e| format!("读取图片失败: {}", e))?
+                .to_rgba8();
+            images.push(img);
+        }
+
+        let sheet = crate::image_manager::compose_contact_sheet(&images, columns, CONTACT_SHEET_PADDING);
+        let (width, height) = sheet.dimensions();
+        let image_id = image_manager
+            .lock()
+            .unwrap()
+            .save_image_from_rgba_sync(width as usize, height as usize, sheet.as_raw())?;
+        let id = crate::database::add_clipboard_image(image_id)?;
+
+        if let Err(e) = clipboard_history::enforce_history_limits() {
+            println!("限制历史记录数量失败: {}", e);
+        }
+
+        Ok(id)
+    }
+
+    // 用外部编辑器打开文本历史项，编辑器保存时同步更新，编辑结束后清理临时文件
+    pub fn open_in_external_editor(app: tauri::AppHandle, index: usize) -> Result<(), String> {
+        let history = Self::get_history();
+        let content = Self::get_full_text(&history, index)?;
+        let id = history[index].id;
+
+        // 每次打开使用独立文件，避免同一项目多次打开时互相覆盖或提前删除
+        let temp_path = std::env::temp_dir().join(format!(
+            "quickclipboard_edit_{}_{}.txt",
+            id,
+            uuid::Uuid::new_v4().simple()
+        ));
+        std::fs::write(&temp_path, &content).map_err(|e| format!("写入临时文件失败: {}", e))?;
+
+        let settings = crate::settings::get_global_settings();
+        let editor = if settings.external_editor_path.trim().is_empty() {
+            "notepad.exe".to_string()
+        } else {
+            settings.external_editor_path.trim().to_string()
+        };
+
+        let mut child = match std::process::Command::new(&editor).arg(&temp_path).spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                let _ = std::fs::remove_file(&temp_path);
+                return Err(format!("启动外部编辑器失败: {}", e));
+            }
+        };
+
+        std::thread::spawn(move || {
+            let modified_time = |path: &std::path::Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            let mut last_modified = modified_time(&temp_path);
+            let mut last_activity = std::time::Instant::now();
+            let mut editor_running = true;
+            let mut last_content = content;
+
+            // 文件有变化时写回数据库
+            let sync_changes = |last_content: &mut String| {
+                let Ok(new_content) = std::fs::read_to_string(&temp_path) else {
+                    return;
+                };
+                if new_content == *last_content {
+                    return;
+                }
+                match crate::database::update_clipboard_item(id, new_content.clone()) {
+                    Ok(()) => {
+                        *last_content = new_content;
+                        let app = app.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let _ = crate::commands::emit_clipboard_updated(app).await;
+                        });
+                    }
+                    Err(e) => println!("更新剪贴板项目失败: {}", e),
+                }
+            };
+
+            loop {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+
+                if !temp_path.exists() {
+                    return;
+                }
+
+                let current_modified = modified_time(&temp_path);
+                if current_modified != last_modified {
+                    last_modified = current_modified;
+                    last_activity = std::time::Instant::now();
+                    sync_changes(&mut last_content);
+                }
+
+                if editor_running && !matches!(child.try_wait(), Ok(None)) {
+                    editor_running = false;
+                }
+
+                // 进程退出不代表编辑结束，文件空闲足够久后才清理
+                if !editor_running && last_activity.elapsed() >= EXTERNAL_EDIT_IDLE_TIMEOUT {
+                    break;
+                }
+            }
+
+            // 编辑结束后再同步一次并清理临时文件
+            sync_changes(&mut last_content);
+            let _ = std::fs::remove_file(&temp_path);
+        });
+
+        Ok(())
+    }
+
+    // 获取历史项目的完整文本（历史列表中的长文本已截断）
+    fn get_full_text(history: &[ClipboardItem], index: usize) -> Result<String, String> {
+        let item = history
+            .get(index)
+            .ok_or_else(|| format!("索引 {} 超出范围", index))?;
+
+        if !matches!(
+            item.content_type,
+            ContentType::Text | ContentType::RichText | ContentType::Link
+        ) {
+            return Err(format!("第 {} 项不是文本内容", index + 1));
+        }
+
+        crate::database::with_connection(|conn| {
+            conn.query_row(
+                "SELECT content FROM clipboard WHERE id = ?1",
+                [item.id],
+                |row| row.get::<_, String>(0),
+            )
+        })
+    }
+
     // 获取剪贴板历史记录
+    // 按 history_order 设置排列，与主窗口列表的索引一致
     pub fn get_history() -> Vec<ClipboardItem> {
+        let mut items = Self::get_history_newest_first();
+        if Self::is_oldest_first() {
+            items.reverse();
+        }
+        items
+    }
+
+    // 始终按最新优先排列（预览窗口和数字快捷键使用）
+    pub fn get_history_newest_first() -> Vec<ClipboardItem> {
         // 获取当前的历史记录数量限制
         let limit = clipboard_history::get_history_limit();
 
@@ -42,6 +270,10 @@ impl ClipboardService {
         }
     }
 
+    pub fn is_oldest_first() -> bool {
+        crate::settings::get_global_settings().history_order == "oldest_first"
+    }
+
     // 移动剪贴板项目到第一位
     pub fn move_to_front(text: String) -> Result<(), String> {
         clipboard_history::move_to_front_if_exists(text);
@@ -61,8 +293,7 @@ impl ClipboardService {
         match Clipboard::new() {
             Ok(mut clipboard) => {
                 if let Ok(text) = clipboard.get_text() {
-                    // 过滤空白内容：检查去除空白字符后是否为空
-                    if !text.is_empty() && !text.trim().is_empty() {
+                    if crate::clipboard_history::should_record(&text) {
                         crate::clipboard_history::add_to_history(text);
                         return Ok(());
                     }
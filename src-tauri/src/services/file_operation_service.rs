@@ -101,6 +101,22 @@ impl FileOperationService {
         crate::file_handler::get_file_info(&path)
     }
 
+    // 将拖入窗口的文件添加为一条文件类型的历史记录，返回实际添加的文件数量
+    pub fn add_dropped_files(files: Vec<String>) -> Result<usize, String> {
+        let (existing, missing): (Vec<String>, Vec<String>) = files
+            .into_iter()
+            .partition(|path| std::path::Path::new(path).exists());
+
+        if !missing.is_empty() {
+            println!("忽略不存在的拖入文件: {:?}", missing);
+        }
+        if existing.is_empty() {
+            return Err("拖入的文件不存在".to_string());
+        }
+
+        crate::clipboard_history::add_files_entry(&existing)
+    }
+
     // 获取剪贴板中的文件
     pub async fn get_clipboard_files() -> Result<Vec<String>, String> {
         crate::file_handler::get_clipboard_files()
@@ -111,6 +127,11 @@ impl FileOperationService {
         crate::file_handler::set_clipboard_files(&files)
     }
 
+    // 按指定格式设置剪贴板中的文件
+    pub async fn set_clipboard_files_as(files: Vec<String>, mode: String) -> Result<(), String> {
+        crate::file_handler::set_clipboard_files_as(&files, &mode)
+    }
+
     // 在文件管理器中打开文件位置
     pub async fn open_file_location(file_path: String) -> Result<(), String> {
         use std::process::Command;
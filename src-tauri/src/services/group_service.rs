@@ -10,6 +10,18 @@ impl GroupService {
         crate::database::get_all_groups().unwrap_or_default()
     }
 
+    // 获取最近使用的分组
+    pub fn get_recent_groups(limit: usize) -> Vec<GroupInfo> {
+        crate::database::get_recent_groups(limit).unwrap_or_default()
+    }
+
+    // 记录分组使用，失败不影响主操作
+    fn record_group_usage(group_name: &str) {
+        if let Err(e) = crate::database::touch_group_usage(group_name) {
+            eprintln!("记录分组使用时间失败: {}", e);
+        }
+    }
+
     // 添加分组
     pub fn add_group(name: String, icon: String) -> Result<GroupInfo, String> {
         crate::groups::add_group(name, icon)
@@ -32,21 +44,52 @@ impl GroupService {
 
     // 移动常用文本到分组
     pub fn move_quick_text_to_group(id: String, group_name: String) -> Result<(), String> {
-        crate::quick_texts::move_quick_text_to_group(id, group_name)
+        crate::quick_texts::move_quick_text_to_group(id, group_name.clone())?;
+        Self::record_group_usage(&group_name);
+        Ok(())
     }
 
-    // 从剪贴板历史添加到分组
-    pub fn add_clipboard_to_group(index: usize, group_name: String) -> Result<crate::database::FavoriteItem, String> {
-        // 从数据库获取剪贴板历史
-        let items = crate::database::get_clipboard_history(None)
+    // 从剪贴板历史添加到分组（按ID定位，与列表排列顺序无关）
+    pub fn add_clipboard_to_group(id: i64, group_name: String) -> Result<crate::database::FavoriteItem, String> {
+        let items = crate::database::get_clipboard_items_full(&[id])
             .map_err(|e| format!("获取剪贴板历史失败: {}", e))?;
+        let item = items.first().ok_or_else(|| format!("剪贴板项目 {} 不存在", id))?;
 
-        if index >= items.len() {
-            return Err(format!("索引 {} 超出历史范围", index));
-        }
+        let favorite_item = Self::favorite_from_clipboard_item(item, group_name.clone())?;
+        crate::database::add_favorite_item(&favorite_item)?;
+        Self::record_group_usage(&group_name);
+        Ok(favorite_item)
+    }
+
+    // 批量将剪贴板历史移动到分组（转为常用文本，单个事务）
+    pub fn move_clipboard_items_to_group(ids: &[i64], group_name: String) -> Result<Vec<crate::database::FavoriteItem>, String> {
+        // 原记录会被删除，必须使用未截断的完整内容
+        let items = crate::database::get_clipboard_items_full(ids)
+            .map_err(|e| format!("获取剪贴板历史失败: {}", e))?;
+
+        let favorite_items = ids
+            .iter()
+            .map(|id| {
+                let item = items
+                    .iter()
+                    .find(|item| item.id == *id)
+                    .ok_or_else(|| format!("剪贴板项目 {} 不存在", id))?;
+                Self::favorite_from_clipboard_item(item, group_name.clone())
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        crate::database::move_clipboard_items_to_favorites(ids, &favorite_items)?;
+        Self::record_group_usage(&group_name);
+        Ok(favorite_items)
+    }
 
-        let content = items[index].content.clone();
-        let html_content = items[index].html_content.clone();
+    // 根据剪贴板历史项构建常用文本
+    fn favorite_from_clipboard_item(
+        item: &crate::database::ClipboardItem,
+        group_name: String,
+    ) -> Result<crate::database::FavoriteItem, String> {
+        let content = item.content.clone();
+        let html_content = item.html_content.clone();
 
         // 处理内容，如果是图片则创建副本
         let final_content = if content.starts_with("image:") {
@@ -94,12 +137,12 @@ impl GroupService {
             html_content,
             group_name,
             image_id: None,
-            content_type: items[index].content_type.clone(),
+            content_type: item.content_type.clone(),
             created_at: chrono::Local::now().timestamp(),
             updated_at: chrono::Local::now().timestamp(),
             item_order: 0,
         };
 
-        crate::database::add_favorite_item(&favorite_item).map(|_| favorite_item)
+        Ok(favorite_item)
     }
 }
@@ -17,6 +17,29 @@ impl ImageService {
         }
     }
 
+    // 获取图片缩略图路径
+    pub fn get_image_thumbnail_path(content: String) -> Result<String, String> {
+        if content.starts_with("image:") {
+            let image_id = content.strip_prefix("image:").unwrap_or("");
+            let image_manager = crate::image_manager::get_image_manager()?;
+            let manager = image_manager
+                .lock()
+                .map_err(|e| format!("获取图片管理器锁失败: {}", e))?;
+            manager.get_thumbnail_path(image_id)
+        } else {
+            Err("不支持的图片格式".to_string())
+        }
+    }
+
+    // 清空缩略图缓存
+    pub fn clear_thumbnails() -> Result<(), String> {
+        let image_manager = crate::image_manager::get_image_manager()?;
+        let manager = image_manager
+            .lock()
+            .map_err(|e| format!("获取图片管理器锁失败: {}", e))?;
+        manager.clear_thumbnails()
+    }
+
     // 保存图片到文件（用于"另存为"功能）
     pub fn save_image_to_file(content: String, file_path: String) -> Result<(), String> {
         use std::fs;
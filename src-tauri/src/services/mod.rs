@@ -13,3 +13,4 @@ pub mod file_operation_service;
 pub mod image_service;
 pub mod ai_service;
 pub mod preview_service;
+pub mod transform_service;
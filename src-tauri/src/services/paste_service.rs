@@ -1,5 +1,10 @@
 use serde::Deserialize;
+use std::sync::Mutex;
 use tauri::WebviewWindow;
+
+// 当前剪贴板对应的历史项目ID（最近一次复制、粘贴或选中的条目），作为相对历史粘贴的基准
+static CURRENT_HISTORY_ITEM_ID: Mutex<Option<i64>> = Mutex::new(None);
+
 #[derive(Deserialize)]
 pub struct PasteContentParams {
     // 剪贴板历史项ID
@@ -17,7 +22,8 @@ pub async fn paste_content(
     let (content, html_content) = if let Some(id) = params.clipboard_id {
         get_clipboard_item_by_id(id)?
     } else if let Some(ref id) = params.quick_text_id {
-        get_quick_text_by_id(id)?
+        let (content, html_content) = get_quick_text_by_id(id)?;
+        expand_quick_text_placeholders(content, html_content)
     } else {
         return Err("必须提供 clipboard_id 或 quick_text_id".to_string());
     };
@@ -29,12 +35,137 @@ pub async fn paste_content(
         paste_image(content, &window).await
     } else {
         // 文本类型：判断是否需要翻译
-        paste_text_with_html(content, html_content, &window).await
+        paste_text_with_html(content.clone(), html_content, &window).await?;
+
+        // 标记为敏感的条目粘贴后定时清除剪贴板
+        if let Some(id) = params.clipboard_id {
+            if crate::database::is_clipboard_item_sensitive(id).unwrap_or(false) {
+                crate::clipboard_auto_clear::schedule_after_paste(&content);
+            }
+        }
+        Ok(())
     }?;
 
+    // 仅统计成功粘贴的历史项目
+    if let Some(id) = params.clipboard_id {
+        set_current_history_item_id(id);
+        if let Err(e) = crate::database::record_paste(id) {
+            log::warn!("记录粘贴次数失败: {}", e);
+        }
+    }
+
     Ok(())
 }
 
+// 展开常用文本中的占位符（含占位符时不再使用原HTML，避免内容不一致）
+fn expand_quick_text_placeholders(content: String, html_content: Option<String>) -> (String, Option<String>) {
+    use crate::utils::placeholders;
+
+    if content.starts_with("files:")
+        || content.starts_with("data:image/")
+        || content.starts_with("image:")
+        || !placeholders::has_placeholders(&content)
+    {
+        return (content, html_content);
+    }
+
+    let clipboard_text = crate::services::clipboard_service::ClipboardService::get_text().unwrap_or_default();
+    (placeholders::expand_placeholders(&content, &clipboard_text), None)
+}
+
+// 记录当前剪贴板对应的历史项目
+pub fn set_current_history_item_id(id: i64) {
+    *CURRENT_HISTORY_ITEM_ID.lock().unwrap() = Some(id);
+}
+
+// 粘贴当前剪贴板内容之前的第 offset 条历史记录（offset 为 0 即当前内容）
+pub async fn paste_relative_history(offset: usize, window: WebviewWindow) -> Result<(), String> {
+    let history = crate::database::get_clipboard_history(None)?;
+
+    // 以当前条目在历史中的位置为基准，尚未记录或已被删除时从最新一条开始
+    let current_id = *CURRENT_HISTORY_ITEM_ID.lock().unwrap();
+    let base = match current_id.and_then(|id| history.iter().position(|item| item.id == id)) {
+        Some(position) => position,
+        None => {
+            log::debug!("未找到当前历史项目 {:?}，从最新一条开始", current_id);
+            0
+        }
+    };
+
+    let item = history
+        .get(base + offset)
+        .ok_or_else(|| format!("没有之前第 {} 条记录", offset))?;
+    paste_history_item_by_id(item.id, window).await
+}
+
+// 按剪贴板历史ID粘贴
+pub async fn paste_history_item_by_id(id: i64, window: WebviewWindow) -> Result<(), String> {
+    let params = PasteContentParams {
+        clipboard_id: Some(id),
+        quick_text_id: None,
+    };
+    paste_content(params, window).await
+}
+
+// 只将历史项目写入系统剪贴板，不执行粘贴也不重新加入历史
+pub fn copy_history_item_by_id(id: i64) -> Result<(), String> {
+    let (content, html_content) = get_clipboard_item_by_id(id)?;
+
+    crate::clipboard_monitor::start_pasting_operation();
+    let result = if content.starts_with("files:") {
+        crate::file_handler::parse_files_entry_paths(&content)
+            .and_then(|paths| crate::file_handler::set_clipboard_files(&paths))
+    } else {
+        crate::clipboard_content::set_clipboard_content_no_history_with_html(content, html_content)
+    };
+    if result.is_ok() {
+        set_current_history_item_id(id);
+    }
+
+    // 延迟结束，确保剪贴板监听器能识别为程序自身的写入
+    std::thread::spawn(|| {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        crate::clipboard_monitor::end_pasting_operation();
+    });
+
+    result
+}
+
+// 获取当前前台应用匹配的粘贴规则
+// 未配置规则时兼容旧的 image_data_priority_apps 列表
+fn resolve_active_paste_rule(settings: &crate::settings::AppSettings) -> crate::settings::AppPasteRule {
+    let process_lower = match crate::utils::window_utils::get_active_window_process_name() {
+        Some(process) => process.to_lowercase(),
+        None => return crate::settings::AppPasteRule::default(),
+    };
+
+    if let Some(rule) = settings
+        .app_paste_rules
+        .iter()
+        .find(|rule| !rule.pattern.is_empty() && process_lower.contains(&rule.pattern.to_lowercase()))
+    {
+        return rule.clone();
+    }
+
+    let is_image_priority = settings
+        .image_data_priority_apps
+        .iter()
+        .any(|app| process_lower.contains(&app.to_lowercase()));
+
+    crate::settings::AppPasteRule {
+        prefer_image: is_image_priority,
+        ..Default::default()
+    }
+}
+
+// 获取规则对应的文件粘贴格式，未设置时兼容 prefer_files
+fn resolve_files_mode(rule: &crate::settings::AppPasteRule) -> String {
+    if matches!(rule.files_mode.as_str(), "hdrop" | "text_paths" | "both") {
+        return rule.files_mode.clone();
+    }
+    if rule.prefer_files { "hdrop" } else { "text_paths" }.to_string()
+}
+
 // 根据ID从数据库获取剪贴板项目
 fn get_clipboard_item_by_id(id: i64) -> Result<(String, Option<String>), String> {
     let result = crate::database::with_connection(|conn| {
@@ -90,8 +221,19 @@ pub async fn paste_text_with_html(
     html_content: Option<String>,
     window: &WebviewWindow,
 ) -> Result<(), String> {
-    // 检查是否需要翻译
     let settings = crate::settings::get_global_settings();
+
+    // 智能粘贴：去除多行文本的公共缩进（HTML 与去缩进后的文本不一致，改用纯文本）
+    let (text_content, html_content) = if settings.smart_paste_strip_common_indent {
+        match strip_common_indent(&text_content) {
+            Some(dedented) => (dedented, None),
+            None => (text_content, html_content),
+        }
+    } else {
+        (text_content, html_content)
+    };
+
+    // 检查是否需要翻译
     let should_translate = crate::ai_translator::is_translation_config_valid(&settings)
         && settings.ai_translate_on_paste;
 
@@ -99,8 +241,8 @@ pub async fn paste_text_with_html(
         // 发送翻译相关事件
         send_translation_events(window, &text_content, "文本粘贴").await;
 
-        // 使用智能翻译命令，根据设置自动选择输出方式
-        match crate::services::translation_service::translate_text_smart(text_content.clone()).await
+        // 根据设置自动选择输出方式（结果窗口模式回退为直接粘贴）
+        match crate::services::translation_service::translate_text_for_paste(text_content.clone()).await
         {
             Ok(_) => {
                 send_translation_success_events(window, &text_content, "文本粘贴").await;
@@ -118,6 +260,52 @@ pub async fn paste_text_with_html(
     paste_text_without_translation_internal_with_html(text_content, html_content, window).await
 }
 
+// 去除多行文本各行的公共前导空白，空白行不参与计算；无需处理时返回 None
+fn strip_common_indent(text: &str) -> Option<String> {
+    if !text.contains('\n') {
+        return None;
+    }
+
+    let mut common: Option<&str> = None;
+    for line in text.split('\n') {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = &line[..line.len() - line.trim_start().len()];
+        common = Some(match common {
+            None => indent,
+            Some(prev) => {
+                let shared = prev
+                    .char_indices()
+                    .zip(indent.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map(|((i, _), _)| i)
+                    .unwrap_or_else(|| prev.len().min(indent.len()));
+                &prev[..shared]
+            }
+        });
+    }
+
+    let prefix_len = common.map(str::len).unwrap_or(0);
+    if prefix_len == 0 {
+        return None;
+    }
+
+    let dedented: Vec<&str> = text
+        .split('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                // 仅保留行尾的 \r
+                &line[line.trim_end_matches('\r').len()..]
+            } else {
+                &line[prefix_len..]
+            }
+        })
+        .collect();
+    Some(dedented.join("\n"))
+}
+
 // 粘贴文本内容
 async fn paste_text_without_translation_internal_with_html(
     text_content: String,
@@ -127,18 +315,25 @@ async fn paste_text_without_translation_internal_with_html(
     // 开始粘贴操作，增加粘贴计数器
     crate::clipboard_monitor::start_pasting_operation();
 
-    // 获取格式设置
+    // 获取格式设置（前台应用规则优先）
     let settings = crate::settings::get_global_settings();
-    let use_html = html_content.is_some() && settings.paste_with_format;
+    let rule = resolve_active_paste_rule(&settings);
+    let use_html = html_content.is_some()
+        && match rule.format.as_str() {
+            "plain" => false,
+            "formatted" => true,
+            _ => settings.paste_with_format,
+        };
 
     // 将文本设置到剪贴板（不添加到历史记录，避免重复）
     let result = if use_html {
-        crate::clipboard_content::set_clipboard_content_no_history_with_html(
+        crate::clipboard_content::set_clipboard_content_no_history_with_html_async(
             text_content,
             html_content,
         )
+        .await
     } else {
-        crate::clipboard_content::set_clipboard_content_no_history(text_content)
+        crate::clipboard_content::set_clipboard_content_no_history_async(text_content).await
     };
 
     if let Err(e) = result {
@@ -185,6 +380,25 @@ pub async fn paste_image(image_content: String, window: &WebviewWindow) -> Resul
             format!("获取图片管理器失败: {}", e)
         })?;
 
+        // 目标应用只接受文件时，将图片导出为临时文件并按文件列表粘贴
+        let settings = crate::settings::get_global_settings();
+        let paste_rule = resolve_active_paste_rule(&settings);
+        if paste_rule.image_as_file {
+            let temp_path = image_manager
+                .lock()
+                .unwrap()
+                .export_temp_file(image_id)
+                .map_err(|e| {
+                    crate::clipboard_monitor::end_pasting_operation();
+                    e
+                })?;
+            if let Err(e) = crate::file_handler::set_clipboard_files(&[temp_path]) {
+                crate::clipboard_monitor::end_pasting_operation();
+                return Err(e);
+            }
+            return finish_image_paste(window);
+        }
+
         let (bgra, png_bytes, width, height, file_path) = {
             let manager = image_manager.lock().unwrap();
             
@@ -205,19 +419,7 @@ pub async fn paste_image(image_content: String, window: &WebviewWindow) -> Resul
         #[cfg(windows)]
         {
             use crate::clipboard_content::set_windows_clipboard_image_with_file;
-            use crate::utils::window_utils::get_active_window_process_name;
-            let settings = crate::settings::get_global_settings();
-            let prefers_image_data = get_active_window_process_name()
-                .map(|process| {
-                    let process_lower = process.to_lowercase();
-                    let is_priority = settings
-                        .image_data_priority_apps
-                        .iter()
-                        .any(|app| process_lower.contains(&app.to_lowercase()));
-                    
-                    is_priority
-                })
-                .unwrap_or(false);
+            let prefers_image_data = paste_rule.prefer_image;
             let file_path_opt = if prefers_image_data {
                 None
             } else {
@@ -239,14 +441,14 @@ pub async fn paste_image(image_content: String, window: &WebviewWindow) -> Resul
         #[cfg(not(windows))]
         {
             // 非Windows系统，使用原有逻辑
-            if let Err(e) = crate::clipboard_content::set_clipboard_content_no_history(image_data) {
+            if let Err(e) = crate::clipboard_content::set_clipboard_content_no_history_async(image_data).await {
                 crate::clipboard_monitor::end_pasting_operation();
                 return Err(e);
             }
         }
     } else if image_content.starts_with("data:image/") {
         // 旧格式：完整的data URL
-        if let Err(e) = crate::clipboard_content::set_clipboard_content_no_history(image_content) {
+        if let Err(e) = crate::clipboard_content::set_clipboard_content_no_history_async(image_content).await {
             crate::clipboard_monitor::end_pasting_operation();
             return Err(e);
         }
@@ -255,6 +457,11 @@ pub async fn paste_image(image_content: String, window: &WebviewWindow) -> Resul
         return Err("不支持的图片格式".to_string());
     }
 
+    finish_image_paste(window)
+}
+
+// 图片写入剪贴板后执行粘贴并收尾
+fn finish_image_paste(window: &WebviewWindow) -> Result<(), String> {
     // 执行粘贴操作
     if !crate::paste_utils::windows_paste() {
         crate::clipboard_monitor::end_pasting_operation();
@@ -278,37 +485,66 @@ pub async fn paste_image(image_content: String, window: &WebviewWindow) -> Resul
 
 // 粘贴文件内容
 pub async fn paste_files(files_data: String, window: &WebviewWindow) -> Result<(), String> {
-    // 解析文件数据
-    if !files_data.starts_with("files:") {
-        return Err("无效的文件数据格式".to_string());
-    }
+    // 解析文件数据并校验路径是否仍然存在
+    let file_paths = crate::file_handler::parse_files_entry_paths(&files_data)?;
+    let summary = crate::file_handler::summarize_files_entry(&files_data).ok();
+    let validity = crate::file_handler::validate_file_paths(&file_paths);
 
-    let files_json = &files_data[6..]; // 去掉 "files:" 前缀
-    let files_data: serde_json::Value =
-        serde_json::from_str(files_json).map_err(|e| format!("解析文件数据失败: {}", e))?;
+    let (valid_file_paths, missing_file_paths): (Vec<String>, Vec<String>) = file_paths
+        .into_iter()
+        .zip(validity)
+        .fold((Vec::new(), Vec::new()), |(mut valid, mut missing), (path, exists)| {
+            if exists {
+                valid.push(path);
+            } else {
+                missing.push(path);
+            }
+            (valid, missing)
+        });
 
-    let files = files_data["files"].as_array().ok_or("文件数据格式错误")?;
+    if valid_file_paths.is_empty() {
+        return Err("所有文件均已不存在或被移动".to_string());
+    }
 
-    let file_paths: Vec<String> = files
-        .iter()
-        .filter_map(|file| file["path"].as_str())
-        .map(|path| path.to_string())
-        .collect();
+    // 记录时超过数量上限被截断的条目只能粘贴已保存的部分
+    let total_count = summary
+        .as_ref()
+        .map(|s| s.total_count)
+        .unwrap_or(valid_file_paths.len());
+    let truncated = summary.as_ref().map_or(false, |s| s.truncated);
+    if truncated {
+        log::info!("文件条目已被截断，只粘贴 {} / {} 个文件", valid_file_paths.len(), total_count);
+    }
 
-    let valid_file_paths: Vec<String> = file_paths
-        .into_iter()
-        .filter(|path| std::path::Path::new(path).exists())
-        .collect();
+    // 部分文件缺失时只粘贴存在的文件，并提示前端
+    if !missing_file_paths.is_empty() || truncated {
+        use tauri::Emitter;
+        if !missing_file_paths.is_empty() {
+            log::warn!("以下文件已不存在，粘贴时跳过: {:?}", missing_file_paths);
+        }
+        let _ = window.emit(
+            "paste-files-warning",
+            serde_json::json!({
+                "missing": missing_file_paths,
+                "pastedCount": valid_file_paths.len(),
+                "totalCount": total_count,
+                "truncated": truncated
+            }),
+        );
+    }
 
-    if valid_file_paths.is_empty() {
-        return Err("没有找到有效的文件路径".to_string());
+    // 前台应用规则要求粘贴路径文本时，按纯文本粘贴
+    let settings = crate::settings::get_global_settings();
+    let files_mode = resolve_files_mode(&resolve_active_paste_rule(&settings));
+    if files_mode == "text_paths" {
+        return paste_text_without_translation_internal_with_html(valid_file_paths.join("\n"), None, window).await;
     }
 
     // 开始粘贴操作，增加粘贴计数器
     crate::clipboard_monitor::start_pasting_operation();
 
     // 设置剪贴板文件
-    if let Err(e) = crate::file_handler::set_clipboard_files(&valid_file_paths) {
+    if let Err(e) = crate::file_handler::set_clipboard_files_as(&valid_file_paths, &files_mode) {
         crate::clipboard_monitor::end_pasting_operation();
         return Err(e);
     }
@@ -356,7 +592,7 @@ async fn send_translation_events(window: &WebviewWindow, text: &str, source: &st
             "source": source
         }),
     ) {
-        eprintln!("发送显示翻译指示器事件失败: {}", e);
+        log::warn!("发送显示翻译指示器事件失败: {}", e);
     }
 
     // 发送翻译开始通知
@@ -369,7 +605,7 @@ async fn send_translation_events(window: &WebviewWindow, text: &str, source: &st
             "textLength": text.len()
         }),
     ) {
-        eprintln!("发送翻译开始通知失败: {}", e);
+        log::warn!("发送翻译开始通知失败: {}", e);
     }
 
     // 发送翻译状态通知
@@ -381,7 +617,7 @@ async fn send_translation_events(window: &WebviewWindow, text: &str, source: &st
             "source": source
         }),
     ) {
-        eprintln!("发送翻译状态通知失败: {}", e);
+        log::warn!("发送翻译状态通知失败: {}", e);
     }
 }
 
@@ -399,7 +635,7 @@ async fn send_translation_success_events(window: &WebviewWindow, text: &str, sou
             "originalLength": text.len()
         }),
     ) {
-        eprintln!("发送翻译成功通知失败: {}", e);
+        log::warn!("发送翻译成功通知失败: {}", e);
     }
 
     // 发送隐藏翻译指示器事件
@@ -409,7 +645,7 @@ async fn send_translation_success_events(window: &WebviewWindow, text: &str, sou
             "source": source
         }),
     ) {
-        eprintln!("发送隐藏翻译指示器事件失败: {}", e);
+        log::warn!("发送隐藏翻译指示器事件失败: {}", e);
     }
 }
 
@@ -426,7 +662,7 @@ async fn send_translation_error_events(window: &WebviewWindow, error: &str, sour
             "error": error
         }),
     ) {
-        eprintln!("发送翻译失败通知失败: {}", e);
+        log::warn!("发送翻译失败通知失败: {}", e);
     }
 
     // 发送隐藏翻译指示器事件
@@ -436,6 +672,30 @@ async fn send_translation_error_events(window: &WebviewWindow, error: &str, sour
             "source": source
         }),
     ) {
-        eprintln!("发送隐藏翻译指示器事件失败: {}", e);
+        log::warn!("发送隐藏翻译指示器事件失败: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_common_indent;
+
+    #[test]
+    fn strips_shared_leading_whitespace() {
+        let text = "    fn main() {\n        run();\n    }";
+        assert_eq!(strip_common_indent(text).unwrap(), "fn main() {\n    run();\n}");
+    }
+
+    #[test]
+    fn ignores_blank_lines_and_keeps_crlf() {
+        let text = "\tif x {\r\n\r\n\t\ty();\r\n\t}";
+        assert_eq!(strip_common_indent(text).unwrap(), "if x {\r\n\r\n\ty();\r\n}");
+    }
+
+    #[test]
+    fn leaves_unindented_or_single_line_text() {
+        assert_eq!(strip_common_indent("    single line"), None);
+        assert_eq!(strip_common_indent("a\n    b"), None);
+        assert_eq!(strip_common_indent("\tmixed\n    spaces"), None);
     }
 }
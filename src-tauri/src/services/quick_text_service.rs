@@ -11,8 +11,13 @@ impl QuickTextService {
         quick_texts::get_all_quick_texts()
     }
 
-    // 添加常用文本
+    // 添加常用文本（未指定分组时使用默认分组）
     pub fn add(title: String, content: String, group_name: String) -> Result<FavoriteItem, String> {
+        let group_name = if group_name.trim().is_empty() {
+            quick_texts::resolve_default_group()
+        } else {
+            group_name
+        };
         quick_texts::add_quick_text(title, content, group_name)
     }
 
@@ -31,6 +36,21 @@ impl QuickTextService {
         quick_texts::delete_quick_text(&id)
     }
 
+    // 复制当前选中的文本并添加到常用文本
+    pub fn add_from_selection(group_name: String) -> Result<FavoriteItem, String> {
+        if !crate::paste_utils::copy_selection_and_wait(1000) {
+            return Err("未检测到选中的文本".to_string());
+        }
+
+        let content = crate::services::clipboard_service::ClipboardService::get_text()?;
+        if content.trim().is_empty() {
+            return Err("选中的内容为空".to_string());
+        }
+
+        let title = crate::utils::content_utils::generate_content_summary(&content, 30);
+        quick_texts::add_quick_text(title, content, group_name)
+    }
+
     // 将剪贴板历史项添加到常用文本
     pub fn add_from_clipboard(id: i64) -> Result<FavoriteItem, String> {
         // 从数据库查询指定ID的剪贴板项
@@ -54,7 +74,7 @@ impl QuickTextService {
         let title = Self::generate_title(&final_content);
 
         // 添加到常用文本
-        quick_texts::add_quick_text_with_group_and_html(title, final_content, html_content, "全部".to_string())
+        quick_texts::add_quick_text_with_group_and_html(title, final_content, html_content, quick_texts::resolve_default_group())
     }
 
     // 处理图片内容，使用图片ID
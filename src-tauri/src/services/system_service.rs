@@ -6,7 +6,7 @@ pub struct SystemService;
 impl SystemService {
     // 调试日志
     pub fn log_debug(message: String) {
-        println!("前端调试: {}", message);
+        log::debug!("前端调试: {}", message);
     }
 
     // 获取应用数据目录
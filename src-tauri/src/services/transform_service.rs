@@ -0,0 +1,36 @@
+use crate::services::clipboard_service::ClipboardService;
+use crate::utils::text_transform;
+
+// 转换流水线服务 - 对当前剪贴板内容执行预设的转换步骤
+pub struct TransformService;
+
+impl TransformService {
+    // 执行指定名称的流水线，结果写回剪贴板
+    pub fn run_pipeline(name: &str) -> Result<String, String> {
+        let settings = crate::settings::get_global_settings();
+        let pipeline = settings
+            .transform_pipelines
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| format!("未找到转换流水线: {}", name))?;
+
+        let input = ClipboardService::get_text()?;
+        let output = text_transform::apply_transforms(&pipeline.steps, &input)?;
+
+        // 不添加历史时，提前同步监听器的最后内容，使其忽略本次变化
+        if !pipeline.add_to_history {
+            crate::clipboard_monitor::initialize_last_content(output.clone());
+        }
+
+        ClipboardService::set_text(output.clone())?;
+        Ok(output)
+    }
+
+    // 获取支持的转换列表
+    pub fn get_available_transforms() -> Vec<String> {
+        text_transform::AVAILABLE_TRANSFORMS
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+}
@@ -9,6 +9,84 @@ use std::sync::atomic::{AtomicBool, Ordering};
 // 全局翻译状态管理
 static TRANSLATION_CANCELLED: AtomicBool = AtomicBool::new(false);
 
+// 获取翻译使用的设置（target_language 为本次翻译临时使用的目标语言，不写入设置）
+fn get_effective_settings(target_language: Option<&str>) -> settings::AppSettings {
+    let mut settings = settings::get_global_settings();
+    if let Some(language) = target_language {
+        settings.ai_target_language = language.to_string();
+    }
+    settings
+}
+
+// 翻译用量统计
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TranslationUsage {
+    pub today_chars: u64,
+    pub total_chars: u64,
+    pub estimated_cost: f64,
+}
+
+// 记录一次成功翻译的用量（原文与译文字符数之和，近似计费量）
+fn record_translation_usage(source: &str, translated: &str) {
+    let chars = (source.chars().count() + translated.chars().count()) as u64;
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    if let Err(e) = crate::database::add_translation_usage(&today, chars) {
+        println!("记录翻译用量失败: {}", e);
+    }
+}
+
+// 获取翻译用量（今日、累计及按累计字符估算的费用）
+pub fn get_translation_usage() -> Result<TranslationUsage, String> {
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let (today_chars, total_chars) = crate::database::get_translation_usage_totals(&today)?;
+    let cost_per_1k = settings::get_global_settings().ai_cost_per_1k_chars;
+
+    Ok(TranslationUsage {
+        today_chars,
+        total_chars,
+        estimated_cost: total_chars as f64 / 1000.0 * cost_per_1k,
+    })
+}
+
+// 重置翻译用量
+pub fn reset_translation_usage() -> Result<(), String> {
+    crate::database::clear_translation_usage()
+}
+
+// 流式片段换行符规范化：\r\n 可能被拆分到两个片段中，
+// 片段末尾的 \r 暂存到下一个片段再统一转换为 \n
+#[derive(Default)]
+struct ChunkNewlineNormalizer {
+    pending_cr: bool,
+}
+
+impl ChunkNewlineNormalizer {
+    // 处理一个片段，返回可以直接输入的文本
+    fn push(&mut self, chunk: &str) -> String {
+        let mut text = String::with_capacity(chunk.len() + 1);
+        if std::mem::take(&mut self.pending_cr) {
+            text.push('\r');
+        }
+        text.push_str(chunk);
+
+        if text.ends_with('\r') {
+            text.pop();
+            self.pending_cr = true;
+        }
+
+        text.replace("\r\n", "\n").replace('\r', "\n")
+    }
+
+    // 流结束时输出暂存的换行符
+    fn finish(&mut self) -> String {
+        if std::mem::take(&mut self.pending_cr) {
+            "\n".to_string()
+        } else {
+            String::new()
+        }
+    }
+}
+
 // 翻译守护结构，确保在函数结束时清理资源
 pub struct TranslationGuard;
 
@@ -23,25 +101,87 @@ impl Drop for TranslationGuard {
     }
 }
 
-// 智能翻译文本（根据设置选择流式输入或直接粘贴）
+// 智能翻译文本（根据设置选择流式输入、直接粘贴或结果窗口）
 pub async fn translate_text_smart(text: String) -> Result<(), String> {
-    let settings = settings::get_global_settings();
+    translate_text_smart_to(text, None).await
+}
+
+// 智能翻译文本，可指定本次使用的目标语言
+async fn translate_text_smart_to(text: String, target_language: Option<String>) -> Result<(), String> {
+    let settings = get_effective_settings(target_language.as_deref());
 
     // 根据输出模式设置选择翻译方式
     match settings.ai_output_mode.as_str() {
         "paste" => {
             println!("使用直接粘贴模式进行翻译");
-            translate_and_paste_text(text).await
+            translate_and_paste_text_to(text, target_language).await
+        }
+        "window" => {
+            println!("使用结果窗口模式进行翻译");
+            translate_to_window_to(text, target_language).await
         }
         "stream" | _ => {
             println!("使用流式输入模式进行翻译");
-            translate_and_input_text(text).await
+            translate_and_input_text_to(text, target_language).await
         }
     }
 }
 
+// 粘贴时翻译：结果必须写入目标位置，结果窗口模式下改用直接粘贴
+pub async fn translate_text_for_paste(text: String) -> Result<(), String> {
+    if get_effective_settings(None).ai_output_mode == "window" {
+        println!("结果窗口模式不适用于粘贴，改用直接粘贴模式进行翻译");
+        return translate_and_paste_text(text).await;
+    }
+    translate_text_smart(text).await
+}
+
 // 翻译文本并直接粘贴（非流式）
 pub async fn translate_and_paste_text(text: String) -> Result<(), String> {
+    translate_and_paste_text_to(text, None).await
+}
+
+// 翻译文本并直接粘贴，可指定本次使用的目标语言
+async fn translate_and_paste_text_to(text: String, target_language: Option<String>) -> Result<(), String> {
+    let result = translate_and_paste_text_inner(text, target_language).await;
+
+    // 用户主动取消时不提示
+    if !TRANSLATION_CANCELLED.load(Ordering::SeqCst) {
+        show_translation_complete_notification(&result);
+    }
+
+    result.map(|_| ())
+}
+
+// 翻译完成通知（开启 notify_on_translation_complete 时）
+fn show_translation_complete_notification(result: &Result<usize, String>) {
+    use tauri::Manager;
+    use tauri_plugin_notification::NotificationExt;
+
+    if !settings::get_global_settings().notify_on_translation_complete {
+        return;
+    }
+
+    let Some(window) = crate::input_monitor::MAIN_WINDOW_HANDLE.get() else {
+        return;
+    };
+
+    let (title, body) = match result {
+        Ok(char_count) => ("翻译完成".to_string(), format!("已粘贴 {} 个字符的译文", char_count)),
+        Err(e) => ("翻译失败".to_string(), e.clone()),
+    };
+
+    let _ = window
+        .app_handle()
+        .notification()
+        .builder()
+        .title(&title)
+        .body(&body)
+        .show();
+}
+
+// 翻译并粘贴，成功时返回译文字符数
+async fn translate_and_paste_text_inner(text: String, target_language: Option<String>) -> Result<usize, String> {
     // 重置取消状态
     TRANSLATION_CANCELLED.store(false, Ordering::SeqCst);
 
@@ -56,7 +196,9 @@ pub async fn translate_and_paste_text(text: String) -> Result<(), String> {
     // 确保在函数结束时禁用快捷键和恢复导航按键
     let _guard = TranslationGuard;
 
-    let settings = settings::get_global_settings();
+    let settings = get_effective_settings(target_language.as_deref());
+
+    crate::ai_config::ensure_ai_enabled(&settings)?;
 
     // 检查翻译是否启用
     if !settings.ai_translation_enabled {
@@ -88,12 +230,14 @@ pub async fn translate_and_paste_text(text: String) -> Result<(), String> {
             }
 
             println!("翻译完成，结果长度: {} 字符", translated_text.len());
+            let char_count = translated_text.chars().count();
+            record_translation_usage(&processed_text, &translated_text);
 
             // 设置剪贴板内容并粘贴
             crate::clipboard_monitor::start_pasting_operation();
 
             // 使用现有的剪贴板设置功能
-            crate::clipboard_content::set_clipboard_content_no_history(translated_text)?;
+            crate::clipboard_content::set_clipboard_content_no_history_async(translated_text).await?;
 
             // 执行粘贴操作
             std::thread::spawn(move || {
@@ -109,7 +253,7 @@ pub async fn translate_and_paste_text(text: String) -> Result<(), String> {
                 crate::clipboard_monitor::end_pasting_operation();
             });
 
-            Ok(())
+            Ok(char_count)
         }
         Err(e) => Err(format!("翻译失败: {}", e)),
     }
@@ -117,6 +261,11 @@ pub async fn translate_and_paste_text(text: String) -> Result<(), String> {
 
 // 翻译文本并流式输入
 pub async fn translate_and_input_text(text: String) -> Result<(), String> {
+    translate_and_input_text_to(text, None).await
+}
+
+// 翻译文本并流式输入，可指定本次使用的目标语言
+async fn translate_and_input_text_to(text: String, target_language: Option<String>) -> Result<(), String> {
     // 重置取消状态
     TRANSLATION_CANCELLED.store(false, Ordering::SeqCst);
 
@@ -131,7 +280,9 @@ pub async fn translate_and_input_text(text: String) -> Result<(), String> {
     // 确保在函数结束时禁用快捷键和恢复导航按键
     let _guard = TranslationGuard;
 
-    let settings = settings::get_global_settings();
+    let settings = get_effective_settings(target_language.as_deref());
+
+    crate::ai_config::ensure_ai_enabled(&settings)?;
 
     // 检查翻译是否启用
     if !settings.ai_translation_enabled {
@@ -162,6 +313,7 @@ pub async fn translate_and_input_text(text: String) -> Result<(), String> {
         Ok(mut receiver) => {
             let mut accumulated_text = String::new();
             let mut chunk_count = 0;
+            let mut normalizer = ChunkNewlineNormalizer::default();
 
             // 处理流式响应并实时输入
             while let Some(translation_result) = receiver.recv().await {
@@ -179,6 +331,13 @@ pub async fn translate_and_input_text(text: String) -> Result<(), String> {
                             return Err("翻译已被取消".to_string());
                         }
 
+                        // 首个片段输入前等待目标输入框就绪，避免开头字符丢失
+                        if chunk_count == 0 {
+                            crate::text_input_simulator::wait_for_input_target(settings.ai_output_initial_delay_ms).await;
+                        }
+
+                        let chunk = normalizer.push(&chunk);
+
                         // 累积文本用于错误恢复
                         accumulated_text.push_str(&chunk);
                         chunk_count += 1;
@@ -207,11 +366,17 @@ pub async fn translate_and_input_text(text: String) -> Result<(), String> {
                         }
                     }
                     TranslationResult::Complete => {
+                        let tail = normalizer.finish();
+                        if !tail.is_empty() {
+                            accumulated_text.push_str(&tail);
+                            let _ = crate::text_input_simulator::simulate_text_chunk_input_smart(&tail).await;
+                        }
                         println!(
                             "翻译完成，总共处理 {} 个片段，累积长度: {}",
                             chunk_count,
                             accumulated_text.len()
                         );
+                        record_translation_usage(&processed_text, &accumulated_text);
                         break;
                     }
                     TranslationResult::Error(e) => {
@@ -226,6 +391,120 @@ pub async fn translate_and_input_text(text: String) -> Result<(), String> {
     }
 }
 
+// 将选中文本（无选中时使用剪贴板文本）翻译为指定语言
+pub async fn translate_clipboard_to(language: String) -> Result<(), String> {
+    if language.trim().is_empty() {
+        return Err("目标语言不能为空".to_string());
+    }
+
+    let text = tokio::task::spawn_blocking(read_selection_preserving_clipboard)
+        .await
+        .map_err(|e| format!("读取选中文本失败: {}", e))??;
+    if text.trim().is_empty() {
+        return Err("没有可翻译的文本".to_string());
+    }
+
+    translate_text_smart_to(text, Some(language)).await
+}
+
+// 模拟复制读取选中文本（无选中时使用剪贴板文本）
+// 读取期间暂停记录历史，读取后恢复原剪贴板文本（原内容非文本时无法恢复）
+fn read_selection_preserving_clipboard() -> Result<String, String> {
+    use crate::services::clipboard_service::ClipboardService;
+
+    let previous_text = ClipboardService::get_text().ok();
+
+    crate::clipboard_monitor::start_suppressing_record();
+    let has_selection = crate::paste_utils::copy_selection_and_wait(500);
+    let text = ClipboardService::get_text();
+
+    if !has_selection {
+        println!("未检测到选中文本，使用当前剪贴板内容翻译");
+    } else if let Some(previous_text) = previous_text {
+        if let Err(e) = crate::clipboard_content::set_clipboard_content_no_history(previous_text) {
+            log::warn!("恢复剪贴板内容失败: {}", e);
+        }
+    }
+
+    // 等待监听器至少轮询一次，把恢复后的内容作为基准，避免恢复本身被记录
+    std::thread::sleep(std::time::Duration::from_millis(300));
+    crate::clipboard_monitor::end_suppressing_record();
+
+    text
+}
+
+// 翻译文本并在悬浮窗口中流式显示（不操作目标应用）
+pub async fn translate_to_window(text: String) -> Result<(), String> {
+    translate_to_window_to(text, None).await
+}
+
+// 翻译文本并在悬浮窗口中显示，可指定本次使用的目标语言
+async fn translate_to_window_to(text: String, target_language: Option<String>) -> Result<(), String> {
+    // 重置取消状态
+    TRANSLATION_CANCELLED.store(false, Ordering::SeqCst);
+
+    let settings = get_effective_settings(target_language.as_deref());
+
+    crate::ai_config::ensure_ai_enabled(&settings)?;
+
+    // 检查翻译是否启用
+    if !settings.ai_translation_enabled {
+        return Err("AI翻译功能未启用".to_string());
+    }
+
+    // 检查配置是否有效
+    if !crate::ai_translator::is_translation_config_valid(&settings) {
+        return Err("AI翻译配置不完整".to_string());
+    }
+
+    let processed_text = preprocess_translation_text(&text)?;
+    let translator = AITranslator::new(config_from_settings(&settings))
+        .map_err(|e| format!("创建翻译器失败: {}", e))?;
+
+    stream_translation_to_window(&translator, &processed_text).await
+}
+
+// 将流式翻译结果发送到翻译结果窗口
+async fn stream_translation_to_window(translator: &AITranslator, text: &str) -> Result<(), String> {
+    let app = crate::ai_translator::get_app_handle().ok_or("翻译事件未初始化")?;
+    let window = crate::translation_window::show_translation_window(app)?;
+    crate::translation_window::emit_start(&window, text);
+
+    let mut receiver = match translator.translate_stream(text).await {
+        Ok(receiver) => receiver,
+        Err(e) => {
+            let message = format!("启动翻译失败: {}", e);
+            crate::translation_window::emit_error(&window, &message);
+            return Err(message);
+        }
+    };
+
+    let mut accumulated_text = String::new();
+    loop {
+        if TRANSLATION_CANCELLED.load(Ordering::SeqCst) {
+            crate::translation_window::emit_error(&window, "翻译已被取消");
+            return Err("翻译已被取消".to_string());
+        }
+
+        match receiver.recv().await {
+            Some(TranslationResult::Chunk(chunk)) => {
+                accumulated_text.push_str(&chunk);
+                crate::translation_window::emit_chunk(&window, &chunk);
+            }
+            Some(TranslationResult::Complete) | None => {
+                record_translation_usage(text, &accumulated_text);
+                crate::translation_window::emit_complete(&window);
+                return Ok(());
+            }
+            Some(TranslationResult::Error(e)) => {
+                let message = format!("翻译失败: {}", e);
+                crate::translation_window::emit_error(&window, &message);
+                return Err(message);
+            }
+        }
+    }
+}
+
 // 取消正在进行的翻译
 pub fn cancel_translation() -> Result<(), String> {
     TRANSLATION_CANCELLED.store(true, Ordering::SeqCst);
@@ -249,7 +528,9 @@ pub async fn translate_and_input_on_copy(text: String) -> Result<(), String> {
     // 确保在函数结束时禁用快捷键和恢复导航按键
     let _guard = TranslationGuard;
 
-    let settings = settings::get_global_settings();
+    let settings = get_effective_settings(None);
+
+    crate::ai_config::ensure_ai_enabled(&settings)?;
 
     // 检查翻译是否启用
     if !settings.ai_translation_enabled {
@@ -291,12 +572,13 @@ pub async fn translate_and_input_on_copy(text: String) -> Result<(), String> {
                     }
 
                     println!("复制时翻译完成，结果长度: {} 字符", translated_text.len());
+                    record_translation_usage(&processed_text, &translated_text);
 
                     // 设置粘贴状态，防止触发新的复制检测
                     crate::clipboard_monitor::start_pasting_operation();
 
                     // 设置剪贴板内容并粘贴
-                    crate::clipboard_content::set_clipboard_content_no_history(translated_text)?;
+                    crate::clipboard_content::set_clipboard_content_no_history_async(translated_text).await?;
 
                     // 执行粘贴操作
                     std::thread::spawn(move || {
@@ -317,6 +599,10 @@ pub async fn translate_and_input_on_copy(text: String) -> Result<(), String> {
                 Err(e) => Err(format!("复制时翻译失败: {}", e)),
             }
         }
+        "window" => {
+            println!("复制时翻译使用结果窗口模式");
+            stream_translation_to_window(&translator, &processed_text).await
+        }
         "stream" | _ => {
             // 流式输入模式：翻译后直接输入到目标位置
             println!("复制时翻译使用流式输入模式");
@@ -324,6 +610,7 @@ pub async fn translate_and_input_on_copy(text: String) -> Result<(), String> {
                 Ok(mut receiver) => {
                     let mut accumulated_text = String::new();
                     let mut chunk_count = 0;
+                    let mut normalizer = ChunkNewlineNormalizer::default();
 
                     // 处理流式翻译结果
                     loop {
@@ -336,6 +623,12 @@ pub async fn translate_and_input_on_copy(text: String) -> Result<(), String> {
                         match receiver.recv().await {
                             Some(result) => match result {
                                 TranslationResult::Chunk(chunk) => {
+                                    if chunk_count == 0 {
+                                        crate::text_input_simulator::wait_for_input_target(settings.ai_output_initial_delay_ms).await;
+                                    }
+
+                                    let chunk = normalizer.push(&chunk);
+
                                     // 累积文本用于错误恢复
                                     accumulated_text.push_str(&chunk);
                                     chunk_count += 1;
@@ -362,11 +655,17 @@ pub async fn translate_and_input_on_copy(text: String) -> Result<(), String> {
                                     }
                                 }
                                 TranslationResult::Complete => {
+                                    let tail = normalizer.finish();
+                                    if !tail.is_empty() {
+                                        accumulated_text.push_str(&tail);
+                                        let _ = crate::text_input_simulator::simulate_text_chunk_input_smart(&tail).await;
+                                    }
                                     println!(
                                         "复制时翻译完成，总共处理 {} 个片段，累积长度: {}",
                                         chunk_count,
                                         accumulated_text.len()
                                     );
+                                    record_translation_usage(&processed_text, &accumulated_text);
                                     break;
                                 }
                                 TranslationResult::Error(e) => {
@@ -424,6 +723,8 @@ pub async fn test_ai_translation() -> Result<String, String> {
 
     // 创建翻译配置
     let config = crate::ai_translator::config_from_settings(&settings);
+    let temperature = config.ai_config.temperature;
+    let max_tokens = config.ai_config.max_tokens;
 
     // 创建翻译器
     let translator = match crate::ai_translator::AITranslator::new(config) {
@@ -456,7 +757,10 @@ pub async fn test_ai_translation() -> Result<String, String> {
             if result.is_empty() {
                 Err("翻译结果为空".to_string())
             } else {
-                Ok(format!("测试成功！翻译结果：{}", result))
+                Ok(format!(
+                    "测试成功！翻译结果：{}\n（temperature: {}, max_tokens: {}）",
+                    result, temperature, max_tokens
+                ))
             }
         }
         Err(e) => Err(format!("启动翻译失败: {}", e)),
@@ -482,3 +786,27 @@ pub fn check_ai_translation_config() -> Result<bool, String> {
     let settings = crate::settings::get_global_settings();
     Ok(crate::ai_translator::is_translation_config_valid(&settings))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizer_joins_split_crlf() {
+        let mut normalizer = ChunkNewlineNormalizer::default();
+        let mut output = String::new();
+        for chunk in ["line1\r", "\nline2\r\n", "line3\r"] {
+            output.push_str(&normalizer.push(chunk));
+        }
+        output.push_str(&normalizer.finish());
+        assert_eq!(output, "line1\nline2\nline3\n");
+    }
+
+    #[test]
+    fn test_normalizer_holds_only_trailing_cr() {
+        let mut normalizer = ChunkNewlineNormalizer::default();
+        assert_eq!(normalizer.push("a\r"), "a");
+        assert_eq!(normalizer.push("b"), "\nb");
+        assert_eq!(normalizer.finish(), "");
+    }
+}
@@ -1,6 +1,10 @@
-use tauri::{AppHandle, Manager, WebviewWindow};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager, WebviewWindow};
 use crate::state_manager;
 
+// 文本编辑窗口置顶状态（仅在本次运行期间保持）
+static EDITOR_TOPMOST: AtomicBool = AtomicBool::new(false);
+
 // 窗口服务 - 处理窗口相关的业务逻辑
 pub struct WindowService;
 
@@ -16,6 +20,17 @@ impl WindowService {
         state_manager::is_window_pinned()
     }
 
+    // 切换窗口固定状态并通知主窗口同步界面，返回新状态
+    pub fn toggle_pinned(app: &AppHandle) -> bool {
+        let pinned = !Self::is_pinned();
+        state_manager::set_window_pinned(pinned);
+
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.emit("window-pin-changed", serde_json::json!({ "pinned": pinned }));
+        }
+        pinned
+    }
+
     // 切换窗口可见性
     pub fn toggle_visibility(app: &AppHandle) -> Result<(), String> {
         if let Some(window) = app.get_webview_window("main") {
@@ -75,6 +90,7 @@ impl WindowService {
             .resizable(true)
             .maximizable(true)
             .decorations(false) // 去除标题栏
+            .always_on_top(Self::is_editor_topmost())
             .build()
             .map_err(|e| format!("创建文本编辑窗口失败: {}", e))?;
 
@@ -99,4 +115,20 @@ impl WindowService {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    // 设置文本编辑窗口置顶
+    pub fn set_editor_topmost(app: &AppHandle, topmost: bool) -> Result<(), String> {
+        EDITOR_TOPMOST.store(topmost, Ordering::Relaxed);
+        if let Some(editor_window) = app.get_webview_window("text-editor") {
+            editor_window
+                .set_always_on_top(topmost)
+                .map_err(|e| format!("设置文本编辑窗口置顶失败: {}", e))?;
+        }
+        Ok(())
+    }
+
+    // 获取文本编辑窗口置顶状态
+    pub fn is_editor_topmost() -> bool {
+        EDITOR_TOPMOST.load(Ordering::Relaxed)
+    }
+}
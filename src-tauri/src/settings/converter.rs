@@ -8,40 +8,93 @@ impl SettingsConverter {
     // 将设置转换为JSON（供前端使用）
     pub fn to_json(settings: &AppSettings) -> Value {
         serde_json::json!({
+            "onboardingCompleted": settings.onboarding_completed,
             "autoStart": settings.auto_start,
             "startHidden": settings.start_hidden,
+            "startupDelayMs": settings.startup_delay_ms,
             "runAsAdmin": settings.run_as_admin,
             "showStartupNotification": settings.show_startup_notification,
+            "logLevel": settings.log_level,
+            "logToFile": settings.log_to_file,
             "historyLimit": settings.history_limit,
+            "imageHistoryLimit": settings.image_history_limit,
             "theme": settings.theme,
+            "windowBackdrop": settings.window_backdrop,
+            "windowCornerRadius": settings.window_corner_radius,
+            "windowBorderColor": settings.window_border_color,
             "opacity": settings.opacity,
             "backgroundImagePath": settings.background_image_path,
             "toggleShortcut": settings.toggle_shortcut,
             "numberShortcuts": settings.number_shortcuts,
             "numberShortcutsModifier": settings.number_shortcuts_modifier,
+            "relativePasteHotkeys": settings.relative_paste_hotkeys,
             "clipboardMonitor": settings.clipboard_monitor,
+            "monitorDebounceMs": settings.monitor_debounce_ms,
+            "rdpClipboardDelayMs": settings.rdp_clipboard_delay_ms,
             "ignoreDuplicates": settings.ignore_duplicates,
+            "recordWhitespaceOnly": settings.record_whitespace_only,
+            "dedupMode": settings.dedup_mode,
             "saveImages": settings.save_images,
+            "mixedContentPreference": settings.mixed_content_preference,
+            "monitorRecordText": settings.monitor_record_text,
+            "monitorRecordImages": settings.monitor_record_images,
+            "monitorRecordFiles": settings.monitor_record_files,
+            "appendMode": settings.append_mode,
+            "appendModifier": settings.append_modifier,
+            "appendSeparator": settings.append_separator,
+            "clipboardSetRetries": settings.clipboard_set_retries,
+            "clipboardSetRetryDelayMs": settings.clipboard_set_retry_delay_ms,
             "showImagePreview": settings.show_image_preview,
+            "thumbnailMaxDimension": settings.thumbnail_max_dimension,
+            "thumbnailFormat": settings.thumbnail_format,
+            "maxTextLength": settings.max_text_length,
+            "maxFilesPerEntry": settings.max_files_per_entry,
+            "oversizedTextAction": settings.oversized_text_action,
+            "maxImageBytes": settings.max_image_bytes,
+            "confirmBeforeClear": settings.confirm_before_clear,
+            "trashRetentionDays": settings.trash_retention_days,
+            "notifyOnCopy": settings.notify_on_copy,
+            "notifyOnTranslationComplete": settings.notify_on_translation_complete,
+            "externalEditorPath": settings.external_editor_path,
             "soundEnabled": settings.sound_enabled,
             "soundVolume": settings.sound_volume,
             "copySoundPath": settings.copy_sound_path,
             "pasteSoundPath": settings.paste_sound_path,
+            "soundQuietStart": settings.sound_quiet_start,
+            "soundQuietEnd": settings.sound_quiet_end,
             "screenshot_enabled": settings.screenshot_enabled,
             "screenshot_shortcut": settings.screenshot_shortcut,
+            "screenshot_last_region_shortcut": settings.screenshot_last_region_shortcut,
             "screenshot_quality": settings.screenshot_quality,
+            "screenshot_format": settings.screenshot_format,
             "screenshot_auto_save": settings.screenshot_auto_save,
+            "screenshot_also_store_path": settings.screenshot_also_store_path,
             "screenshot_show_hints": settings.screenshot_show_hints,
             "screenshot_element_detection": settings.screenshot_element_detection,
             "screenshot_magnifier_enabled": settings.screenshot_magnifier_enabled,
             "screenshot_hints_enabled": settings.screenshot_hints_enabled,
             "screenshot_color_include_format": settings.screenshot_color_include_format,
+            "screenshot_save_dir": settings.screenshot_save_dir,
+            "screenshot_filename_template": settings.screenshot_filename_template,
+            "screenshot_mask_opacity": settings.screenshot_mask_opacity,
+            "screenshot_selection_color": settings.screenshot_selection_color,
+            "screenshot_aspect_lock": settings.screenshot_aspect_lock,
+            "screenshot_min_size": settings.screenshot_min_size,
+            "screenshot_smart_selection_enabled": settings.screenshot_smart_selection_enabled,
+            "screenshot_capture_timeout_secs": settings.screenshot_capture_timeout_secs,
+            "scrolling_min_interval_ms": settings.scrolling_min_interval_ms,
+            "scrolling_max_interval_ms": settings.scrolling_max_interval_ms,
+            "last_screenshot_region": settings.last_screenshot_region,
             "previewEnabled": settings.preview_enabled,
             "previewShortcut": settings.preview_shortcut,
             "previewItemsCount": settings.preview_items_count,
             "previewAutoPaste": settings.preview_auto_paste,
+            "previewConfirmMode": settings.preview_confirm_mode,
             "previewScrollSound": settings.preview_scroll_sound,
             "previewScrollSoundPath": settings.preview_scroll_sound_path,
+            "previewNavSoundPath": settings.preview_nav_sound_path,
+            "previewScrollStep": settings.preview_scroll_step,
+            "previewLayout": settings.preview_layout,
             "aiTranslationEnabled": settings.ai_translation_enabled,
             "aiApiKey": settings.ai_api_key,
             "aiModel": settings.ai_model,
@@ -50,15 +103,34 @@ impl SettingsConverter {
             "aiTranslateOnCopy": settings.ai_translate_on_copy,
             "aiTranslateOnPaste": settings.ai_translate_on_paste,
             "aiTranslationPrompt": settings.ai_translation_prompt,
+            "aiLanguagePrompts": settings.ai_language_prompts,
             "aiInputSpeed": settings.ai_input_speed,
             "aiNewlineMode": settings.ai_newline_mode,
             "aiOutputMode": settings.ai_output_mode,
+            "aiOutputInitialDelayMs": settings.ai_output_initial_delay_ms,
+            "aiMaxRetries": settings.ai_max_retries,
+            "aiRetryBaseDelayMs": settings.ai_retry_base_delay_ms,
+            "aiCostPer1kChars": settings.ai_cost_per_1k_chars,
+            "aiTemperature": settings.ai_temperature,
+            "aiMaxTokens": settings.ai_max_tokens,
+            "aiLanguageHotkeys": settings.ai_language_hotkeys,
+            "ocrLanguage": settings.ocr_language,
+            "enableKeyboardHook": settings.enable_keyboard_hook,
+            "enableMouseHook": settings.enable_mouse_hook,
+            "enableAi": settings.enable_ai,
+            "enableScreenshot": settings.enable_screenshot,
+            "ephemeralMode": settings.ephemeral_mode,
+            "autoClearClipboardSeconds": settings.auto_clear_clipboard_seconds,
             "mouseMiddleButtonEnabled": settings.mouse_middle_button_enabled,
             "mouseMiddleButtonModifier": settings.mouse_middle_button_modifier,
+            "mouseMiddleButtonAction": settings.mouse_middle_button_action,
+            "doubleClickAction": settings.double_click_action,
             "clipboardAnimationEnabled": settings.clipboard_animation_enabled,
             "autoScrollToTopOnShow": settings.auto_scroll_to_top_on_show,
+            "historyOrder": settings.history_order,
             "windowPositionMode": settings.window_position_mode,
             "rememberWindowSize": settings.remember_window_size,
+            "windowSizePresets": settings.window_size_presets,
             "savedWindowPosition": settings.saved_window_position,
             "savedWindowSize": settings.saved_window_size,
             "appFilterEnabled": settings.app_filter_enabled,
@@ -70,17 +142,27 @@ impl SettingsConverter {
             "autoFocusSearch": settings.auto_focus_search,
             "sidebarHoverDelay": settings.sidebar_hover_delay,
             "pasteWithFormat": settings.paste_with_format,
+            "smartPasteStripCommonIndent": settings.smart_paste_strip_common_indent,
             "imageDataPriorityApps": settings.image_data_priority_apps,
+            "appPasteRules": settings.app_paste_rules,
             "navigateUpShortcut": settings.navigate_up_shortcut,
             "navigateDownShortcut": settings.navigate_down_shortcut,
             "tabLeftShortcut": settings.tab_left_shortcut,
             "tabRightShortcut": settings.tab_right_shortcut,
             "focusSearchShortcut": settings.focus_search_shortcut,
             "hideWindowShortcut": settings.hide_window_shortcut,
+            "escapeBehavior": settings.escape_behavior,
             "executeItemShortcut": settings.execute_item_shortcut,
             "previousGroupShortcut": settings.previous_group_shortcut,
             "nextGroupShortcut": settings.next_group_shortcut,
             "togglePinShortcut": settings.toggle_pin_shortcut,
+            "togglePinShortcutGlobal": settings.toggle_pin_shortcut_global,
+            "quickTextCaptureShortcut": settings.quick_text_capture_shortcut,
+            "quickTextCaptureGroup": settings.quick_text_capture_group,
+            "defaultGroupId": settings.default_group_id,
+            "settingsShortcut": settings.settings_shortcut,
+            "cycleWindowSizeShortcut": settings.cycle_window_size_shortcut,
+            "transformPipelines": settings.transform_pipelines,
         })
     }
 
@@ -135,39 +217,213 @@ impl SettingsConverter {
             };
         }
 
+        update_bool!(onboarding_completed, "onboardingCompleted");
+
         // 基础设置
         update_bool!(auto_start, "autoStart");
         update_bool!(start_hidden, "startHidden");
+
+        if let Some(v) = json.get("startupDelayMs").and_then(|v| v.as_u64()) {
+            settings.startup_delay_ms = v.min(60000) as u32;
+        }
         update_bool!(run_as_admin, "runAsAdmin");
         update_bool!(show_startup_notification, "showStartupNotification");
+
+        if let Some(v) = json.get("logLevel").and_then(|v| v.as_str()) {
+            if matches!(v, "off" | "error" | "warn" | "info" | "debug" | "trace") {
+                settings.log_level = v.to_string();
+            }
+        }
+        update_bool!(log_to_file, "logToFile");
         update_u64!(history_limit, "historyLimit");
+        update_u64!(image_history_limit, "imageHistoryLimit");
         update_string!(theme, "theme");
+
+        if let Some(v) = json.get("windowBackdrop").and_then(|v| v.as_str()) {
+            if matches!(v, "blur" | "acrylic" | "mica" | "none") {
+                settings.window_backdrop = v.to_string();
+            }
+        }
+
+        if let Some(v) = json.get("windowCornerRadius").and_then(|v| v.as_str()) {
+            if matches!(v, "round" | "small" | "none" | "default") {
+                settings.window_corner_radius = v.to_string();
+            }
+        }
+
+        update_string!(window_border_color, "windowBorderColor");
         update_f64!(opacity, "opacity");
         update_string!(background_image_path, "backgroundImagePath");
         update_string!(toggle_shortcut, "toggleShortcut");
         update_bool!(number_shortcuts, "numberShortcuts");
         update_string!(number_shortcuts_modifier, "numberShortcutsModifier");
+
+        if let Some(v) = json.get("relativePasteHotkeys") {
+            if let Ok(hotkeys) = serde_json::from_value(v.clone()) {
+                settings.relative_paste_hotkeys = hotkeys;
+            }
+        }
         update_bool!(clipboard_monitor, "clipboardMonitor");
+
+        if let Some(v) = json.get("monitorDebounceMs").and_then(|v| v.as_u64()) {
+            settings.monitor_debounce_ms = v.min(10000) as u32;
+        }
+        if let Some(v) = json.get("rdpClipboardDelayMs").and_then(|v| v.as_u64()) {
+            settings.rdp_clipboard_delay_ms = v.min(2000) as u32;
+        }
         update_bool!(ignore_duplicates, "ignoreDuplicates");
+        update_bool!(record_whitespace_only, "recordWhitespaceOnly");
+
+        if let Some(v) = json.get("dedupMode").and_then(|v| v.as_str()) {
+            if matches!(v, "exact" | "trimmed" | "normalized") {
+                settings.dedup_mode = v.to_string();
+            }
+        }
         update_bool!(save_images, "saveImages");
+
+        if let Some(v) = json.get("mixedContentPreference").and_then(|v| v.as_str()) {
+            if matches!(v, "text" | "image" | "both") {
+                settings.mixed_content_preference = v.to_string();
+            }
+        }
+        update_bool!(monitor_record_text, "monitorRecordText");
+        update_bool!(monitor_record_images, "monitorRecordImages");
+        update_bool!(monitor_record_files, "monitorRecordFiles");
+        update_bool!(append_mode, "appendMode");
+
+        if let Some(v) = json.get("appendModifier").and_then(|v| v.as_str()) {
+            if matches!(v, "Alt" | "Shift" | "Win" | "None") {
+                settings.append_modifier = v.to_string();
+            }
+        }
+        update_string!(append_separator, "appendSeparator");
+
+        if let Some(v) = json.get("clipboardSetRetries").and_then(|v| v.as_u64()) {
+            settings.clipboard_set_retries = v.min(10) as u32;
+        }
+        if let Some(v) = json.get("clipboardSetRetryDelayMs").and_then(|v| v.as_u64()) {
+            settings.clipboard_set_retry_delay_ms = v.clamp(1, 500) as u32;
+        }
         update_bool!(show_image_preview, "showImagePreview");
 
+        if let Some(v) = json.get("thumbnailMaxDimension").and_then(|v| v.as_u64()) {
+            settings.thumbnail_max_dimension = (v as u32).max(32).min(1024);
+        }
+
+        if let Some(v) = json.get("thumbnailFormat").and_then(|v| v.as_str()) {
+            if matches!(v, "webp" | "jpeg" | "png") {
+                settings.thumbnail_format = v.to_string();
+            }
+        }
+
+        // 记录大小限制（0 表示不限制）
+        update_u64!(max_text_length, "maxTextLength");
+
+        if let Some(v) = json.get("maxFilesPerEntry").and_then(|v| v.as_u64()) {
+            settings.max_files_per_entry = v.max(1).min(100_000) as u32;
+        }
+        update_u64!(max_image_bytes, "maxImageBytes");
+
+        if let Some(v) = json.get("oversizedTextAction").and_then(|v| v.as_str()) {
+            if matches!(v, "truncate" | "skip") {
+                settings.oversized_text_action = v.to_string();
+            }
+        }
+        update_bool!(confirm_before_clear, "confirmBeforeClear");
+
+        if let Some(v) = json.get("trashRetentionDays").and_then(|v| v.as_u64()) {
+            settings.trash_retention_days = (v as u32).max(1).min(90);
+        }
+        update_bool!(notify_on_copy, "notifyOnCopy");
+        update_bool!(notify_on_translation_complete, "notifyOnTranslationComplete");
+        update_string!(external_editor_path, "externalEditorPath");
+
         // 音效设置
         update_bool!(sound_enabled, "soundEnabled");
         update_f64!(sound_volume, "soundVolume");
         update_string!(copy_sound_path, "copySoundPath");
         update_string!(paste_sound_path, "pasteSoundPath");
 
+        // 静音时段只接受空值或 HH:MM
+        if let Some(v) = json.get("soundQuietStart").and_then(|v| v.as_str()) {
+            if v.is_empty() || crate::sound_manager::parse_time_of_day(v).is_some() {
+                settings.sound_quiet_start = v.trim().to_string();
+            }
+        }
+        if let Some(v) = json.get("soundQuietEnd").and_then(|v| v.as_str()) {
+            if v.is_empty() || crate::sound_manager::parse_time_of_day(v).is_some() {
+                settings.sound_quiet_end = v.trim().to_string();
+            }
+        }
+
         // 截屏设置
         update_bool!(screenshot_enabled, "screenshot_enabled");
         update_string!(screenshot_shortcut, "screenshot_shortcut");
+        update_string!(screenshot_last_region_shortcut, "screenshot_last_region_shortcut");
         update_u8!(screenshot_quality, "screenshot_quality");
+
+        if let Some(v) = json.get("screenshot_format").and_then(|v| v.as_str()) {
+            if matches!(v, "auto" | "png" | "jpeg" | "webp") {
+                settings.screenshot_format = v.to_string();
+            }
+        }
         update_bool!(screenshot_auto_save, "screenshot_auto_save");
+        update_bool!(screenshot_also_store_path, "screenshot_also_store_path");
         update_bool!(screenshot_show_hints, "screenshot_show_hints");
         update_string!(screenshot_element_detection, "screenshot_element_detection");
         update_bool!(screenshot_magnifier_enabled, "screenshot_magnifier_enabled");
         update_bool!(screenshot_hints_enabled, "screenshot_hints_enabled");
         update_bool!(screenshot_color_include_format, "screenshot_color_include_format");
+        update_string!(screenshot_save_dir, "screenshot_save_dir");
+        update_string!(screenshot_filename_template, "screenshot_filename_template");
+
+        if let Some(v) = json.get("screenshot_mask_opacity").and_then(|v| v.as_u64()) {
+            settings.screenshot_mask_opacity = v.min(255) as u8;
+        }
+
+        // 仅接受 #RRGGBB 格式的颜色
+        if let Some(v) = json.get("screenshot_selection_color").and_then(|v| v.as_str()) {
+            if v.len() == 7 && v.starts_with('#') && v[1..].chars().all(|c| c.is_ascii_hexdigit()) {
+                settings.screenshot_selection_color = v.to_string();
+            }
+        }
+
+        if let Some(v) = json.get("screenshot_aspect_lock").and_then(|v| v.as_str()) {
+            if matches!(v, "free" | "16:9" | "4:3" | "1:1") {
+                settings.screenshot_aspect_lock = v.to_string();
+            }
+        }
+
+        if let Some(v) = json.get("screenshot_min_size").and_then(|v| v.as_u64()) {
+            settings.screenshot_min_size = v.clamp(1, 200) as u32;
+        }
+        update_bool!(screenshot_smart_selection_enabled, "screenshot_smart_selection_enabled");
+
+        // 截屏采集超时限制在 1-60 秒
+        if let Some(v) = json.get("screenshot_capture_timeout_secs").and_then(|v| v.as_u64()) {
+            settings.screenshot_capture_timeout_secs = v.clamp(1, 60) as u32;
+        }
+
+        // 长截屏采集间隔限制在 10-1000ms，且最大间隔不小于最小间隔
+        if let Some(v) = json.get("scrolling_min_interval_ms").and_then(|v| v.as_u64()) {
+            settings.scrolling_min_interval_ms = v.clamp(10, 1000) as u32;
+        }
+        if let Some(v) = json.get("scrolling_max_interval_ms").and_then(|v| v.as_u64()) {
+            settings.scrolling_max_interval_ms = v.clamp(10, 1000) as u32;
+        }
+        if settings.scrolling_max_interval_ms < settings.scrolling_min_interval_ms {
+            settings.scrolling_max_interval_ms = settings.scrolling_min_interval_ms;
+        }
+
+        if let Some(v) = json.get("last_screenshot_region").and_then(|v| v.as_array()) {
+            if v.len() == 4 {
+                if let (Some(x), Some(y), Some(w), Some(h)) =
+                    (v[0].as_i64(), v[1].as_i64(), v[2].as_u64(), v[3].as_u64())
+                {
+                    settings.last_screenshot_region = Some((x as i32, y as i32, w as u32, h as u32));
+                }
+            }
+        }
 
         // 预览窗口设置
         update_bool!(preview_enabled, "previewEnabled");
@@ -176,6 +432,18 @@ impl SettingsConverter {
         update_bool!(preview_auto_paste, "previewAutoPaste");
         update_bool!(preview_scroll_sound, "previewScrollSound");
         update_string!(preview_scroll_sound_path, "previewScrollSoundPath");
+        update_string!(preview_nav_sound_path, "previewNavSoundPath");
+        update_string!(preview_layout, "previewLayout");
+
+        if let Some(v) = json.get("previewConfirmMode").and_then(|v| v.as_str()) {
+            if matches!(v, "auto" | "confirm") {
+                settings.preview_confirm_mode = v.to_string();
+            }
+        }
+
+        if let Some(v) = json.get("previewScrollStep").and_then(|v| v.as_u64()) {
+            settings.preview_scroll_step = (v as u32).max(1).min(20);
+        }
 
         // AI翻译设置
         update_bool!(ai_translation_enabled, "aiTranslationEnabled");
@@ -186,13 +454,80 @@ impl SettingsConverter {
         update_bool!(ai_translate_on_copy, "aiTranslateOnCopy");
         update_bool!(ai_translate_on_paste, "aiTranslateOnPaste");
         update_string!(ai_translation_prompt, "aiTranslationPrompt");
+
+        if let Some(v) = json.get("aiLanguagePrompts") {
+            if let Ok(prompts) = serde_json::from_value(v.clone()) {
+                settings.ai_language_prompts = prompts;
+            }
+        }
         update_u32!(ai_input_speed, "aiInputSpeed");
         update_string!(ai_newline_mode, "aiNewlineMode");
         update_string!(ai_output_mode, "aiOutputMode");
 
+        if let Some(v) = json.get("aiOutputInitialDelayMs").and_then(|v| v.as_u64()) {
+            settings.ai_output_initial_delay_ms = v.min(5000) as u32;
+        }
+
+        if let Some(v) = json.get("aiMaxRetries").and_then(|v| v.as_u64()) {
+            settings.ai_max_retries = v.min(5) as u32;
+        }
+
+        if let Some(v) = json.get("aiRetryBaseDelayMs").and_then(|v| v.as_u64()) {
+            settings.ai_retry_base_delay_ms = v.max(100).min(30000) as u32;
+        }
+
+        if let Some(v) = json.get("aiCostPer1kChars").and_then(|v| v.as_f64()) {
+            settings.ai_cost_per_1k_chars = v.max(0.0);
+        }
+
+        // 模型参数超出范围时恢复默认值
+        if let Some(v) = json.get("aiTemperature").and_then(|v| v.as_f64()) {
+            settings.ai_temperature = if crate::ai_config::TEMPERATURE_RANGE.contains(&v) {
+                v
+            } else {
+                AppSettings::default().ai_temperature
+            };
+        }
+
+        if let Some(v) = json.get("aiMaxTokens").and_then(|v| v.as_u64()) {
+            settings.ai_max_tokens = match u32::try_from(v) {
+                Ok(v) if crate::ai_config::MAX_TOKENS_RANGE.contains(&v) => v,
+                _ => AppSettings::default().ai_max_tokens,
+            };
+        }
+
+        if let Some(v) = json.get("aiLanguageHotkeys") {
+            if let Ok(hotkeys) = serde_json::from_value(v.clone()) {
+                settings.ai_language_hotkeys = hotkeys;
+            }
+        }
+
+        // OCR设置
+        update_string!(ocr_language, "ocrLanguage");
+
+        // 功能开关
+        update_bool!(enable_keyboard_hook, "enableKeyboardHook");
+        update_bool!(enable_mouse_hook, "enableMouseHook");
+        update_bool!(enable_ai, "enableAi");
+        update_bool!(enable_screenshot, "enableScreenshot");
+
+        // 隐私设置
+        update_bool!(ephemeral_mode, "ephemeralMode");
+
+        if let Some(v) = json.get("autoClearClipboardSeconds").and_then(|v| v.as_u64()) {
+            settings.auto_clear_clipboard_seconds = v.min(3600) as u32;
+        }
+
         // 鼠标设置
         update_bool!(mouse_middle_button_enabled, "mouseMiddleButtonEnabled");
         update_string!(mouse_middle_button_modifier, "mouseMiddleButtonModifier");
+        update_string!(mouse_middle_button_action, "mouseMiddleButtonAction");
+
+        if let Some(v) = json.get("doubleClickAction").and_then(|v| v.as_str()) {
+            if matches!(v, "paste" | "copy_only" | "edit" | "pin") {
+                settings.double_click_action = v.to_string();
+            }
+        }
 
         // 动画设置
         update_bool!(clipboard_animation_enabled, "clipboardAnimationEnabled");
@@ -200,10 +535,26 @@ impl SettingsConverter {
         // 显示行为
         update_bool!(auto_scroll_to_top_on_show, "autoScrollToTopOnShow");
 
+        if let Some(v) = json.get("historyOrder").and_then(|v| v.as_str()) {
+            if matches!(v, "newest_first" | "oldest_first") {
+                settings.history_order = v.to_string();
+            }
+        }
+
         // 窗口设置
         update_string!(window_position_mode, "windowPositionMode");
         update_bool!(remember_window_size, "rememberWindowSize");
 
+        // 窗口大小预设不能小于主窗口的最小尺寸
+        if let Some(v) = json.get("windowSizePresets") {
+            if let Ok(presets) = serde_json::from_value::<Vec<(u32, u32)>>(v.clone()) {
+                settings.window_size_presets = presets
+                    .into_iter()
+                    .map(|(width, height)| (width.clamp(350, 7680), height.clamp(500, 4320)))
+                    .collect();
+            }
+        }
+
         if let Some(v) = json.get("savedWindowPosition").and_then(|v| v.as_array()) {
             if v.len() == 2 {
                 if let (Some(x), Some(y)) = (v[0].as_i64(), v[1].as_i64()) {
@@ -250,6 +601,7 @@ impl SettingsConverter {
 
         // 格式设置
         update_bool!(paste_with_format, "pasteWithFormat");
+        update_bool!(smart_paste_strip_common_indent, "smartPasteStripCommonIndent");
 
         if let Some(v) = json.get("imageDataPriorityApps").and_then(|v| v.as_array()) {
             settings.image_data_priority_apps = v
@@ -259,6 +611,12 @@ impl SettingsConverter {
                 .collect();
         }
 
+        if let Some(v) = json.get("appPasteRules") {
+            if let Ok(rules) = serde_json::from_value(v.clone()) {
+                settings.app_paste_rules = rules;
+            }
+        }
+
         // 快捷键设置
         update_string!(navigate_up_shortcut, "navigateUpShortcut");
         update_string!(navigate_down_shortcut, "navigateDownShortcut");
@@ -266,9 +624,28 @@ impl SettingsConverter {
         update_string!(tab_right_shortcut, "tabRightShortcut");
         update_string!(focus_search_shortcut, "focusSearchShortcut");
         update_string!(hide_window_shortcut, "hideWindowShortcut");
+
+        if let Some(v) = json.get("escapeBehavior").and_then(|v| v.as_str()) {
+            if matches!(v, "hide" | "clear_then_hide") {
+                settings.escape_behavior = v.to_string();
+            }
+        }
         update_string!(execute_item_shortcut, "executeItemShortcut");
         update_string!(previous_group_shortcut, "previousGroupShortcut");
         update_string!(next_group_shortcut, "nextGroupShortcut");
         update_string!(toggle_pin_shortcut, "togglePinShortcut");
+        update_bool!(toggle_pin_shortcut_global, "togglePinShortcutGlobal");
+        update_string!(quick_text_capture_shortcut, "quickTextCaptureShortcut");
+        update_string!(quick_text_capture_group, "quickTextCaptureGroup");
+        update_string!(default_group_id, "defaultGroupId");
+        update_string!(settings_shortcut, "settingsShortcut");
+        update_string!(cycle_window_size_shortcut, "cycleWindowSizeShortcut");
+
+        // 转换流水线设置
+        if let Some(v) = json.get("transformPipelines") {
+            if let Ok(pipelines) = serde_json::from_value(v.clone()) {
+                settings.transform_pipelines = pipelines;
+            }
+        }
     }
 }
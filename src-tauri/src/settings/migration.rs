@@ -15,7 +15,7 @@ impl SettingsMigration {
     ) -> Result<(), String> {
         let new_dir = PathBuf::from(&new_path);
 
-        // 验证新路径
+        // 验证新路径（可写性与剩余空间在迁移前检查）
         if !new_dir.exists() {
             fs::create_dir_all(&new_dir)
                 .map_err(|e| format!("创建新存储目录失败: {}", e))?;
@@ -44,6 +44,9 @@ impl SettingsMigration {
         settings.custom_storage_path = Some(new_path);
         settings.use_custom_storage = true;
 
+        // 先切换全局设置，确保数据库在新目录重新打开
+        super::state::update_global_settings(settings.clone())?;
+
         // 重新初始化数据库
         crate::database::reinitialize_database()
             .map_err(|e| format!("重新初始化数据库失败: {}", e))?;
@@ -82,6 +85,9 @@ impl SettingsMigration {
         settings.custom_storage_path = None;
         settings.use_custom_storage = false;
 
+        // 先切换全局设置，确保数据库在新目录重新打开
+        super::state::update_global_settings(settings.clone())?;
+
         // 重新初始化数据库
         crate::database::reinitialize_database()
             .map_err(|e| format!("重新初始化数据库失败: {}", e))?;
@@ -10,13 +10,15 @@ mod settings_service;   // 业务逻辑服务
 mod window;             // 设置窗口管理
 
 // 公共导出 - 供全局 commands.rs 直接调用
-pub use model::{AppSettings, StorageInfo};
+pub use model::{AppPasteRule, AppSettings, RelativePasteHotkey, StorageInfo, TranslationLanguageHotkey};
 pub use state::{
     get_global_settings, 
     update_global_settings, 
     get_data_directory,
     save_window_position,
-    save_window_size
+    save_window_size,
+    save_screenshot_region,
+    init_settings_events
 };
 pub use settings_service::SettingsService;
 pub use window::SettingsWindow;
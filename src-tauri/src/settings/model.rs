@@ -1,50 +1,108 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // 应用设置数据模型
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AppSettings {
+    // 设置文件结构版本
+    pub settings_schema_version: u32,
+    // 是否已完成首次启动引导
+    pub onboarding_completed: bool,
+
     // === 基础设置 ===
     pub auto_start: bool,
     pub start_hidden: bool,
     pub run_as_admin: bool,
     pub show_startup_notification: bool,
+    pub startup_delay_ms: u32,
+    pub log_level: String,
+    pub log_to_file: bool,
     pub history_limit: u64,
+    pub image_history_limit: u64,
     pub theme: String,
+    pub window_backdrop: String,
+    pub window_corner_radius: String,
+    pub window_border_color: String,
     pub opacity: f64,
     pub background_image_path: String,
     pub toggle_shortcut: String,
     pub number_shortcuts: bool,
     pub number_shortcuts_modifier: String,
+    pub relative_paste_hotkeys: Vec<RelativePasteHotkey>,
     pub clipboard_monitor: bool,
+    pub monitor_debounce_ms: u32,
+    pub rdp_clipboard_delay_ms: u32,
     pub ignore_duplicates: bool,
+    pub record_whitespace_only: bool,
+    pub dedup_mode: String,
     pub save_images: bool,
+    pub mixed_content_preference: String,
+    pub monitor_record_text: bool,
+    pub monitor_record_images: bool,
+    pub monitor_record_files: bool,
+    pub append_mode: bool,         // 追加模式：新复制的文本拼接到上一条文本之后
+    pub append_modifier: String,   // 复制时按住该修饰键临时追加（Alt/Shift/Win，None 时不启用）
+    pub append_separator: String,  // 追加时的分隔符
+    pub clipboard_set_retries: u32,
+    pub clipboard_set_retry_delay_ms: u32,
     pub show_image_preview: bool,
+    pub thumbnail_max_dimension: u32,
+    pub thumbnail_format: String,
+    pub max_text_length: u64,
+    pub max_files_per_entry: u32, // 单条文件记录最多保存的文件数量，超出部分只计入摘要
+    pub oversized_text_action: String,
+    pub max_image_bytes: u64,
+    pub confirm_before_clear: bool,
+    pub trash_retention_days: u32,
+    pub notify_on_copy: bool,
+    pub notify_on_translation_complete: bool, // 粘贴模式翻译完成或失败时发送通知
+    pub external_editor_path: String,         // 外部编辑器路径，为空时使用记事本
 
     // === 音效设置 ===
     pub sound_enabled: bool,
     pub sound_volume: f64,
     pub copy_sound_path: String,
     pub paste_sound_path: String,
+    pub sound_quiet_start: String, // 静音时段开始（HH:MM），为空时不启用
+    pub sound_quiet_end: String,   // 静音时段结束（HH:MM），可跨越午夜
 
     // === 截屏设置 ===
     pub screenshot_enabled: bool,
     pub screenshot_shortcut: String,
+    pub screenshot_last_region_shortcut: String, // 直接重截上次区域，为空时不注册
     pub screenshot_quality: u8,
+    pub screenshot_format: String,
     pub screenshot_auto_save: bool,
+    pub screenshot_also_store_path: bool, // 保存截屏文件后，把文件路径另存为一条文件历史
     pub screenshot_show_hints: bool,
     pub screenshot_element_detection: String,
     pub screenshot_magnifier_enabled: bool,
     pub screenshot_hints_enabled: bool,
     pub screenshot_color_include_format: bool,
+    pub screenshot_save_dir: String,
+    pub screenshot_filename_template: String,
+    pub screenshot_mask_opacity: u8,
+    pub screenshot_selection_color: String,
+    pub screenshot_aspect_lock: String, // 选区比例锁定: "free" | "16:9" | "4:3" | "1:1"
+    pub screenshot_min_size: u32,                // 选区最小宽高（像素）
+    pub screenshot_smart_selection_enabled: bool, // 是否启用智能元素选区
+    pub screenshot_capture_timeout_secs: u32, // 全屏截取超时时间（秒），超时视为取消
+    pub scrolling_min_interval_ms: u32, // 长截屏画面变化时的采集间隔
+    pub scrolling_max_interval_ms: u32, // 长截屏画面长时间无变化时的采集间隔
+    pub last_screenshot_region: Option<(i32, i32, u32, u32)>,
 
     // === 预览窗口设置 ===
     pub preview_enabled: bool,
     pub preview_shortcut: String,
     pub preview_items_count: u32,
     pub preview_auto_paste: bool,
+    pub preview_confirm_mode: String, // "auto" 松开即粘贴 | "confirm" 松开后按回车确认
     pub preview_scroll_sound: bool,
     pub preview_scroll_sound_path: String,
+    pub preview_nav_sound_path: String, // 方向键切换预览项的音效，为空时不播放
+    pub preview_scroll_step: u32,
+    pub preview_layout: String,
 
     // === AI翻译设置 ===
     pub ai_translation_enabled: bool,
@@ -55,19 +113,46 @@ pub struct AppSettings {
     pub ai_translate_on_copy: bool,
     pub ai_translate_on_paste: bool,
     pub ai_translation_prompt: String,
+    pub ai_language_prompts: HashMap<String, String>,
     pub ai_input_speed: u32,
     pub ai_newline_mode: String,
     pub ai_output_mode: String,
+    pub ai_output_initial_delay_ms: u32,
+    pub ai_max_retries: u32,
+    pub ai_retry_base_delay_ms: u32,
+    pub ai_cost_per_1k_chars: f64, // 每千字符的估算费用，用于翻译用量统计
+    pub ai_temperature: f64,       // 模型温度 (0.0-2.0)，越低翻译越稳定
+    pub ai_max_tokens: u32,        // 最大输出token数量
+    pub ai_language_hotkeys: Vec<TranslationLanguageHotkey>,
+
+    // === OCR设置 ===
+    pub ocr_language: String, // 截屏 OCR 识别语言（BCP-47，如 zh-Hans-CN），为空时识别简体中文和英文
+
+    // === 功能开关（受限环境下可关闭对应子系统） ===
+    pub enable_keyboard_hook: bool, // 全局键盘钩子，关闭后窗口导航等由钩子处理的快捷键不可用（RegisterHotKey 注册的全局快捷键不受影响）
+    pub enable_mouse_hook: bool,    // 全局鼠标钩子
+    pub enable_ai: bool,            // AI 相关功能
+    pub enable_screenshot: bool,    // 截屏子系统
+
+    // === 隐私设置 ===
+    pub ephemeral_mode: bool, // 临时模式：历史记录只保存在内存中，退出后清空
+    pub auto_clear_clipboard_seconds: u32, // 粘贴敏感内容后自动清除剪贴板的秒数，0 为不清除
 
     // === 鼠标设置 ===
     pub mouse_middle_button_enabled: bool,
     pub mouse_middle_button_modifier: String,
+    pub mouse_middle_button_action: String,
+    // 双击历史项操作: paste / copy_only / edit / pin
+    pub double_click_action: String,
 
     // === 动画设置 ===
     pub clipboard_animation_enabled: bool,
 
     // === 显示行为 ===
     pub auto_scroll_to_top_on_show: bool,
+    // 分页历史的显示顺序: newest_first / oldest_first
+    // 按索引操作的命令和数字粘贴快捷键始终按最新优先计数，不受此设置影响
+    pub history_order: String,
 
     // === 应用过滤设置 ===
     pub app_filter_enabled: bool,
@@ -76,12 +161,14 @@ pub struct AppSettings {
 
     #[serde(default)]
     pub image_data_priority_apps: Vec<String>,
+    pub app_paste_rules: Vec<AppPasteRule>,
 
     // === 窗口设置 ===
     pub window_position_mode: String,
     pub remember_window_size: bool,
     pub saved_window_position: Option<(i32, i32)>,
     pub saved_window_size: Option<(u32, u32)>,
+    pub window_size_presets: Vec<(u32, u32)>, // 窗口大小预设（逻辑像素），按顺序循环切换
 
     // === 贴边隐藏设置 ===
     pub edge_hide_enabled: bool,
@@ -97,6 +184,7 @@ pub struct AppSettings {
 
     // === 格式设置 ===
     pub paste_with_format: bool,
+    pub smart_paste_strip_common_indent: bool,
 
     // === 快捷键设置 ===
     pub hotkeys_enabled: bool,
@@ -106,10 +194,20 @@ pub struct AppSettings {
     pub tab_right_shortcut: String,
     pub focus_search_shortcut: String,
     pub hide_window_shortcut: String,
+    pub escape_behavior: String, // "hide" 直接隐藏 | "clear_then_hide" 先清空搜索框再隐藏
     pub execute_item_shortcut: String,
     pub previous_group_shortcut: String,
     pub next_group_shortcut: String,
     pub toggle_pin_shortcut: String,
+    pub toggle_pin_shortcut_global: bool, // 固定快捷键在窗口未激活时也生效
+    pub quick_text_capture_shortcut: String,
+    pub quick_text_capture_group: String,
+    pub default_group_id: String, // 新收藏/常用文本未指定分组时放入的分组
+    pub settings_shortcut: String,
+    pub cycle_window_size_shortcut: String, // 在窗口大小预设之间切换，为空时不注册
+
+    // === 转换流水线设置 ===
+    pub transform_pipelines: Vec<TransformPipeline>,
 
     // === 数据存储设置 ===
     pub custom_storage_path: Option<String>,
@@ -119,47 +217,102 @@ pub struct AppSettings {
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
+            settings_schema_version: super::storage::CURRENT_SETTINGS_SCHEMA_VERSION,
+            onboarding_completed: false,
+
             // 基础设置
             auto_start: false,
             start_hidden: true,
             run_as_admin: false,
             show_startup_notification: true,
+            startup_delay_ms: 0,
+            log_level: "info".to_string(),
+            log_to_file: false,
             history_limit: 100,
+            image_history_limit: 0,
             theme: "light".to_string(),
+            window_backdrop: "none".to_string(),
+            window_corner_radius: "default".to_string(),
+            window_border_color: String::new(),
             opacity: 0.9,
             background_image_path: String::new(),
             toggle_shortcut: "Alt+V".to_string(),
             number_shortcuts: true,
             number_shortcuts_modifier: "Ctrl".to_string(),
+            relative_paste_hotkeys: vec![],
             clipboard_monitor: true,
+            monitor_debounce_ms: 0,
+            rdp_clipboard_delay_ms: 150,
             ignore_duplicates: true,
+            record_whitespace_only: false,
+            dedup_mode: "exact".to_string(),
             save_images: true,
+            mixed_content_preference: "text".to_string(),
+            monitor_record_text: true,
+            monitor_record_images: true,
+            monitor_record_files: true,
+            append_mode: false,
+            append_modifier: "Alt".to_string(),
+            append_separator: "\n".to_string(),
+            clipboard_set_retries: 3,
+            clipboard_set_retry_delay_ms: 25,
             show_image_preview: false,
+            thumbnail_max_dimension: 256,
+            thumbnail_format: "webp".to_string(),
+            max_text_length: 0,
+            max_files_per_entry: 1000,
+            oversized_text_action: "truncate".to_string(),
+            max_image_bytes: 0,
+            confirm_before_clear: false,
+            trash_retention_days: 7,
+            notify_on_copy: false,
+            notify_on_translation_complete: false,
+            external_editor_path: "notepad.exe".to_string(),
 
             // 音效设置
             sound_enabled: true,
             sound_volume: 50.0,
             copy_sound_path: String::new(),
             paste_sound_path: String::new(),
+            sound_quiet_start: String::new(),
+            sound_quiet_end: String::new(),
 
             // 截屏设置
             screenshot_enabled: true,
             screenshot_shortcut: "Ctrl+Shift+A".to_string(),
+            screenshot_last_region_shortcut: String::new(),
             screenshot_quality: 85,
+            screenshot_format: "auto".to_string(),
             screenshot_auto_save: true,
+            screenshot_also_store_path: false,
             screenshot_show_hints: true,
             screenshot_element_detection: "all".to_string(),
             screenshot_magnifier_enabled: true,
             screenshot_hints_enabled: true,
             screenshot_color_include_format: true,
+            screenshot_save_dir: String::new(),
+            screenshot_filename_template: "QC截屏_{date}_{time}".to_string(),
+            screenshot_mask_opacity: 128,
+            screenshot_selection_color: "#007bff".to_string(),
+            screenshot_aspect_lock: "free".to_string(),
+            screenshot_min_size: 10,
+            screenshot_smart_selection_enabled: true,
+            screenshot_capture_timeout_secs: 5,
+            scrolling_min_interval_ms: 25,
+            scrolling_max_interval_ms: 60,
+            last_screenshot_region: None,
 
             // 预览窗口设置
             preview_enabled: true,
             preview_shortcut: "Ctrl+`".to_string(),
             preview_items_count: 5,
             preview_auto_paste: true,
+            preview_confirm_mode: "auto".to_string(),
             preview_scroll_sound: true,
             preview_scroll_sound_path: "sounds/roll.mp3".to_string(),
+            preview_nav_sound_path: String::new(),
+            preview_scroll_step: 1,
+            preview_layout: "list".to_string(),
 
             // AI翻译设置
             ai_translation_enabled: false,
@@ -170,31 +323,57 @@ impl Default for AppSettings {
             ai_translate_on_copy: false,
             ai_translate_on_paste: true,
             ai_translation_prompt: "请将以下文本翻译成{target_language}，严格保持原文的所有格式、换行符、段落结构和空白字符，只返回翻译结果，不要添加任何解释或修改格式：".to_string(),
+            ai_language_prompts: HashMap::new(),
             ai_input_speed: 50,
             ai_newline_mode: "auto".to_string(),
             ai_output_mode: "stream".to_string(),
+            ai_output_initial_delay_ms: 150,
+            ai_max_retries: 2,
+            ai_retry_base_delay_ms: 1000,
+            ai_cost_per_1k_chars: 0.0,
+            ai_temperature: 0.3,
+            ai_max_tokens: 2048,
+            ai_language_hotkeys: vec![],
+
+            // OCR设置
+            ocr_language: String::new(),
+
+            // 功能开关
+            enable_keyboard_hook: true,
+            enable_mouse_hook: true,
+            enable_ai: true,
+            enable_screenshot: true,
+
+            // 隐私设置
+            ephemeral_mode: false,
+            auto_clear_clipboard_seconds: 0,
 
             // 鼠标设置
             mouse_middle_button_enabled: true,
             mouse_middle_button_modifier: "None".to_string(),
+            mouse_middle_button_action: "show".to_string(),
+            double_click_action: "paste".to_string(),
 
             // 动画设置
             clipboard_animation_enabled: true,
 
             // 显示行为
             auto_scroll_to_top_on_show: false,
+            history_order: "newest_first".to_string(),
 
             // 应用过滤设置
             app_filter_enabled: false,
             app_filter_mode: "blacklist".to_string(),
             app_filter_list: vec![],
             image_data_priority_apps: vec![],
+            app_paste_rules: vec![],
 
             // 窗口设置
             window_position_mode: "smart".to_string(),
             remember_window_size: false,
             saved_window_position: None,
             saved_window_size: None,
+            window_size_presets: vec![(360, 520), (480, 760)],
 
             // 贴边隐藏设置
             edge_hide_enabled: true,
@@ -210,6 +389,7 @@ impl Default for AppSettings {
 
             // 格式设置
             paste_with_format: true,
+            smart_paste_strip_common_indent: false,
 
             // 快捷键设置
             hotkeys_enabled: true,
@@ -219,10 +399,20 @@ impl Default for AppSettings {
             tab_right_shortcut: "ArrowRight".to_string(),
             focus_search_shortcut: "Tab".to_string(),
             hide_window_shortcut: "Escape".to_string(),
+            escape_behavior: "hide".to_string(),
             execute_item_shortcut: "Ctrl+Enter".to_string(),
             previous_group_shortcut: "Ctrl+ArrowUp".to_string(),
             next_group_shortcut: "Ctrl+ArrowDown".to_string(),
             toggle_pin_shortcut: "Ctrl+P".to_string(),
+            toggle_pin_shortcut_global: false,
+            quick_text_capture_shortcut: String::new(),
+            quick_text_capture_group: "全部".to_string(),
+            default_group_id: "全部".to_string(),
+            settings_shortcut: String::new(),
+            cycle_window_size_shortcut: String::new(),
+
+            // 转换流水线设置
+            transform_pipelines: vec![],
 
             // 数据存储设置
             custom_storage_path: None,
@@ -231,6 +421,60 @@ impl Default for AppSettings {
     }
 }
 
+// 按应用的粘贴规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppPasteRule {
+    // 匹配的进程名（不区分大小写，包含即匹配）
+    pub pattern: String,
+    // 文本格式：default | plain | formatted
+    pub format: String,
+    // 图片只粘贴图像数据，不附带文件路径
+    pub prefer_image: bool,
+    // 图片以临时文件形式粘贴（用于不接受位图的应用）
+    pub image_as_file: bool,
+    // 文件按文件列表粘贴，关闭时粘贴为路径文本
+    pub prefer_files: bool,
+    // 文件粘贴格式：hdrop | text_paths | both，为空时按 prefer_files 决定
+    pub files_mode: String,
+}
+
+impl Default for AppPasteRule {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            format: "default".to_string(),
+            prefer_image: false,
+            image_as_file: false,
+            prefer_files: true,
+            files_mode: String::new(),
+        }
+    }
+}
+
+// 翻译到指定语言的快捷键
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslationLanguageHotkey {
+    pub shortcut: String,
+    pub language: String,
+}
+
+// 粘贴前第 N 条记录的快捷键（相对于当前剪贴板内容）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelativePasteHotkey {
+    pub shortcut: String,
+    pub offset: usize,
+}
+
+// 转换流水线
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformPipeline {
+    pub name: String,
+    pub steps: Vec<String>,
+    #[serde(default)]
+    pub add_to_history: bool,
+}
+
 // 存储信息
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct StorageInfo {
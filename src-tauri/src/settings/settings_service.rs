@@ -1,4 +1,4 @@
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 
 #[cfg(not(debug_assertions))]
 use auto_launch::AutoLaunch;
@@ -17,14 +17,42 @@ impl SettingsService {
         if let Some(obj) = settings_filtered.as_object_mut() {
             obj.remove("savedWindowSize");
             obj.remove("savedWindowPosition");
+            obj.remove("last_screenshot_region");
         }
 
+        let previous_settings = state::get_global_settings();
+
+        // 保存前校验快捷键冲突
+        let mut candidate_settings = previous_settings.clone();
+        SettingsConverter::update_from_json(&mut candidate_settings, &settings_filtered);
+        crate::hotkey_manager::validate_settings_shortcuts(&candidate_settings, Some(&previous_settings))?;
+
         // 更新全局设置
         state::update_global_settings_from_json(&settings_filtered)?;
 
         // 获取更新后的设置
         let app_settings = state::get_global_settings();
 
+        // 缩略图设置变更时清空缓存，按新设置重新生成
+        if previous_settings.thumbnail_max_dimension != app_settings.thumbnail_max_dimension
+            || previous_settings.thumbnail_format != app_settings.thumbnail_format
+        {
+            if let Err(e) = crate::services::image_service::ImageService::clear_thumbnails() {
+                eprintln!("{}", e);
+            }
+        }
+
+        // 切换临时模式或历史排序后刷新历史列表
+        if previous_settings.ephemeral_mode != app_settings.ephemeral_mode
+            || previous_settings.history_order != app_settings.history_order
+        {
+            crate::database::set_ephemeral_mode(app_settings.ephemeral_mode)?;
+            let app = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                let _ = crate::commands::emit_clipboard_updated(app).await;
+            });
+        }
+
         // 应用各种设置
         Self::apply_settings(&app_settings)?;
 
@@ -34,6 +62,50 @@ impl SettingsService {
         Ok(())
     }
 
+    // 从备份恢复设置，并重新应用
+    pub fn restore_backup(app_handle: AppHandle, name: &str) -> Result<(), String> {
+        let mut restored = super::storage::SettingsStorage::load_backup(name)?;
+
+        // 窗口位置等运行时状态保持当前值
+        let current = state::get_global_settings();
+        restored.saved_window_position = current.saved_window_position;
+        restored.saved_window_size = current.saved_window_size;
+        restored.last_screenshot_region = current.last_screenshot_region;
+
+        Self::replace_all_settings(&app_handle, restored)
+    }
+
+    // 恢复默认设置（保留存储位置、窗口状态和引导状态）
+    pub fn reset_to_defaults(app_handle: AppHandle) -> Result<(), String> {
+        let current = state::get_global_settings();
+        let mut defaults = AppSettings::default();
+        defaults.use_custom_storage = current.use_custom_storage;
+        defaults.custom_storage_path = current.custom_storage_path;
+        defaults.saved_window_position = current.saved_window_position;
+        defaults.saved_window_size = current.saved_window_size;
+        defaults.onboarding_completed = current.onboarding_completed;
+
+        Self::replace_all_settings(&app_handle, defaults)
+    }
+
+    // 整体替换设置：保存、应用并重新注册快捷键
+    pub fn replace_all_settings(app_handle: &AppHandle, new_settings: AppSettings) -> Result<(), String> {
+        crate::hotkey_manager::validate_settings_shortcuts(&new_settings, None)?;
+        state::update_global_settings(new_settings)?;
+
+        let app_settings = state::get_global_settings();
+        Self::apply_settings(&app_settings)?;
+        Self::handle_special_settings(app_handle, &SettingsConverter::to_json(&app_settings), &app_settings)?;
+
+        // 按新设置重新注册全部快捷键（含截屏快捷键与数字快捷键钩子）
+        if crate::hotkey_manager::is_hotkeys_enabled() {
+            crate::hotkey_manager::disable_hotkeys();
+            crate::hotkey_manager::enable_hotkeys()?;
+        }
+
+        Ok(())
+    }
+
     // 设置开机自启动
     pub fn set_startup_launch(enabled: bool) -> Result<(), String> {
         #[cfg(debug_assertions)]
@@ -67,6 +139,8 @@ impl SettingsService {
     fn apply_settings(app_settings: &AppSettings) -> Result<(), String> {
         crate::clipboard_history::set_history_limit(app_settings.history_limit as usize);
 
+        crate::logger::apply_settings(app_settings);
+
         if let Err(e) = Self::set_startup_launch(app_settings.auto_start) {
             println!("设置开机自启动失败: {}", e);
         }
@@ -77,6 +151,13 @@ impl SettingsService {
 
         crate::clipboard_history::set_save_images(app_settings.save_images);
 
+        if app_settings.auto_clear_clipboard_seconds == 0 {
+            crate::clipboard_auto_clear::cancel_pending_clear();
+        }
+
+        crate::input_monitor::set_keyboard_hook_enabled(app_settings.enable_keyboard_hook);
+        crate::input_monitor::set_mouse_hook_enabled(app_settings.enable_mouse_hook);
+
         #[cfg(windows)]
         crate::global_state::set_number_shortcuts_enabled(app_settings.number_shortcuts);
         #[cfg(windows)]
@@ -91,6 +172,8 @@ impl SettingsService {
             copy_sound_path: app_settings.copy_sound_path.clone(),
             paste_sound_path: app_settings.paste_sound_path.clone(),
             preset: "default".to_string(),
+            quiet_start: app_settings.sound_quiet_start.clone(),
+            quiet_end: app_settings.sound_quiet_end.clone(),
         };
         crate::sound_manager::update_sound_settings(sound_settings);
 
@@ -108,9 +191,17 @@ impl SettingsService {
     // 处理特殊设置逻辑
     fn handle_special_settings(
         app_handle: &AppHandle,
-        _settings_filtered: &serde_json::Value,
+        settings_filtered: &serde_json::Value,
         app_settings: &AppSettings,
     ) -> Result<(), String> {
+        // 主题或窗口外观变更时重新应用窗口效果
+        if ["theme", "windowBackdrop", "windowCornerRadius", "windowBorderColor"]
+            .iter()
+            .any(|key| settings_filtered.get(*key).is_some())
+        {
+            crate::system_theme::apply_theme(app_handle);
+        }
+
         // 更新全局热键
         {
             // 更新主窗口快捷键
@@ -132,15 +223,38 @@ impl SettingsService {
             if let Err(e) = crate::hotkey_manager::update_preview_hotkey(&preview_shortcut) {
                 eprintln!("更新预览窗口快捷键失败: {}", e);
             }
-        }
 
-        use tauri::Emitter;
-        if let Some(main_window) = app_handle.get_webview_window("main") {
-            let _ = main_window.emit("settings-changed", SettingsConverter::to_json(app_settings));
-        }
-        if let Some(settings_window) = app_handle.get_webview_window("settings") {
-            let _ = settings_window.emit("settings-changed", SettingsConverter::to_json(app_settings));
+            // 更新常用文本采集快捷键
+            if let Err(e) = crate::hotkey_manager::update_quick_text_capture_hotkey(&app_settings.quick_text_capture_shortcut) {
+                eprintln!("更新常用文本采集快捷键失败: {}", e);
+            }
+
+            // 更新设置窗口快捷键
+            if let Err(e) = crate::hotkey_manager::update_settings_hotkey(&app_settings.settings_shortcut) {
+                eprintln!("更新设置窗口快捷键失败: {}", e);
+            }
+
+            // 更新重截上次区域快捷键
+            if let Err(e) = crate::hotkey_manager::update_last_region_screenshot_hotkey(&app_settings.screenshot_last_region_shortcut) {
+                eprintln!("更新重截上次区域快捷键失败: {}", e);
+            }
+
+            // 更新切换窗口大小快捷键
+            if let Err(e) = crate::hotkey_manager::update_cycle_window_size_hotkey(&app_settings.cycle_window_size_shortcut) {
+                eprintln!("更新切换窗口大小快捷键失败: {}", e);
+            }
+
+            // 更新翻译语言快捷键
+            if let Err(e) = crate::hotkey_manager::register_translation_language_hotkeys(&app_settings.ai_language_hotkeys) {
+                eprintln!("更新翻译语言快捷键失败: {}", e);
+            }
+
+            // 更新相对历史粘贴快捷键
+            if let Err(e) = crate::hotkey_manager::register_relative_paste_hotkeys(&app_settings.relative_paste_hotkeys) {
+                eprintln!("更新相对历史粘贴快捷键失败: {}", e);
+            }
         }
+
         // 同步托盘"剪贴板监听"菜单文案
         if let Some(item) = crate::tray::TOGGLE_MONITOR_ITEM.get() {
             let _ = item.set_text(if app_settings.clipboard_monitor { "禁用剪贴板监听" } else { "启用剪贴板监听" });
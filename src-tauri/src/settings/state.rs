@@ -1,12 +1,24 @@
 use super::model::AppSettings;
 use super::storage::SettingsStorage;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 use std::sync::{Arc, Mutex};
+use tauri::Emitter;
 
 // 全局设置状态管理
 static GLOBAL_SETTINGS: Lazy<Arc<Mutex<AppSettings>>> =
     Lazy::new(|| Arc::new(Mutex::new(SettingsStorage::load_or_default())));
 
+// 用于向所有窗口广播设置变更
+static APP_HANDLE: OnceCell<tauri::AppHandle> = OnceCell::new();
+
+// 仅记录窗口状态的设置项，变更时不广播
+const SILENT_KEYS: &[&str] = &["savedWindowPosition", "savedWindowSize", "last_screenshot_region"];
+
+// 初始化设置变更事件
+pub fn init_settings_events(app_handle: tauri::AppHandle) {
+    let _ = APP_HANDLE.set(app_handle);
+}
+
 // 获取全局设置
 pub fn get_global_settings() -> AppSettings {
     GLOBAL_SETTINGS.lock().unwrap().clone()
@@ -14,16 +26,45 @@ pub fn get_global_settings() -> AppSettings {
 
 // 更新全局设置
 pub fn update_global_settings(settings: AppSettings) -> Result<(), String> {
-    {
+    let previous = {
         let mut global_settings = GLOBAL_SETTINGS.lock().unwrap();
-        *global_settings = settings.clone();
-    }
+        std::mem::replace(&mut *global_settings, settings.clone())
+    };
 
     SettingsStorage::save(&settings)?;
 
+    broadcast_settings_changed(&previous, &settings);
+
     Ok(())
 }
 
+// 向所有窗口发送 settings-changed 事件，附带变更的设置项
+fn broadcast_settings_changed(previous: &AppSettings, current: &AppSettings) {
+    let Some(app_handle) = APP_HANDLE.get() else {
+        return;
+    };
+
+    let previous_json = previous.to_json();
+    let mut current_json = current.to_json();
+    let changed_keys: Vec<String> = match current_json.as_object() {
+        Some(map) => map
+            .iter()
+            .filter(|(key, value)| previous_json.get(key.as_str()) != Some(*value))
+            .map(|(key, _)| key.clone())
+            .collect(),
+        None => return,
+    };
+
+    if changed_keys.iter().all(|key| SILENT_KEYS.contains(&key.as_str())) {
+        return;
+    }
+
+    if let Some(map) = current_json.as_object_mut() {
+        map.insert("changedKeys".to_string(), serde_json::json!(changed_keys));
+    }
+    let _ = app_handle.emit("settings-changed", current_json);
+}
+
 // 从JSON更新全局设置
 pub(super) fn update_global_settings_from_json(json: &serde_json::Value) -> Result<(), String> {
     let mut settings = get_global_settings();
@@ -44,6 +85,13 @@ pub fn save_window_position(x: i32, y: i32) -> Result<(), String> {
     update_global_settings(settings)
 }
 
+// 保存最近一次截屏区域（物理像素）
+pub fn save_screenshot_region(x: i32, y: i32, width: u32, height: u32) -> Result<(), String> {
+    let mut settings = get_global_settings();
+    settings.last_screenshot_region = Some((x, y, width, height));
+    update_global_settings(settings)
+}
+
 // 保存窗口大小
 pub fn save_window_size(width: u32, height: u32) -> Result<(), String> {
     let mut settings = get_global_settings();
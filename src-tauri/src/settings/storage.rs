@@ -4,6 +4,13 @@ use std::fs;
 use std::path::PathBuf;
 use std::env;
 
+// 当前设置文件结构版本，新增迁移时递增
+pub const CURRENT_SETTINGS_SCHEMA_VERSION: u32 = 1;
+
+// 设置备份保留数量及最小间隔（秒），避免频繁保存时刷掉有效备份
+const MAX_SETTINGS_BACKUPS: usize = 10;
+const SETTINGS_BACKUP_MIN_INTERVAL_SECS: u64 = 300;
+
 // 设置文件存储管理
 pub struct SettingsStorage;
 
@@ -83,16 +90,83 @@ impl SettingsStorage {
         let content = fs::read_to_string(&settings_path)
             .map_err(|e| format!("读取设置文件失败: {}", e))?;
 
-        let settings: AppSettings = serde_json::from_str(&content)
+        let mut value: serde_json::Value = serde_json::from_str(&content)
             .map_err(|e| format!("解析设置文件失败: {}", e))?;
 
+        Self::migrate_settings_value(&mut value);
+        let (settings, skipped) = Self::merge_onto_defaults(&value)?;
+        if !skipped.is_empty() {
+            println!("以下设置项无效，已使用默认值: {}", skipped.join(", "));
+        }
+
         Ok(settings)
     }
 
+    // 按文件中的结构版本依次执行迁移
+    fn migrate_settings_value(value: &mut serde_json::Value) {
+        let version = value
+            .get("settings_schema_version")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as u32;
+
+        if version < CURRENT_SETTINGS_SCHEMA_VERSION {
+            println!("迁移设置文件结构: v{} -> v{}", version, CURRENT_SETTINGS_SCHEMA_VERSION);
+        }
+
+        if let Some(map) = value.as_object_mut() {
+            // v0 -> v1：已有设置文件的老用户无需再走首次引导
+            if version < 1 && !map.contains_key("onboarding_completed") {
+                map.insert("onboarding_completed".to_string(), serde_json::json!(true));
+            }
+
+            map.insert(
+                "settings_schema_version".to_string(),
+                serde_json::json!(CURRENT_SETTINGS_SCHEMA_VERSION),
+            );
+        }
+    }
+
+    // 将文件中的设置逐项合并到默认设置上，无法解析的字段保留默认值，返回被跳过的字段
+    fn merge_onto_defaults(value: &serde_json::Value) -> Result<(AppSettings, Vec<String>), String> {
+        if let Ok(settings) = serde_json::from_value::<AppSettings>(value.clone()) {
+            return Ok((settings, Vec::new()));
+        }
+
+        let file_fields = value.as_object().ok_or("设置文件格式无效")?;
+        let mut merged = serde_json::to_value(AppSettings::default())
+            .map_err(|e| format!("序列化默认设置失败: {}", e))?;
+        let mut skipped = Vec::new();
+
+        for (key, field_value) in file_fields {
+            let Some(merged_map) = merged.as_object_mut() else {
+                break;
+            };
+            let previous = merged_map.insert(key.clone(), field_value.clone());
+
+            if serde_json::from_value::<AppSettings>(merged.clone()).is_err() {
+                skipped.push(key.clone());
+                if let Some(merged_map) = merged.as_object_mut() {
+                    match previous {
+                        Some(previous) => merged_map.insert(key.clone(), previous),
+                        None => merged_map.remove(key),
+                    };
+                }
+            }
+        }
+
+        let settings = serde_json::from_value(merged)
+            .map_err(|e| format!("解析设置文件失败: {}", e))?;
+        Ok((settings, skipped))
+    }
+
     // 保存设置到文件
     pub fn save(settings: &AppSettings) -> Result<(), String> {
         let settings_path = Self::get_settings_file_path()?;
 
+        if let Err(e) = Self::backup_settings_file(&settings_path) {
+            println!("备份设置文件失败: {}", e);
+        }
+
         let content = serde_json::to_string_pretty(settings)
             .map_err(|e| format!("序列化设置失败: {}", e))?;
 
@@ -102,11 +176,118 @@ impl SettingsStorage {
         Ok(())
     }
 
-    // 加载设置，如果失败则返回默认设置并保存
+    // 获取设置备份目录
+    fn get_backup_directory() -> Result<PathBuf, String> {
+        let backup_dir = Self::get_default_data_directory()?.join("settings_backups");
+        fs::create_dir_all(&backup_dir)
+            .map_err(|e| format!("创建设置备份目录失败: {}", e))?;
+        Ok(backup_dir)
+    }
+
+    // 覆盖前备份当前设置文件，内容未变化或距上次备份过近时跳过
+    fn backup_settings_file(settings_path: &PathBuf) -> Result<(), String> {
+        if !settings_path.exists() {
+            return Ok(());
+        }
+
+        let current = fs::read(settings_path)
+            .map_err(|e| format!("读取设置文件失败: {}", e))?;
+        let backup_dir = Self::get_backup_directory()?;
+        let backups = Self::list_backups()?;
+
+        if let Some(latest) = backups.first() {
+            let latest_path = backup_dir.join(latest);
+            if fs::read(&latest_path).ok().as_deref() == Some(current.as_slice()) {
+                return Ok(());
+            }
+            let too_recent = fs::metadata(&latest_path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.elapsed().ok())
+                .map(|elapsed| elapsed.as_secs() < SETTINGS_BACKUP_MIN_INTERVAL_SECS)
+                .unwrap_or(false);
+            if too_recent {
+                return Ok(());
+            }
+        }
+
+        let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        fs::write(backup_dir.join(format!("settings-{}.json", timestamp)), &current)
+            .map_err(|e| format!("写入设置备份失败: {}", e))?;
+
+        // 只保留最近的若干个备份
+        for old_backup in Self::list_backups()?.into_iter().skip(MAX_SETTINGS_BACKUPS) {
+            let _ = fs::remove_file(backup_dir.join(old_backup));
+        }
+
+        Ok(())
+    }
+
+    // 列出设置备份（按时间从新到旧）
+    pub fn list_backups() -> Result<Vec<String>, String> {
+        let backup_dir = Self::get_backup_directory()?;
+        let mut names: Vec<String> = fs::read_dir(&backup_dir)
+            .map_err(|e| format!("读取设置备份目录失败: {}", e))?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| Self::is_backup_name(name))
+            .collect();
+
+        names.sort_by(|a, b| b.cmp(a));
+        Ok(names)
+    }
+
+    // 从备份加载设置（不写入 settings.json）
+    pub fn load_backup(name: &str) -> Result<AppSettings, String> {
+        if !Self::is_backup_name(name) {
+            return Err("无效的备份名称".to_string());
+        }
+
+        let backup_path = Self::get_backup_directory()?.join(name);
+        let content = fs::read_to_string(&backup_path)
+            .map_err(|e| format!("读取设置备份失败: {}", e))?;
+        let mut value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| format!("解析设置备份失败: {}", e))?;
+
+        Self::migrate_settings_value(&mut value);
+        let (settings, _) = Self::merge_onto_defaults(&value)?;
+        Ok(settings)
+    }
+
+    // 解析数据包等外部来源的设置：与设置文件相同的迁移和逐项合并，兼容旧版本导出的内容
+    pub fn parse_imported(content: &str) -> Result<AppSettings, String> {
+        let mut value: serde_json::Value = serde_json::from_str(content)
+            .map_err(|e| format!("解析设置文件失败: {}", e))?;
+
+        Self::migrate_settings_value(&mut value);
+        let (settings, skipped) = Self::merge_onto_defaults(&value)?;
+        if !skipped.is_empty() {
+            println!("以下导入的设置项无效，已使用默认值: {}", skipped.join(", "));
+        }
+        Ok(settings)
+    }
+
+    fn is_backup_name(name: &str) -> bool {
+        name.starts_with("settings-")
+            && name.ends_with(".json")
+            && !name.contains(['/', '\\'])
+            && !name.contains("..")
+    }
+
+    // 加载设置，如果失败则返回默认设置并保存（损坏的设置文件先另存，避免丢失）
     pub fn load_or_default() -> AppSettings {
         match Self::load() {
             Ok(settings) => settings,
-            Err(_) => {
+            Err(e) => {
+                println!("加载设置失败，使用默认设置: {}", e);
+                if let Ok(settings_path) = Self::get_settings_file_path() {
+                    if settings_path.exists() {
+                        let timestamp = chrono::Local::now().format("%Y%m%d%H%M%S");
+                        let broken_path = settings_path.with_file_name(format!("settings.broken-{}.json", timestamp));
+                        let _ = fs::rename(&settings_path, broken_path);
+                    }
+                }
+
                 let default_settings = AppSettings::default();
                 let _ = Self::save(&default_settings);
                 default_settings
@@ -145,3 +326,40 @@ impl SettingsStorage {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_onto_defaults_skips_invalid_fields() {
+        let value = serde_json::json!({
+            "history_limit": 500,
+            "theme": 42,
+            "unknown_field": true
+        });
+
+        let (settings, skipped) = SettingsStorage::merge_onto_defaults(&value).unwrap();
+        assert_eq!(settings.history_limit, 500);
+        assert_eq!(settings.theme, AppSettings::default().theme);
+        assert_eq!(skipped, vec!["theme".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_imported_accepts_older_settings() {
+        let content = r#"{ "history_limit": 300, "theme": "dark" }"#;
+
+        let settings = SettingsStorage::parse_imported(content).unwrap();
+        assert_eq!(settings.history_limit, 300);
+        assert_eq!(settings.theme, "dark");
+        assert!(settings.onboarding_completed);
+        assert_eq!(settings.settings_schema_version, CURRENT_SETTINGS_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_is_backup_name() {
+        assert!(SettingsStorage::is_backup_name("settings-20240101-120000.json"));
+        assert!(!SettingsStorage::is_backup_name("settings-../../evil.json"));
+        assert!(!SettingsStorage::is_backup_name("other.json"));
+    }
+}
@@ -17,6 +17,9 @@ pub struct SoundSettings {
     pub copy_sound_path: String,
     pub paste_sound_path: String,
     pub preset: String,
+    // 静音时段（HH:MM），为空或起止相同时不启用
+    pub quiet_start: String,
+    pub quiet_end: String,
 }
 
 impl Default for SoundSettings {
@@ -27,10 +30,46 @@ impl Default for SoundSettings {
             copy_sound_path: String::new(),
             paste_sound_path: String::new(),
             preset: "classic".to_string(),
+            quiet_start: String::new(),
+            quiet_end: String::new(),
         }
     }
 }
 
+// 解析 HH:MM 为当天的分钟数
+pub fn parse_time_of_day(value: &str) -> Option<u32> {
+    let (hour, minute) = value.trim().split_once(':')?;
+    let hour: u32 = hour.parse().ok()?;
+    let minute: u32 = minute.parse().ok()?;
+    (hour < 24 && minute < 60).then_some(hour * 60 + minute)
+}
+
+// 判断指定时刻是否在静音时段内，支持跨越午夜的时段（如 22:00-07:00）
+fn is_within_quiet_hours(start: u32, end: u32, now: u32) -> bool {
+    if start == end {
+        false
+    } else if start < end {
+        now >= start && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+// 当前时间是否处于静音时段
+fn is_quiet_now(settings: &SoundSettings) -> bool {
+    use chrono::Timelike;
+
+    let (Some(start), Some(end)) = (
+        parse_time_of_day(&settings.quiet_start),
+        parse_time_of_day(&settings.quiet_end),
+    ) else {
+        return false;
+    };
+
+    let now = chrono::Local::now();
+    is_within_quiet_hours(start, end, now.hour() * 60 + now.minute())
+}
+
 // 全局音频流句柄
 static GLOBAL_AUDIO_STREAM_HANDLE: Lazy<Arc<Mutex<Option<OutputStreamHandle>>>> = 
     Lazy::new(|| Arc::new(Mutex::new(None)));
@@ -328,7 +367,7 @@ pub fn update_sound_settings(settings: SoundSettings) {
 
 pub fn play_copy_sound() {
     if let Ok(settings) = GLOBAL_SOUND_SETTINGS.lock() {
-        if settings.enabled {
+        if settings.enabled && !is_quiet_now(&settings) {
             let sound_path = settings.copy_sound_path.clone();
             let volume = settings.volume;
 
@@ -355,7 +394,7 @@ pub fn play_copy_sound() {
 
 pub fn play_paste_sound() {
     if let Ok(settings) = GLOBAL_SOUND_SETTINGS.lock() {
-        if settings.enabled {
+        if settings.enabled && !is_quiet_now(&settings) {
             let sound_path = settings.paste_sound_path.clone();
             let volume = settings.volume;
 
@@ -388,7 +427,7 @@ pub fn play_scroll_sound() {
     }
 
     if let Ok(settings) = GLOBAL_SOUND_SETTINGS.lock() {
-        if settings.enabled {
+        if settings.enabled && !is_quiet_now(&settings) {
             let sound_path = app_settings.preview_scroll_sound_path.clone();
             let volume = settings.volume;
 
@@ -413,6 +452,26 @@ pub fn play_scroll_sound() {
     }
 }
 
+// 播放预览键盘导航音效（音量减半，路径为空时不播放）
+pub fn play_nav_sound() {
+    let sound_path = crate::settings::get_global_settings().preview_nav_sound_path;
+    if sound_path.is_empty() {
+        return;
+    }
+
+    if let Ok(settings) = GLOBAL_SOUND_SETTINGS.lock() {
+        if settings.enabled && !is_quiet_now(&settings) {
+            let volume = settings.volume * 0.5;
+
+            thread::spawn(move || {
+                if let Err(e) = SoundManager::play_sound_sync(&sound_path, volume) {
+                    eprintln!("播放导航音效失败: {}", e);
+                }
+            });
+        }
+    }
+}
+
 // 清理音效缓存
 pub fn clear_sound_cache() -> Result<(), String> {
     // 清理内存缓存
@@ -437,3 +496,27 @@ pub fn clear_sound_cache() -> Result<(), String> {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_of_day() {
+        assert_eq!(parse_time_of_day("22:00"), Some(22 * 60));
+        assert_eq!(parse_time_of_day("7:05"), Some(7 * 60 + 5));
+        assert_eq!(parse_time_of_day("24:00"), None);
+        assert_eq!(parse_time_of_day(""), None);
+    }
+
+    #[test]
+    fn test_quiet_hours_across_midnight() {
+        let (start, end) = (22 * 60, 7 * 60);
+        assert!(is_within_quiet_hours(start, end, 23 * 60));
+        assert!(is_within_quiet_hours(start, end, 3 * 60));
+        assert!(!is_within_quiet_hours(start, end, 12 * 60));
+        assert!(!is_within_quiet_hours(start, end, 7 * 60));
+        assert!(is_within_quiet_hours(9 * 60, 17 * 60, 12 * 60));
+        assert!(!is_within_quiet_hours(9 * 60, 9 * 60, 9 * 60));
+    }
+}
@@ -0,0 +1,149 @@
+// 系统主题跟随 - 读取 Windows 深色模式设置并在切换时通知前端
+
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+#[cfg(windows)]
+use windows::Win32::System::Registry::{
+    RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, HKEY_CURRENT_USER, KEY_READ,
+};
+
+// 跟随系统主题时使用的设置值
+pub const SYSTEM_THEME: &str = "system";
+
+#[cfg(windows)]
+const PERSONALIZE_PATH: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize";
+#[cfg(windows)]
+const APPS_USE_LIGHT_THEME_VALUE: &str = "AppsUseLightTheme";
+
+// 主题监听线程是否已启动
+static WATCHER_STARTED: AtomicBool = AtomicBool::new(false);
+// 上一次检测到的系统是否为深色模式
+static LAST_SYSTEM_DARK: Lazy<Mutex<Option<bool>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Clone, serde::Serialize)]
+struct ThemeChangedPayload {
+    theme: String,
+    setting: String,
+}
+
+// 读取系统是否为深色模式
+#[cfg(windows)]
+pub fn is_system_dark_mode() -> bool {
+    unsafe {
+        let path: Vec<u16> = PERSONALIZE_PATH
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+        let value_name: Vec<u16> = APPS_USE_LIGHT_THEME_VALUE
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+
+        let mut hkey: HKEY = HKEY::default();
+        let result = RegOpenKeyExW(
+            HKEY_CURRENT_USER,
+            windows::core::PCWSTR(path.as_ptr()),
+            0,
+            KEY_READ,
+            &mut hkey,
+        );
+        if result.is_err() {
+            return false;
+        }
+
+        let mut value: u32 = 1;
+        let mut value_size: u32 = std::mem::size_of::<u32>() as u32;
+        let result = RegQueryValueExW(
+            hkey,
+            windows::core::PCWSTR(value_name.as_ptr()),
+            None,
+            None,
+            Some(&mut value as *mut u32 as *mut u8),
+            Some(&mut value_size),
+        );
+        let _ = RegCloseKey(hkey);
+
+        // AppsUseLightTheme 为 0 表示深色模式
+        result.is_ok() && value == 0
+    }
+}
+
+// 非 Windows 平台默认浅色
+#[cfg(not(windows))]
+pub fn is_system_dark_mode() -> bool {
+    false
+}
+
+// 将主题设置解析为实际使用的主题
+pub fn resolve_theme(theme: &str) -> String {
+    if theme == SYSTEM_THEME {
+        if is_system_dark_mode() {
+            "dark".to_string()
+        } else {
+            "light".to_string()
+        }
+    } else {
+        theme.to_string()
+    }
+}
+
+// 按当前主题设置应用窗口效果并通知前端
+pub fn apply_theme(app: &AppHandle) {
+    let settings = crate::settings::get_global_settings();
+    let setting = settings.theme;
+    let theme = resolve_theme(&setting);
+    let dark = theme == "dark";
+
+    for window in app.webview_windows().values() {
+        crate::window_effects::set_window_dark_mode(window, dark);
+    }
+
+    // 背景材质只作用于主窗口
+    if let Some(main_window) = app.get_webview_window("main") {
+        apply_main_window_effects(&main_window);
+    }
+
+    let _ = app.emit("theme-changed", ThemeChangedPayload { theme, setting });
+}
+
+// 按当前设置应用主窗口的背景材质、圆角和边框
+pub fn apply_main_window_effects(main_window: &tauri::WebviewWindow) {
+    let settings = crate::settings::get_global_settings();
+    let dark = resolve_theme(&settings.theme) == "dark";
+
+    crate::window_effects::set_window_backdrop(main_window, &settings.window_backdrop, dark);
+    crate::window_effects::set_window_corner_and_border(
+        main_window,
+        &settings.window_corner_radius,
+        &settings.window_border_color,
+    );
+}
+
+// 启动系统主题监听
+pub fn start_system_theme_watcher(app: AppHandle) {
+    if WATCHER_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    *LAST_SYSTEM_DARK.lock().unwrap() = Some(is_system_dark_mode());
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let dark = is_system_dark_mode();
+        let changed = {
+            let mut last = LAST_SYSTEM_DARK.lock().unwrap();
+            let changed = *last != Some(dark);
+            *last = Some(dark);
+            changed
+        };
+
+        if changed && crate::settings::get_global_settings().theme == SYSTEM_THEME {
+            println!("系统主题已切换为: {}", if dark { "深色" } else { "浅色" });
+            apply_theme(&app);
+        }
+    });
+}
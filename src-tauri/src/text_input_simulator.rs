@@ -478,6 +478,18 @@ pub fn update_global_input_simulator_config(config: InputSimulatorConfig) {
     }
 }
 
+// 流式输入首个片段前等待目标输入框就绪，并重新确保窗口焦点
+pub async fn wait_for_input_target(delay_ms: u32) {
+    if delay_ms > 0 {
+        sleep(Duration::from_millis(delay_ms as u64)).await;
+    }
+
+    #[cfg(windows)]
+    if let Ok(simulator) = GLOBAL_INPUT_SIMULATOR.lock() {
+        simulator.ensure_target_window_focus();
+    }
+}
+
 // 批量流式输入文本片段
 pub async fn simulate_text_chunk_input_batched(chunk: &str) -> Result<(), String> {
     if chunk.is_empty() {
@@ -0,0 +1,106 @@
+use serde_json::json;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, WebviewWindow, WebviewWindowBuilder};
+
+const TRANSLATION_WINDOW_LABEL: &str = "translation-result";
+
+// 当前翻译的状态，窗口首次创建时页面加载完成前的事件会丢失，由页面加载后主动拉取
+#[derive(Clone, serde::Serialize)]
+pub struct TranslationWindowState {
+    pub source: String,
+    pub text: String,
+    pub status: &'static str, // idle / translating / complete / error
+    pub message: String,
+}
+
+static WINDOW_STATE: Mutex<TranslationWindowState> = Mutex::new(TranslationWindowState {
+    source: String::new(),
+    text: String::new(),
+    status: "idle",
+    message: String::new(),
+});
+
+// 获取或创建翻译结果窗口，并显示在鼠标附近
+pub fn show_translation_window(app: &AppHandle) -> Result<WebviewWindow, String> {
+    let window = match app.get_webview_window(TRANSLATION_WINDOW_LABEL) {
+        Some(window) => window,
+        None => create_translation_window(app)?,
+    };
+
+    crate::preview_window::position_preview_window(&window)?;
+    window.show().map_err(|e| format!("显示翻译结果窗口失败: {}", e))?;
+    Ok(window)
+}
+
+// 创建翻译结果窗口（不抢占焦点，避免影响目标应用）
+fn create_translation_window(app: &AppHandle) -> Result<WebviewWindow, String> {
+    WebviewWindowBuilder::new(
+        app,
+        TRANSLATION_WINDOW_LABEL,
+        tauri::WebviewUrl::App("translationResult.html".into()),
+    )
+    .title("翻译结果")
+    .inner_size(420.0, 260.0)
+    .min_inner_size(240.0, 120.0)
+    .resizable(true)
+    .decorations(false)
+    .transparent(true)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .focused(false)
+    .visible(false)
+    .shadow(false)
+    .build()
+    .map_err(|e| format!("创建翻译结果窗口失败: {}", e))
+}
+
+// 开始新的翻译，清空窗口内容
+pub fn emit_start(window: &WebviewWindow, source: &str) {
+    {
+        let mut state = WINDOW_STATE.lock().unwrap();
+        state.source = source.to_string();
+        state.text.clear();
+        state.status = "translating";
+        state.message.clear();
+    }
+    let _ = window.emit("translation-window-start", json!({ "source": source }));
+}
+
+// 同时发送累计文本，页面重复收到同一片段时也不会重复显示
+pub fn emit_chunk(window: &WebviewWindow, chunk: &str) {
+    let text = {
+        let mut state = WINDOW_STATE.lock().unwrap();
+        state.text.push_str(chunk);
+        state.text.clone()
+    };
+    let _ = window.emit("translation-window-chunk", json!({ "chunk": chunk, "text": text }));
+}
+
+pub fn emit_complete(window: &WebviewWindow) {
+    WINDOW_STATE.lock().unwrap().status = "complete";
+    let _ = window.emit("translation-window-complete", ());
+}
+
+pub fn emit_error(window: &WebviewWindow, message: &str) {
+    {
+        let mut state = WINDOW_STATE.lock().unwrap();
+        state.status = "error";
+        state.message = message.to_string();
+    }
+    let _ = window.emit("translation-window-error", json!({ "message": message }));
+}
+
+// 获取当前翻译状态（页面加载完成后调用）
+#[tauri::command]
+pub fn get_translation_window_state() -> TranslationWindowState {
+    WINDOW_STATE.lock().unwrap().clone()
+}
+
+// 关闭翻译结果窗口
+#[tauri::command]
+pub fn close_translation_window(app: AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(TRANSLATION_WINDOW_LABEL) {
+        window.hide().map_err(|e| format!("隐藏翻译结果窗口失败: {}", e))?;
+    }
+    Ok(())
+}
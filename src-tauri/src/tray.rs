@@ -12,6 +12,35 @@ use once_cell::sync::OnceCell;
 // 暴露需要动态更新文本的菜单项引用
 pub static TOGGLE_HOTKEYS_ITEM: OnceCell<tauri::menu::MenuItem<tauri::Wry>> = OnceCell::new();
 pub static TOGGLE_MONITOR_ITEM: OnceCell<tauri::menu::MenuItem<tauri::Wry>> = OnceCell::new();
+// 在后台线程延迟注册托盘，失败时重试（系统外壳尚未就绪时托盘可能注册失败）
+pub fn setup_tray_with_retry(app: AppHandle, delay: Duration, max_attempts: u32) {
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+
+        for attempt in 1..=max_attempts {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let handle = app.clone();
+            let dispatched = app.run_on_main_thread(move || {
+                let _ = tx.send(setup_tray(&handle).map_err(|e| e.to_string()));
+            });
+
+            let result = match dispatched {
+                Ok(_) => rx.recv().unwrap_or_else(|_| Err("托盘注册未执行".to_string())),
+                Err(e) => Err(e.to_string()),
+            };
+
+            match result {
+                Ok(_) => return,
+                Err(e) => println!("注册托盘失败（第 {} 次）: {}", attempt, e),
+            }
+
+            std::thread::sleep(Duration::from_secs(1));
+        }
+
+        eprintln!("托盘注册重试次数已用尽");
+    });
+}
+
 
 pub fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     // 创建托盘菜单
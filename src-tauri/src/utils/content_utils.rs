@@ -6,7 +6,12 @@ pub fn generate_files_title(files_content: &str) -> String {
     if let Some(files_json) = files_content.strip_prefix("files:") {
         if let Ok(files_data) = serde_json::from_str::<serde_json::Value>(files_json) {
             if let Some(files_array) = files_data["files"].as_array() {
-                let file_count = files_array.len();
+                // 超过数量上限的条目按原始选择的数量显示
+                let file_count = files_data["total_count"]
+                    .as_u64()
+                    .map(|count| count as usize)
+                    .unwrap_or(0)
+                    .max(files_array.len());
 
                 if file_count == 0 {
                     return "空文件列表".to_string();
@@ -30,3 +35,23 @@ pub fn generate_files_title(files_content: &str) -> String {
     // 解析失败时的回退标题
     "文件".to_string()
 }
+
+// 生成内容摘要（用于菜单、通知等简短展示）
+pub fn generate_content_summary(content: &str, max_chars: usize) -> String {
+    if content.starts_with("files:") {
+        return generate_files_title(content);
+    }
+
+    if content.starts_with("image:") || content.starts_with("data:image/") {
+        return "[图片]".to_string();
+    }
+
+    let single_line = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() > max_chars {
+        let truncated: String = single_line.chars().take(max_chars).collect();
+        format!("{}...", truncated)
+    } else {
+        single_line
+    }
+}
+
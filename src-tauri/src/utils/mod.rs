@@ -1,2 +1,5 @@
 pub mod content_utils;
 pub mod window_utils;
+pub mod text_transform;
+pub mod text_diff;
+pub mod placeholders;
@@ -0,0 +1,41 @@
+// 常用文本占位符展开
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static PLACEHOLDER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*([a-zA-Z_]+)\s*\}\}").unwrap());
+
+// 展开占位符，未识别的占位符原样保留
+pub fn expand_placeholders(content: &str, clipboard_text: &str) -> String {
+    let now = chrono::Local::now();
+
+    PLACEHOLDER_RE
+        .replace_all(content, |caps: &regex::Captures| match &caps[1] {
+            "date" => now.format("%Y-%m-%d").to_string(),
+            "time" => now.format("%H:%M:%S").to_string(),
+            "datetime" => now.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "timestamp" => now.timestamp().to_string(),
+            "clipboard" => clipboard_text.to_string(),
+            _ => caps[0].to_string(),
+        })
+        .into_owned()
+}
+
+// 是否包含占位符
+pub fn has_placeholders(content: &str) -> bool {
+    PLACEHOLDER_RE.is_match(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_placeholders() {
+        assert_eq!(expand_placeholders("a {{clipboard}} b", "X"), "a X b");
+        assert_eq!(expand_placeholders("{{ unknown }}", "X"), "{{ unknown }}");
+        assert_eq!(expand_placeholders("{{date}}", "").len(), 10);
+        assert!(!has_placeholders("plain {text}"));
+    }
+}
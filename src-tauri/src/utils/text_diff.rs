@@ -0,0 +1,57 @@
+// 文本差异比较工具
+
+use serde::Serialize;
+use similar::{ChangeTag, TextDiff};
+
+// 差异片段：连续的同类行合并为一个片段
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffChunk {
+    // added | removed | unchanged
+    pub tag: String,
+    pub text: String,
+}
+
+// 按行比较两段文本
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffChunk> {
+    let diff = TextDiff::from_lines(old, new);
+    let mut chunks: Vec<DiffChunk> = Vec::new();
+
+    for change in diff.iter_all_changes() {
+        let tag = match change.tag() {
+            ChangeTag::Insert => "added",
+            ChangeTag::Delete => "removed",
+            ChangeTag::Equal => "unchanged",
+        };
+
+        match chunks.last_mut() {
+            Some(last) if last.tag == tag => last.text.push_str(change.value()),
+            _ => chunks.push(DiffChunk {
+                tag: tag.to_string(),
+                text: change.value().to_string(),
+            }),
+        }
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let chunks = diff_lines("a\nb\nc\n", "a\nx\nc\n");
+        let tags: Vec<&str> = chunks.iter().map(|c| c.tag.as_str()).collect();
+        assert_eq!(tags, vec!["unchanged", "removed", "added", "unchanged"]);
+        assert_eq!(chunks[1].text, "b\n");
+        assert_eq!(chunks[2].text, "x\n");
+    }
+
+    #[test]
+    fn test_diff_lines_identical() {
+        let chunks = diff_lines("a\nb", "a\nb");
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].tag, "unchanged");
+    }
+}
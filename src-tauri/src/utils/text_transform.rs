@@ -0,0 +1,92 @@
+// 文本转换工具函数
+
+use base64::{engine::general_purpose, Engine as _};
+
+// 支持的转换名称
+pub const AVAILABLE_TRANSFORMS: &[&str] = &[
+    "trim",
+    "uppercase",
+    "lowercase",
+    "base64_encode",
+    "base64_decode",
+    "url_encode",
+    "url_decode",
+    "json_format",
+    "json_minify",
+    "remove_empty_lines",
+    "sort_lines",
+    "dedupe_lines",
+];
+
+// 按名称执行单个转换
+pub fn apply_transform(name: &str, input: &str) -> Result<String, String> {
+    match name {
+        "trim" => Ok(input.trim().to_string()),
+        "uppercase" => Ok(input.to_uppercase()),
+        "lowercase" => Ok(input.to_lowercase()),
+        "base64_encode" => Ok(general_purpose::STANDARD.encode(input.as_bytes())),
+        "base64_decode" => {
+            let bytes = general_purpose::STANDARD
+                .decode(input.trim())
+                .map_err(|e| format!("Base64 解码失败: {}", e))?;
+            String::from_utf8(bytes).map_err(|_| "解码结果不是有效的 UTF-8 文本".to_string())
+        }
+        "url_encode" => Ok(urlencoding::encode(input).into_owned()),
+        "url_decode" => urlencoding::decode(input)
+            .map(|s| s.into_owned())
+            .map_err(|e| format!("URL 解码失败: {}", e)),
+        "json_format" => {
+            let value: serde_json::Value =
+                serde_json::from_str(input).map_err(|e| format!("JSON 解析失败: {}", e))?;
+            serde_json::to_string_pretty(&value).map_err(|e| format!("JSON 格式化失败: {}", e))
+        }
+        "json_minify" => {
+            let value: serde_json::Value =
+                serde_json::from_str(input).map_err(|e| format!("JSON 解析失败: {}", e))?;
+            serde_json::to_string(&value).map_err(|e| format!("JSON 压缩失败: {}", e))
+        }
+        "remove_empty_lines" => Ok(input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")),
+        "sort_lines" => {
+            let mut lines: Vec<&str> = input.lines().collect();
+            lines.sort();
+            Ok(lines.join("\n"))
+        }
+        "dedupe_lines" => {
+            let mut seen = std::collections::HashSet::new();
+            Ok(input
+                .lines()
+                .filter(|line| seen.insert(*line))
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        _ => Err(format!("未知的转换: {}", name)),
+    }
+}
+
+// 依次执行多个转换，任一步骤失败时返回该步骤名称
+pub fn apply_transforms(steps: &[String], input: &str) -> Result<String, String> {
+    let mut result = input.to_string();
+    for step in steps {
+        result = apply_transform(step, &result).map_err(|e| format!("步骤 {} 失败: {}", step, e))?;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_transforms() {
+        let steps = vec!["trim".to_string(), "base64_decode".to_string(), "json_minify".to_string()];
+        let input = "  eyAiYSI6IDEgfQ==  ";
+        assert_eq!(apply_transforms(&steps, input).unwrap(), "{\"a\":1}");
+
+        let err = apply_transforms(&["json_format".to_string()], "not json").unwrap_err();
+        assert!(err.contains("json_format"));
+    }
+}
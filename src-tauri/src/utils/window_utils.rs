@@ -89,3 +89,15 @@ pub fn is_target_file_manager() -> bool {
     // 非Windows系统暂时返回false，不延迟
     false
 }
+
+// 当前是否运行在远程桌面会话中
+#[cfg(windows)]
+pub fn is_remote_session() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{GetSystemMetrics, SM_REMOTESESSION};
+    unsafe { GetSystemMetrics(SM_REMOTESESSION) != 0 }
+}
+
+#[cfg(not(windows))]
+pub fn is_remote_session() -> bool {
+    false
+}
@@ -14,3 +14,184 @@ pub fn set_window_blur(window: &WebviewWindow) {
 pub fn set_window_blur(_window: &WebviewWindow) {
     println!("窗口模糊效果仅在 Windows 平台支持");
 }
+
+// 设置窗口深色模式（影响系统绘制的边框和阴影）
+#[cfg(target_os = "windows")]
+pub fn set_window_dark_mode(window: &WebviewWindow, dark: bool) {
+    use windows::Win32::Foundation::{BOOL, HWND};
+    use windows::Win32::Graphics::Dwm::{DwmSetWindowAttribute, DWMWA_USE_IMMERSIVE_DARK_MODE};
+
+    let hwnd = match window.hwnd() {
+        Ok(hwnd) => HWND(hwnd.0 as isize),
+        Err(e) => {
+            println!("获取窗口句柄失败: {}", e);
+            return;
+        }
+    };
+
+    let value = BOOL::from(dark);
+    unsafe {
+        if let Err(e) = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_USE_IMMERSIVE_DARK_MODE,
+            &value as *const BOOL as *const _,
+            std::mem::size_of::<BOOL>() as u32,
+        ) {
+            println!("设置窗口深色模式失败: {}", e);
+        }
+    }
+}
+
+// 非 Windows 平台的空实现
+#[cfg(not(target_os = "windows"))]
+pub fn set_window_dark_mode(_window: &WebviewWindow, _dark: bool) {}
+
+// 设置窗口背景材质：blur | acrylic | mica | none
+// Windows 11 使用 DWMWA_SYSTEMBACKDROP_TYPE，不支持时回退到 window-vibrancy 的实现
+#[cfg(target_os = "windows")]
+pub fn set_window_backdrop(window: &WebviewWindow, backdrop: &str, dark: bool) {
+    use window_vibrancy::{apply_acrylic, apply_blur, clear_acrylic, clear_blur, clear_mica};
+    use windows::Win32::Graphics::Dwm::{
+        DWMSBT_MAINWINDOW, DWMSBT_NONE, DWMSBT_TRANSIENTWINDOW,
+    };
+
+    // 先清除之前的效果
+    let _ = clear_blur(window);
+    let _ = clear_acrylic(window);
+    let _ = clear_mica(window);
+
+    let tint = if dark { (0, 0, 0, 40) } else { (255, 255, 255, 10) };
+
+    let result = match backdrop {
+        "mica" => {
+            if set_system_backdrop(window, DWMSBT_MAINWINDOW) {
+                Ok(())
+            } else {
+                apply_acrylic(window, Some(tint))
+            }
+        }
+        "acrylic" => {
+            if set_system_backdrop(window, DWMSBT_TRANSIENTWINDOW) {
+                Ok(())
+            } else {
+                apply_acrylic(window, Some(tint))
+            }
+        }
+        "blur" => {
+            set_system_backdrop(window, DWMSBT_NONE);
+            apply_blur(window, Some(tint))
+        }
+        _ => {
+            set_system_backdrop(window, DWMSBT_NONE);
+            Ok(())
+        }
+    };
+
+    if let Err(e) = result {
+        println!("设置窗口背景材质失败: {}", e);
+    }
+}
+
+// 通过 DWM 设置系统背景材质（仅 Windows 11 22H2 及以上支持）
+#[cfg(target_os = "windows")]
+fn set_system_backdrop(
+    window: &WebviewWindow,
+    backdrop_type: windows::Win32::Graphics::Dwm::DWM_SYSTEMBACKDROP_TYPE,
+) -> bool {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Dwm::{DwmSetWindowAttribute, DWMWA_SYSTEMBACKDROP_TYPE};
+
+    let hwnd = match window.hwnd() {
+        Ok(hwnd) => HWND(hwnd.0 as isize),
+        Err(_) => return false,
+    };
+
+    unsafe {
+        DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_SYSTEMBACKDROP_TYPE,
+            &backdrop_type as *const _ as *const _,
+            std::mem::size_of_val(&backdrop_type) as u32,
+        )
+        .is_ok()
+    }
+}
+
+// 非 Windows 平台的空实现
+#[cfg(not(target_os = "windows"))]
+pub fn set_window_backdrop(_window: &WebviewWindow, _backdrop: &str, _dark: bool) {}
+
+// 将 "#RRGGBB" 颜色转换为 COLORREF（0x00BBGGRR）
+pub fn parse_hex_color_to_colorref(color: &str) -> Option<u32> {
+    let hex = color.trim().trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let r = (value >> 16) & 0xFF;
+    let g = (value >> 8) & 0xFF;
+    let b = value & 0xFF;
+    Some((b << 16) | (g << 8) | r)
+}
+
+// 设置窗口圆角和边框颜色
+// corner_radius: round | small | none | default；border_color: "#RRGGBB" | none | 空（系统默认）
+#[cfg(target_os = "windows")]
+pub fn set_window_corner_and_border(window: &WebviewWindow, corner_radius: &str, border_color: &str) {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Dwm::{
+        DwmSetWindowAttribute, DWMWA_BORDER_COLOR, DWMWA_WINDOW_CORNER_PREFERENCE,
+        DWMWCP_DEFAULT, DWMWCP_DONOTROUND, DWMWCP_ROUND, DWMWCP_ROUNDSMALL,
+    };
+
+    // DWM 约定的特殊颜色值
+    const DWMWA_COLOR_DEFAULT: u32 = 0xFFFFFFFF;
+    const DWMWA_COLOR_NONE: u32 = 0xFFFFFFFE;
+
+    let hwnd = match window.hwnd() {
+        Ok(hwnd) => HWND(hwnd.0 as isize),
+        Err(e) => {
+            println!("获取窗口句柄失败: {}", e);
+            return;
+        }
+    };
+
+    let corner_preference = match corner_radius {
+        "round" => DWMWCP_ROUND,
+        "small" => DWMWCP_ROUNDSMALL,
+        "none" => DWMWCP_DONOTROUND,
+        _ => DWMWCP_DEFAULT,
+    };
+
+    let color = match border_color {
+        "" => DWMWA_COLOR_DEFAULT,
+        "none" => DWMWA_COLOR_NONE,
+        color => parse_hex_color_to_colorref(color).unwrap_or(DWMWA_COLOR_DEFAULT),
+    };
+
+    unsafe {
+        // Windows 10 不支持这些属性，失败时忽略即可
+        if let Err(e) = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_WINDOW_CORNER_PREFERENCE,
+            &corner_preference as *const _ as *const _,
+            std::mem::size_of_val(&corner_preference) as u32,
+        ) {
+            println!("设置窗口圆角失败: {}", e);
+        }
+
+        if let Err(e) = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_BORDER_COLOR,
+            &color as *const u32 as *const _,
+            std::mem::size_of::<u32>() as u32,
+        ) {
+            println!("设置窗口边框颜色失败: {}", e);
+        }
+    }
+}
+
+// 非 Windows 平台的空实现
+#[cfg(not(target_os = "windows"))]
+pub fn set_window_corner_and_border(_window: &WebviewWindow, _corner_radius: &str, _border_color: &str) {}
@@ -1,10 +1,102 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use tauri::WebviewWindow;
 
 static MAIN_WINDOW_AUTO_SHOWN: AtomicBool = AtomicBool::new(false);
 
+// 主窗口当前的缩放比例（f64 的位表示，0 表示未知）
+static MAIN_WINDOW_SCALE_FACTOR: AtomicU64 = AtomicU64::new(0);
+
+// 记录主窗口初始缩放比例
+pub fn remember_scale_factor(window: &WebviewWindow) {
+    if let Ok(scale_factor) = window.scale_factor() {
+        MAIN_WINDOW_SCALE_FACTOR.store(scale_factor.to_bits(), Ordering::Relaxed);
+    }
+}
+
+// 在设置的窗口大小预设之间循环切换，返回切换后的逻辑大小
+pub fn cycle_window_size(window: &WebviewWindow) -> Result<(u32, u32), String> {
+    let settings = crate::settings::get_global_settings();
+    if settings.window_size_presets.is_empty() {
+        return Err("未设置窗口大小预设".to_string());
+    }
+
+    let scale_factor = window.scale_factor().map_err(|e| format!("获取缩放比例失败: {}", e))?;
+    let current = window
+        .inner_size()
+        .map_err(|e| format!("获取窗口大小失败: {}", e))?
+        .to_logical::<f64>(scale_factor);
+
+    // 当前大小与某个预设接近时切换到下一个，否则从第一个开始
+    let presets = &settings.window_size_presets;
+    let next_index = presets
+        .iter()
+        .position(|&(width, height)| {
+            (current.width - width as f64).abs() <= 2.0 && (current.height - height as f64).abs() <= 2.0
+        })
+        .map(|index| (index + 1) % presets.len())
+        .unwrap_or(0);
+    let (preset_width, preset_height) = presets[next_index];
+
+    // 限制在当前显示器范围内（物理像素）
+    let mut physical_width = (preset_width as f64 * scale_factor).round() as i32;
+    let mut physical_height = (preset_height as f64 * scale_factor).round() as i32;
+    if let Ok((monitor_x, monitor_y, monitor_width, monitor_height)) =
+        crate::screenshot::screen_utils::ScreenUtils::get_monitor_bounds(window)
+    {
+        physical_width = physical_width.min(monitor_width);
+        physical_height = physical_height.min(monitor_height);
+
+        // 调整大小后超出显示器时移回屏幕内
+        if let Ok(position) = window.outer_position() {
+            let x = position.x.min(monitor_x + monitor_width - physical_width).max(monitor_x);
+            let y = position.y.min(monitor_y + monitor_height - physical_height).max(monitor_y);
+            if x != position.x || y != position.y {
+                let _ = window.set_position(tauri::PhysicalPosition::new(x, y));
+            }
+        }
+    }
+
+    window
+        .set_size(tauri::PhysicalSize::new(physical_width as u32, physical_height as u32))
+        .map_err(|e| format!("设置窗口大小失败: {}", e))?;
+
+    if settings.remember_window_size {
+        crate::settings::save_window_size(physical_width as u32, physical_height as u32)?;
+    }
+
+    Ok((
+        (physical_width as f64 / scale_factor).round() as u32,
+        (physical_height as f64 / scale_factor).round() as u32,
+    ))
+}
+
+// 主窗口移动到不同 DPI 的显示器后，重新应用窗口效果并换算保存的窗口大小
+pub fn handle_scale_factor_changed(window: &WebviewWindow, scale_factor: f64) {
+    let previous = f64::from_bits(MAIN_WINDOW_SCALE_FACTOR.swap(scale_factor.to_bits(), Ordering::Relaxed));
+    println!("主窗口缩放比例变化: {} -> {}", previous, scale_factor);
+
+    crate::system_theme::apply_main_window_effects(window);
+
+    // 保存的窗口大小是物理像素，按新的缩放比例换算以保持相同的逻辑大小
+    // 保存的位置是虚拟桌面坐标，与 DPI 无关，无需换算
+    let settings = crate::settings::get_global_settings();
+    if previous > 0.0 && settings.remember_window_size {
+        if let Some((width, height)) = settings.saved_window_size {
+            let ratio = scale_factor / previous;
+            let scaled_width = (width as f64 * ratio).round() as u32;
+            let scaled_height = (height as f64 * ratio).round() as u32;
+            if let Err(e) = crate::settings::save_window_size(scaled_width, scaled_height) {
+                println!("换算窗口大小失败: {}", e);
+            }
+        }
+    }
+
+    use tauri::Emitter;
+    let _ = window.emit("window-scale-factor-changed", scale_factor);
+}
+
 // 显示窗口
 pub fn show_webview_window(window: tauri::WebviewWindow) {
     // 检查是否处于边缘吸附隐藏状态
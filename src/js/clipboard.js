@@ -110,7 +110,7 @@ function generateClipboardItemHTML(item, index) {
   const noShortcutClass = index >= 9 ? ' no-shortcut' : '';
 
   return `
-    <div class="clipboard-item${activeClass}${noShortcutClass}" draggable="true" data-index="${index}">
+    <div class="clipboard-item${activeClass}${noShortcutClass}" data-index="${index}">
       ${timestampHTML}
       ${contentHTML}
       ${numberHTML}
@@ -485,6 +485,10 @@ export function renderClipboardItems() {
   }).catch(() => { });
 }
 
+// 双击判定间隔（双击操作不是粘贴时，单击粘贴需延迟到该间隔之后）
+const DOUBLE_CLICK_DELAY = 250;
+let pendingClick = null;
+
 // 处理剪贴板项目点击事件
 function handleClipboardItemClick(index, event) {
   if (isDragging) return;
@@ -500,7 +504,59 @@ function handleClipboardItemClick(index, event) {
 
   // 处理主要的点击事件（粘贴）
   const clipboardItem = event.target.closest('.clipboard-item');
-  handleClipboardItemPaste(item, originalIndex, clipboardItem);
+  const doubleClickAction = getCurrentSettings().doubleClickAction || 'paste';
+  if (doubleClickAction === 'paste') {
+    handleClipboardItemPaste(item, originalIndex, clipboardItem);
+    return;
+  }
+
+  // 同一项目的第二次点击视为双击，执行设置的双击操作
+  if (pendingClick && pendingClick.item === item) {
+    clearTimeout(pendingClick.timer);
+    pendingClick = null;
+    handleClipboardItemDoubleClick(doubleClickAction, item, originalIndex);
+    return;
+  }
+
+  if (pendingClick) {
+    clearTimeout(pendingClick.timer);
+  }
+  pendingClick = {
+    item,
+    timer: setTimeout(() => {
+      pendingClick = null;
+      handleClipboardItemPaste(item, originalIndex, clipboardItem);
+    }, DOUBLE_CLICK_DELAY)
+  };
+}
+
+// 执行双击操作：copy_only 仅复制 / edit 编辑 / pin 切换固定
+async function handleClipboardItemDoubleClick(action, item, index) {
+  const contentType = item.content_type || 'text';
+  try {
+    switch (action) {
+      case 'copy_only':
+        await invoke('copy_clipboard_item', { id: item.id });
+        setActiveItem(index);
+        showNotification('已复制到剪贴板', 'success', 1500);
+        break;
+      case 'edit':
+        if (contentType === 'text' || contentType === 'link' || contentType === 'rich_text') {
+          await openTextEditor(item, index);
+        } else {
+          showNotification('该类型内容不支持编辑', 'info');
+        }
+        break;
+      case 'pin':
+        await invoke('set_clipboard_item_pinned', { id: item.id, pinned: !item.pinned });
+        item.pinned = !item.pinned;
+        showNotification(item.pinned ? '已固定' : '已取消固定', 'success', 1500);
+        break;
+    }
+  } catch (error) {
+    console.error('执行双击操作失败:', error);
+    showNotification('操作失败', 'error');
+  }
 }
 
 // 处理剪贴板项目右键菜单
@@ -575,7 +631,7 @@ async function clearClipboardHistory() {
     '确定要清空所有剪贴板历史记录吗？此操作不可撤销。',
     async () => {
       try {
-        await invoke('clear_clipboard_history');
+        await invoke('clear_clipboard_history', { confirmed: true });
         showNotification('已清空剪贴板历史', 'success');
         // 刷新列表
         await refreshClipboardHistory();
@@ -658,6 +714,19 @@ function showClipboardContextMenu(event, item, index) {
       onClick: async () => {
         await openTextEditor(item, index);
       }
+    }, {
+      icon: item.sensitive ? 'ti-lock-open' : 'ti-lock',
+      text: item.sensitive ? '取消敏感标记' : '标记为敏感',
+      onClick: async () => {
+        try {
+          await invoke('set_clipboard_item_sensitive', { id: item.id, sensitive: !item.sensitive });
+          item.sensitive = !item.sensitive;
+          showNotification(item.sensitive ? '已标记为敏感，粘贴后将按设置自动清除剪贴板' : '已取消敏感标记', 'success');
+        } catch (error) {
+          console.error('设置敏感标记失败:', error);
+          showNotification('设置失败', 'error');
+        }
+      }
     });
   }
 
@@ -60,6 +60,28 @@ export async function setupClipboardEventListener() {
       refreshClipboardHistory();
     });
 
+    // 监听窗口固定状态变化（全局固定快捷键触发）
+    await listen('window-pin-changed', async (event) => {
+      const { pinned } = event.payload;
+      const { setIsPinned } = await import('./config.js');
+      const { updateToolState } = await import('./toolManager.js');
+      setIsPinned(pinned);
+      updateToolState('pin-button', pinned);
+    });
+
+    // 监听其他剪贴板管理器冲突提示
+    await listen('clipboard-manager-conflict', async (event) => {
+      const { name } = event.payload;
+      const { showNotification } = await import('./notificationManager.js');
+      showNotification(`检测到 ${name} 正在运行，可能导致部分复制内容未被记录`, 'warning', 8000);
+    });
+
+    // 监听管理员权限请求被取消（UAC 被拒绝）
+    await listen('elevation-cancelled', async () => {
+      const { showNotification } = await import('./notificationManager.js');
+      showNotification('已取消管理员权限请求，需要管理员权限的功能将不可用', 'warning', 6000);
+    });
+
     // 监听常用文本刷新事件
     await listen('refreshQuickTexts', () => {
       console.log('收到常用文本刷新通知');
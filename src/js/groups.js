@@ -189,8 +189,8 @@ function renderGroupsList(container) {
       }
     });
 
-    // 拖拽事件
-    setupGroupDropEvents(groupItem, group.name);
+    // 作为列表项目的拖放目标
+    groupItem.dataset.groupName = group.name;
 
     groupItem.appendChild(iconElement);
     groupItem.appendChild(nameElement);
@@ -198,103 +198,69 @@ function renderGroupsList(container) {
   });
 }
 
-// 设置分组拖拽事件
-function setupGroupDropEvents(groupItem, groupName) {
-  groupItem.addEventListener('dragover', (e) => {
-    e.preventDefault();
-    e.stopPropagation(); // 阻止事件冒泡
-    groupItem.classList.add('drop-target');
-  });
-
-  groupItem.addEventListener('dragenter', (e) => {
-    e.preventDefault();
-    e.stopPropagation(); // 阻止事件冒泡
-    groupItem.classList.add('drop-target');
-  });
-
-  groupItem.addEventListener('dragleave', (e) => {
-    const rect = groupItem.getBoundingClientRect();
-    const x = e.clientX;
-    const y = e.clientY;
+// 查找指定位置下方可作为拖放目标的分组
+export function findGroupDropTarget(x, y) {
+  const element = document.elementFromPoint(x, y);
+  return element ? element.closest('.group-item[data-group-name]') : null;
+}
 
-    if (x < rect.left || x > rect.right || y < rect.top || y > rect.bottom) {
-      groupItem.classList.remove('drop-target');
+// 高亮当前拖放目标分组（传入 null 清除高亮）
+export function setGroupDropHighlight(target) {
+  document.querySelectorAll('.group-item.drop-target').forEach(item => {
+    if (item !== target) {
+      item.classList.remove('drop-target');
     }
   });
+  if (target) {
+    target.classList.add('drop-target');
+  }
+}
 
-  groupItem.addEventListener('drop', async (e) => {
-    e.preventDefault();
-    e.stopPropagation(); // 阻止事件冒泡
-    groupItem.classList.remove('drop-target');
-
-    try {
-      // 优先尝试获取自定义MIME类型的数据
-      let rawData = e.dataTransfer.getData('application/x-quickclipboard');
-
-      // 如果自定义类型没有数据，回退到text/plain
-      if (!rawData) {
-        rawData = e.dataTransfer.getData('text/plain');
-      }
-
-      // console.log('拖拽原始数据:', rawData);
+// 将拖拽的剪贴板项目或常用文本放入分组
+export async function dropOnGroup(groupName, data) {
+  try {
+    if (data.type === 'clipboard') {
+      try {
+        // 从剪贴板历史拖拽到分组，需要先添加到常用文本
+        await invoke('add_clipboard_to_group', {
+          id: data.id,
+          groupName: groupName
+        });
+
+        // 不切换标签页，保持在剪贴板历史列表
+        // 只触发刷新事件更新分组数据
+        window.dispatchEvent(new CustomEvent('refreshQuickTexts'));
 
-      if (!rawData) {
-        console.warn('拖拽数据为空');
-        return;
+        // 显示成功提示
+        const targetGroup = groups.find(g => g.name === groupName);
+        showNotification(`已添加到 ${targetGroup?.name || groupName}`, 'success');
+      } catch (error) {
+        console.error('添加到分组失败:', error);
+        showNotification('添加到分组失败，请重试', 'error');
       }
-
-      let data;
+    } else if (data.type === 'quicktext') {
       try {
-        data = JSON.parse(rawData);
-      } catch (parseError) {
-        console.error('拖拽数据解析失败:', parseError);
-        showNotification('拖拽操作失败，数据格式错误', 'error');
-        return;
-      }
+        // 常用文本拖拽到分组
+        await invoke('move_quick_text_to_group', {
+          id: data.id,
+          groupName: groupName
+        });
 
-      if (data.type === 'clipboard') {
-        try {
-          // 从剪贴板历史拖拽到分组，需要先添加到常用文本
-          await invoke('add_clipboard_to_group', {
-            index: data.index,
-            groupName: groupName
-          });
-
-          // 不切换标签页，保持在剪贴板历史列表
-          // 只触发刷新事件更新分组数据
-          window.dispatchEvent(new CustomEvent('refreshQuickTexts'));
-
-          // 显示成功提示
-          const targetGroup = groups.find(g => g.name === groupName);
-          showNotification(`已添加到 ${targetGroup?.name || groupName}`, 'success');
-        } catch (error) {
-          console.error('添加到分组失败:', error);
-          showNotification('添加到分组失败，请重试', 'error');
-        }
-      } else if (data.type === 'quicktext') {
-        try {
-          // 常用文本拖拽到分组
-          await invoke('move_quick_text_to_group', {
-            id: data.id,
-            groupName: groupName
-          });
-
-          // 强制刷新当前分组的显示
-          window.dispatchEvent(new CustomEvent('refreshQuickTexts'));
-
-          // 显示成功提示
-          const targetGroup = groups.find(g => g.name === groupName);
-          showNotification(`已移动到 ${targetGroup?.name || groupName}`, 'success');
-        } catch (error) {
-          console.error('移动到分组失败:', error);
-          showNotification('移动到分组失败，请重试', 'error');
-        }
+        // 强制刷新当前分组的显示
+        window.dispatchEvent(new CustomEvent('refreshQuickTexts'));
+
+        // 显示成功提示
+        const targetGroup = groups.find(g => g.name === groupName);
+        showNotification(`已移动到 ${targetGroup?.name || groupName}`, 'success');
+      } catch (error) {
+        console.error('移动到分组失败:', error);
+        showNotification('移动到分组失败，请重试', 'error');
       }
-    } catch (error) {
-      console.error('拖拽到分组失败:', error);
-      showAlertModal('错误', '移动到分组失败，请重试');
     }
-  });
+  } catch (error) {
+    console.error('拖拽到分组失败:', error);
+    showAlertModal('错误', '移动到分组失败，请重试');
+  }
 }
 
 // 更新分组选择下拉框
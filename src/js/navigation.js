@@ -35,6 +35,9 @@ let shortcutsHelpContent = null;
 let shortcutsHelpClose = null;
 let isFirstLaunch = false;
 
+// 隐藏窗口快捷键行为：hide | clear_then_hide
+let escapeBehavior = 'hide';
+
 // 初始化导航系统
 export async function initNavigation() {
   try {
@@ -44,6 +47,10 @@ export async function initNavigation() {
       handleNavigationAction(event.payload);
     });
 
+    // 读取隐藏窗口快捷键行为设置
+    await loadEscapeBehavior();
+    await listen('settings-changed', loadEscapeBehavior);
+
     // 设置点击同步
     setupClickSync();
 
@@ -571,8 +578,39 @@ export async function focusSearchBox() {
   }
 }
 
-// 隐藏窗口
+// 加载隐藏窗口快捷键行为设置
+async function loadEscapeBehavior() {
+  try {
+    const settings = await invoke('get_settings');
+    escapeBehavior = settings.escapeBehavior || 'hide';
+  } catch (error) {
+    console.error('读取隐藏窗口行为设置失败:', error);
+  }
+}
+
+// 清空当前标签页的搜索框，返回是否有内容被清空
+function clearActiveSearchBox() {
+  const activeTab = document.querySelector('.tab-content.active');
+  if (!activeTab) return false;
+
+  const searchInput = activeTab.id === 'quick-texts-tab'
+    ? document.querySelector('#quick-texts-search')
+    : document.querySelector('#search-input');
+
+  if (!searchInput || !searchInput.value) return false;
+
+  searchInput.value = '';
+  searchInput.dispatchEvent(new Event('input', { bubbles: true }));
+  searchInput.blur();
+  return true;
+}
+
+// 隐藏窗口（clear_then_hide 模式下先清空搜索框，再次按下才隐藏）
 async function hideWindow() {
+  if (escapeBehavior === 'clear_then_hide' && clearActiveSearchBox()) {
+    return;
+  }
+
   try {
     await invoke('toggle_window_visibility');
     resetNavigation();
@@ -114,7 +114,7 @@ function generateQuickTextItemHTML(text, index) {
   const groupBadgeHTML = generateGroupBadgeHTML(text);
 
   return `
-    <div class="quick-text-item" draggable="true" data-index="${index}">
+    <div class="quick-text-item" data-index="${index}">
       ${timestampHTML}
       ${groupBadgeHTML}
       ${contentHTML}
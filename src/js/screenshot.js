@@ -1,14 +1,30 @@
 import { invoke } from '@tauri-apps/api/core';
 import { showNotification } from './notificationManager.js';
 
-// 启动内置截屏窗口
+let capturePending = false;
+
+// 启动内置截屏窗口（截取尚未完成时再次调用则取消）
 export async function startBuiltinScreenshot() {
+  if (capturePending) {
+    await invoke('cancel_screenshot_capture').catch(error => {
+      console.error('取消截屏失败:', error);
+    });
+    return;
+  }
+
+  capturePending = true;
   try {
     console.log('启动内置截屏窗口...');
     await invoke('start_builtin_screenshot');
   } catch (error) {
-    console.error('启动内置截屏窗口失败:', error);
-    showNotification(`启动内置截屏窗口失败: ${error}`, 'error');
+    if (String(error).includes('截屏已取消')) {
+      showNotification('截屏已取消', 'info');
+    } else {
+      console.error('启动内置截屏窗口失败:', error);
+      showNotification(`启动内置截屏窗口失败: ${error}`, 'error');
+    }
+  } finally {
+    capturePending = false;
   }
 }
 
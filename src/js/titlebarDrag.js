@@ -40,6 +40,8 @@ function initControlsSortable() {
     ghostClass: 'sortable-ghost',
     chosenClass: 'sortable-chosen',
     dragClass: 'sortable-drag',
+    // 主窗口启用了原生文件拖放，HTML5 拖放在窗口内不可用，改用模拟拖拽
+    forceFallback: true,
     sort: true,
     
     // 过滤掉工具面板切换按钮，不允许拖拽
@@ -106,6 +108,8 @@ function initToolsPanelSortable() {
     ghostClass: 'sortable-ghost',
     chosenClass: 'sortable-chosen',
     dragClass: 'sortable-drag',
+    // 主窗口启用了原生文件拖放，HTML5 拖放在窗口内不可用，改用模拟拖拽
+    forceFallback: true,
     sort: true,
     
     onStart: function(evt) {
@@ -237,7 +237,6 @@ function createToolElement(tool, location) {
     const button = document.createElement('button');
     button.id = tool.id;
     button.className = `control-button unified-tool ${tool.type === 'toggle' ? 'toggle-button' : ''}`;
-    button.setAttribute('draggable', 'true');
     button.setAttribute('data-tool-id', tool.id);
     button.setAttribute('data-tool-type', tool.type);
     button.title = tool.title;
@@ -253,7 +252,6 @@ function createToolElement(tool, location) {
     // 工具面板：div包裹button的结构
     const wrapper = document.createElement('div');
     wrapper.className = 'tool-item';
-    wrapper.setAttribute('draggable', 'true');
     wrapper.setAttribute('data-tool-id', tool.id);
     
     const button = document.createElement('button');
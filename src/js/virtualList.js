@@ -2,6 +2,7 @@ import Clusterize from 'clusterize.js';
 import Sortable from 'sortablejs';
 import LazyLoad from 'vanilla-lazyload';
 import * as navigation from './navigation.js';
+import { findGroupDropTarget, setGroupDropHighlight, dropOnGroup } from './groups.js';
 
 /**
  * 虚拟滚动列表类
@@ -69,9 +70,12 @@ export class VirtualList {
       ghostClass: 'sortable-ghost',
       chosenClass: 'sortable-chosen',
       dragClass: 'sortable-drag',
+      // 主窗口启用了原生文件拖放，HTML5 拖放在窗口内不可用，改用模拟拖拽
+      forceFallback: true,
       onStart: (evt) => {
         this.isDragging = true;
         this.setDragData(evt);
+        this.startGroupDropTracking();
 
         if (this.sortableOptions.onStart) {
           this.sortableOptions.onStart(evt);
@@ -82,6 +86,15 @@ export class VirtualList {
           this.isDragging = false;
         }, 100);
 
+        // 拖到分组上时添加/移动到该分组，不参与排序
+        const groupName = this.finishGroupDropTracking(evt.originalEvent);
+        if (groupName && this.currentDragData) {
+          dropOnGroup(groupName, this.currentDragData);
+          this.currentDragData = null;
+          return;
+        }
+        this.currentDragData = null;
+
         if (this.sortableOptions.onEnd) {
           this.sortableOptions.onEnd(evt);
         }
@@ -152,61 +165,60 @@ export class VirtualList {
   setDragData(evt) {
     const draggedElement = evt.item;
     const index = parseInt(draggedElement.getAttribute('data-index'));
-    this.setDragDataForElement(evt.originalEvent, index);
+    this.currentDragData = this.buildDragData(index);
   }
 
-  // 为指定元素设置拖拽数据
-  setDragDataForElement(event, index) {
-    if (index >= 0 && index < this.data.length) {
-      const item = this.data[index];
-      let dragData = {};
-
-      // 根据列表类型设置不同的拖拽数据
-      if (this.scrollId === 'clipboard-list') {
-        // 剪贴板列表 - 需要找到在原始数组中的索引
-        const originalIndex = this.findOriginalIndex(item);
-        dragData = {
-          type: 'clipboard',
-          index: originalIndex,
-          content: item.content
-        };
-      } else if (this.scrollId === 'quick-texts-list') {
-        // 常用文本列表
-        dragData = {
-          type: 'quicktext',
-          id: item.id,
-          content: item.content
-        };
-      }
+  // 拖拽过程中跟踪指针下方的分组并高亮
+  startGroupDropTracking() {
+    this.stopGroupDropTracking();
+    this.groupDropMoveHandler = (e) => {
+      setGroupDropHighlight(findGroupDropTarget(e.clientX, e.clientY));
+    };
+    document.addEventListener('pointermove', this.groupDropMoveHandler, true);
+  }
 
-      // 设置拖拽数据
-      const dragDataString = JSON.stringify(dragData);
+  stopGroupDropTracking() {
+    if (this.groupDropMoveHandler) {
+      document.removeEventListener('pointermove', this.groupDropMoveHandler, true);
+      this.groupDropMoveHandler = null;
+    }
+    setGroupDropHighlight(null);
+  }
 
-      // 使用自定义MIME类型和text/plain作为备用
-      if (event && event.dataTransfer) {
-        event.dataTransfer.setData('application/x-quickclipboard', dragDataString);
-        event.dataTransfer.setData('text/plain', dragDataString);
-      }
+  // 结束跟踪，返回松开位置下方的分组名（没有则为 null）
+  finishGroupDropTracking(event) {
+    this.stopGroupDropTracking();
+    const point = event?.changedTouches?.[0] || event;
+    if (!point || point.clientX === undefined) {
+      return null;
     }
+    const target = findGroupDropTarget(point.clientX, point.clientY);
+    return target ? target.dataset.groupName : null;
   }
 
-  // 查找项目在原始数组中的索引
-  findOriginalIndex(item) {
+  // 构建拖拽数据
+  buildDragData(index) {
+    if (index < 0 || index >= this.data.length) {
+      return null;
+    }
+
+    const item = this.data[index];
     if (this.scrollId === 'clipboard-list') {
-      // 通过时间戳和内容匹配找到原始索引
-      try {
-        // 尝试从全局作用域获取clipboardHistory
-        const clipboardHistory = window.clipboardHistory || [];
-        return clipboardHistory.findIndex(originalItem =>
-          originalItem.content === item.content &&
-          originalItem.created_at === item.created_at
-        );
-      } catch (error) {
-        console.warn('无法获取原始剪贴板历史数组:', error);
-        return -1;
-      }
+      // 剪贴板列表 - 按ID定位，不受列表排列顺序影响
+      return {
+        type: 'clipboard',
+        id: item.id,
+        content: item.content
+      };
+    } else if (this.scrollId === 'quick-texts-list') {
+      // 常用文本列表
+      return {
+        type: 'quicktext',
+        id: item.id,
+        content: item.content
+      };
     }
-    return -1;
+    return null;
   }
 
   // 设置拖拽项为激活状态
@@ -333,15 +345,6 @@ export class VirtualList {
       this.lastHoverTarget = null;
     }, true);
 
-    // 使用事件委托处理拖拽开始事件
-    contentElement.addEventListener('dragstart', (e) => {
-      const item = e.target.closest('[data-index]');
-      if (item) {
-        const index = parseInt(item.getAttribute('data-index'));
-        this.setDragDataForElement(e, index);
-      }
-    });
-
     // 监听滚动事件，触发图片加载和管理滚动状态
     const scrollElement = document.getElementById(this.scrollId);
     if (scrollElement) {
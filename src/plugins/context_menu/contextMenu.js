@@ -12,6 +12,7 @@ const menuContainer = document.getElementById('menuContainer');
 
 let currentOptions = null;
 let initialMenuSize = null; // 保存主菜单的初始大小
+let activeMenuItem = null; // 键盘导航当前选中项
 
 // 应用主题
 function applyTheme(theme) {
@@ -31,12 +32,12 @@ function applyTheme(theme) {
 }
 
 // 创建子菜单
-function createSubmenu(items) {
+function createSubmenu(items, parentPath) {
     const submenu = document.createElement('div');
     submenu.className = 'submenu-container';
     
     items.forEach(item => {
-        const menuItemElement = createMenuItem(item);
+        const menuItemElement = createMenuItem(item, parentPath);
         submenu.appendChild(menuItemElement);
     });
     
@@ -96,8 +97,8 @@ async function positionSubmenu(submenu, parentItem) {
     }, 0);
 }
 
-// 创建菜单项
-function createMenuItem(item) {
+// 创建菜单项（parentPath 为父级菜单项 ID 列表）
+function createMenuItem(item, parentPath = []) {
     // 如果是分割线
     if (item.separator) {
         const separator = document.createElement('div');
@@ -112,6 +113,7 @@ function createMenuItem(item) {
         menuItem.classList.add('disabled');
     }
     menuItem.dataset.itemId = item.id;
+    const itemPath = [...parentPath, item.id];
     
     // 如果有子菜单，标记为有子菜单
     const hasChildren = item.children && item.children.length > 0;
@@ -149,6 +151,14 @@ function createMenuItem(item) {
     label.textContent = item.label;
     menuItem.appendChild(label);
 
+    // 快捷键提示
+    if (item.shortcut) {
+        const shortcut = document.createElement('span');
+        shortcut.className = 'menu-item-shortcut';
+        shortcut.textContent = item.shortcut;
+        menuItem.appendChild(shortcut);
+    }
+
     // 子菜单指示器
     if (hasChildren) {
         const indicator = document.createElement('i');
@@ -156,18 +166,19 @@ function createMenuItem(item) {
         menuItem.appendChild(indicator);
         
         // 创建子菜单容器并添加到 menuContainer（相对定位）
-        const submenu = createSubmenu(item.children);
+        const submenu = createSubmenu(item.children, itemPath);
         menuContainer.appendChild(submenu);
         
-        // 存储子菜单引用到菜单项，以便清理
+        // 存储子菜单引用到菜单项，以便清理和键盘导航
         menuItem.submenuElement = submenu;
+        submenu.parentMenuItem = menuItem;
         
         // 有子菜单的项也支持点击
         if (!item.disabled) {
             menuItem.addEventListener('click', (e) => {
                 if (!e.target.classList.contains('menu-item-submenu-indicator')) {
                     e.stopPropagation();
-                    hideMenu(item.id);
+                    hideMenu(item.id, itemPath);
                 }
             });
         }
@@ -226,7 +237,7 @@ function createMenuItem(item) {
         if (!item.disabled) {
             menuItem.addEventListener('click', (e) => {
                 e.stopPropagation();
-                hideMenu(item.id);
+                hideMenu(item.id, itemPath);
             });
         }
     }
@@ -243,6 +254,7 @@ function renderMenu(options) {
 
     // 清空容器
     menuContainer.innerHTML = '';
+    activeMenuItem = null;
 
     // 渲染所有菜单项
     options.items.forEach(item => {
@@ -293,12 +305,12 @@ if (!reloadListenerRegistered) {
 }
 
 // 隐藏菜单窗口并清理状态
-async function hideMenu(itemId = null) {
+async function hideMenu(itemId = null, itemPath = null) {
     if (isClosing) return;
     isClosing = true;
     
     try {
-        await invoke('submit_context_menu', { itemId: itemId || null });
+        await invoke('submit_context_menu', { itemId: itemId || null, itemPath: itemId ? itemPath : null });
         
         // 清理所有子菜单状态
         document.querySelectorAll('.submenu-container').forEach(submenu => {
@@ -321,10 +333,117 @@ window.addEventListener('blur', () => {
     hideMenu(null);
 });
 
-// ESC 键隐藏菜单
-document.addEventListener('keydown', (e) => {
-    if (e.key === 'Escape') {
-        hideMenu(null);
+// 获取某一级菜单中可选中的菜单项
+function getNavigableItems(container) {
+    return Array.from(container.children).filter(
+        el => el.classList.contains('menu-item') && !el.classList.contains('disabled')
+    );
+}
+
+// 当前键盘导航所在的菜单层级
+function getActiveLevel() {
+    return activeMenuItem?.parentElement || menuContainer;
+}
+
+function setActiveMenuItem(menuItem) {
+    activeMenuItem?.classList.remove('keyboard-active');
+    activeMenuItem = menuItem;
+    if (menuItem) {
+        menuItem.classList.add('keyboard-active');
+        menuItem.scrollIntoView({ block: 'nearest' });
+    }
+}
+
+// 在当前层级中上下移动
+function moveActiveItem(step) {
+    const items = getNavigableItems(getActiveLevel());
+    if (items.length === 0) return;
+
+    const index = items.indexOf(activeMenuItem);
+    const nextIndex = index === -1
+        ? (step > 0 ? 0 : items.length - 1)
+        : (index + step + items.length) % items.length;
+    setActiveMenuItem(items[nextIndex]);
+}
+
+// 打开当前项的子菜单并选中第一项
+function openActiveSubmenu() {
+    const submenu = activeMenuItem?.submenuElement;
+    if (!submenu) return false;
+
+    document.querySelectorAll('.submenu-container.show').forEach(s => {
+        if (s !== submenu && !s.contains(activeMenuItem)) {
+            s.classList.remove('show');
+        }
+    });
+    submenu.classList.add('show');
+    positionSubmenu(submenu, activeMenuItem);
+    setActiveMenuItem(getNavigableItems(submenu)[0] || activeMenuItem);
+    return true;
+}
+
+// 关闭当前所在的子菜单并回到父级菜单项
+async function closeActiveSubmenu() {
+    const level = getActiveLevel();
+    if (level === menuContainer || !level.parentMenuItem) return false;
+
+    level.classList.remove('show');
+    setActiveMenuItem(level.parentMenuItem);
+    if (!document.querySelector('.submenu-container.show')) {
+        await restoreWindowSize();
+    }
+    return true;
+}
+
+// 按首字母跳转到下一个匹配项
+function jumpToItemByLetter(letter) {
+    const items = getNavigableItems(getActiveLevel());
+    const start = items.indexOf(activeMenuItem);
+    for (let offset = 1; offset <= items.length; offset++) {
+        const candidate = items[(start + offset + items.length) % items.length];
+        const label = candidate.querySelector('.menu-item-label')?.textContent.trim().toLowerCase() || '';
+        if (label.startsWith(letter)) {
+            setActiveMenuItem(candidate);
+            return;
+        }
+    }
+}
+
+// 键盘操作：方向键导航、回车确认、Esc 关闭、首字母跳转
+document.addEventListener('keydown', async (e) => {
+    switch (e.key) {
+        case 'ArrowDown':
+            e.preventDefault();
+            moveActiveItem(1);
+            break;
+        case 'ArrowUp':
+            e.preventDefault();
+            moveActiveItem(-1);
+            break;
+        case 'ArrowRight':
+            e.preventDefault();
+            openActiveSubmenu();
+            break;
+        case 'ArrowLeft':
+            e.preventDefault();
+            await closeActiveSubmenu();
+            break;
+        case 'Enter':
+        case ' ':
+            e.preventDefault();
+            if (activeMenuItem && !openActiveSubmenu()) {
+                activeMenuItem.click();
+            }
+            break;
+        case 'Escape':
+            if (!(await closeActiveSubmenu())) {
+                hideMenu(null);
+            }
+            break;
+        default:
+            if (e.key.length === 1 && !e.ctrlKey && !e.altKey && !e.metaKey) {
+                jumpToItemByLetter(e.key.toLowerCase());
+            }
     }
 });
 
@@ -1,8 +1,17 @@
 import { invoke } from '@tauri-apps/api/core';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 document.addEventListener('contextmenu', event => event.preventDefault());
-// 显示右键菜单
+// 显示右键菜单，返回所选项 ID
 export async function showContextMenu(options) {
+    return await invokeContextMenu('show_context_menu', options);
+}
+
+// 显示右键菜单，返回所选项的完整 ID 路径（如 ['move-to-group', 'group-1']）
+export async function showContextMenuWithPath(options) {
+    return await invokeContextMenu('show_context_menu_with_path', options);
+}
+
+async function invokeContextMenu(command, options) {
     try {
         const currentWindow = getCurrentWindow();
         
@@ -19,7 +28,7 @@ export async function showContextMenu(options) {
         const screenX = logicalWindowX + options.x;
         const screenY = logicalWindowY + titleBarHeight + options.y;
         
-        const result = await invoke('show_context_menu', {
+        const result = await invoke(command, {
             items: options.items,
             x: Math.round(screenX),
             y: Math.round(screenY),
@@ -54,6 +63,7 @@ export function createMenuItem(id, label, options = {}) {
         label,
         icon: options.icon || null,
         favicon: options.favicon || null,
+        shortcut: options.shortcut || null,
         disabled: options.disabled || false,
         children: options.children || null,
         separator: false
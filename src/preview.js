@@ -110,6 +110,9 @@ document.addEventListener('DOMContentLoaded', async () => {
   // console.log('预览窗口初始化完成');
 });
 
+// 等待确认前的状态文字
+let statusBeforeConfirm = null;
+
 // 设置事件监听器
 async function setupEventListeners() {
   // 监听剪贴板历史更新
@@ -130,7 +133,7 @@ async function setupEventListeners() {
 
   // 监听滚动事件
   await listen('preview-scroll', (event) => {
-    const { direction, newIndex } = event.payload;
+    const { direction, newIndex, source } = event.payload;
     // console.log('收到滚动事件:', direction, 'newIndex:', newIndex);
 
     if (typeof newIndex === 'number') {
@@ -138,8 +141,8 @@ async function setupEventListeners() {
       currentIndex = newIndex;
       renderPreviewItems(); // 重新渲染预览项
 
-      // 播放滚动音效（只有当索引真正改变时）
-      if (oldIndex !== currentIndex && previewSettings.scrollSound) {
+      // 播放滚动音效（只有当索引真正改变时，键盘导航的音效由后端播放）
+      if (oldIndex !== currentIndex && source !== 'keyboard' && previewSettings.scrollSound) {
         // console.log('播放滚动音效 - 索引从', oldIndex, '变为', currentIndex);
         playScrollSound();
       }
@@ -150,6 +153,11 @@ async function setupEventListeners() {
 
   // 监听索引更新事件
   await listen('preview-index-changed', (event) => {
+    // 重新显示时恢复等待确认前的状态文字
+    if (statusBeforeConfirm !== null) {
+      updateStatusIndicator(statusBeforeConfirm);
+      statusBeforeConfirm = null;
+    }
     const newIndex = event.payload.index;
     updateActiveIndex(newIndex);
   });
@@ -164,6 +172,21 @@ async function setupEventListeners() {
     // 根据数据源刷新数据
     await refreshDataSource(tab, groupId);
   });
+
+  // 监听等待确认事件（确认模式下松开快捷键后）
+  await listen('preview-awaiting-confirm', () => {
+    const statusElement = document.getElementById('preview-status');
+    statusBeforeConfirm = statusElement ? statusElement.textContent : null;
+    updateStatusIndicator('按回车粘贴，Esc 取消');
+  });
+
+  // 监听滚轮不可用事件（前台为管理员权限窗口）
+  await listen('preview-scroll-unavailable', () => {
+    const statusElement = document.getElementById('preview-status');
+    const originalText = statusElement ? statusElement.textContent : '';
+    updateStatusIndicator('滚轮不可用：请使用方向键或以管理员身份运行');
+    setTimeout(() => updateStatusIndicator(originalText), 3000);
+  });
 }
 
 // 初始化数据源 - 根据主窗口当前状态
@@ -189,7 +212,7 @@ async function initializeDataSource() {
 async function refreshClipboardHistory() {
   try {
     // console.log('开始获取剪贴板历史');
-    const history = await invoke('get_clipboard_history');
+    const history = await invoke('get_clipboard_history', { newestFirst: true });
     // console.log('获取到剪贴板历史:', history);
     clipboardHistory = history || [];
     currentIndex = 0;
@@ -211,7 +234,7 @@ async function refreshDataSource(tab, groupId) {
 
     if (tab === 'clipboard') {
       // 剪贴板历史
-      const history = await invoke('get_clipboard_history');
+      const history = await invoke('get_clipboard_history', { newestFirst: true });
       clipboardHistory = history || [];
       statusText = '剪贴板历史';
     } else if (tab === 'quick-texts') {
@@ -25,6 +25,34 @@ export class ScreenshotAPI {
         }
     }
 
+    /**
+     * 记录最近一次确认的截屏区域
+     */
+    static async rememberRegion(selection) {
+        try {
+            await invoke('remember_screenshot_region', {
+                x: selection.left,
+                y: selection.top,
+                width: selection.width,
+                height: selection.height
+            });
+        } catch (error) {
+            console.error('记录截屏区域失败:', error);
+        }
+    }
+
+    /**
+     * 开启自动保存时将选区图片写入文件，返回保存路径（未开启时为 null）
+     */
+    static async autoSaveRegion(dataUrl) {
+        try {
+            return await invoke('auto_save_region_screenshot', { dataUrl });
+        } catch (error) {
+            console.error('自动保存截屏失败:', error);
+            return null;
+        }
+    }
+
     /**
      * 约束选区或工具栏位置到合适的显示器边界内
      */
@@ -100,6 +128,20 @@ export class ScreenshotAPI {
         return await invoke('cancel_scrolling_screenshot');
     }
 
+    /**
+     * 回退最近拼接的一帧并重新匹配
+     */
+    static async rescanLastScrollFrame() {
+        return await invoke('rescan_last_scroll_frame');
+    }
+
+    /**
+     * 忽略变化检测，强制拼接当前区域
+     */
+    static async addManualScrollFrame() {
+        return await invoke('add_manual_scroll_frame');
+    }
+
     static async updateScrollingPanelRect(panel) {
         return await invoke('update_scrolling_panel_rect', { panel });
     }
@@ -3,6 +3,8 @@
  * 使用 UI Automation API 实现智能元素识别
  */
 
+import { settingsManager } from '../utils/settings-manager.js';
+
 const { invoke } = window.__TAURI__.core;
 const { listen } = window.__TAURI__.event;
 
@@ -31,6 +33,11 @@ export class AutoSelectionManager {
             return;
         }
 
+        // 关闭智能选区时只允许手动拖拽
+        if (!settingsManager.getSetting('smartSelectionEnabled')) {
+            return;
+        }
+
         try {
             await this.setupListeners();
             this.isActive = true;
@@ -22,6 +22,13 @@ export class CanvasSelectionManager {
         
         this.ctx = this.canvas.getContext('2d', { alpha: true });
         this.updateCanvasSize();
+
+        // 方向键调整选区的模式：move | resize
+        this.keyboardAdjustMode = 'move';
+
+        // 遮罩和选区边框样式
+        this.maskColor = 'rgba(0, 0, 0, 0.5)';
+        this.selectionColor = '#007bff';
         
         // 选区状态
         this.selectionRect = null;
@@ -46,6 +53,10 @@ export class CanvasSelectionManager {
         this.resizeStartY = 0;
         this.resizeStartRect = null;
         this.isKeepingAspectRatio = false; 
+        // 设置中锁定的选区比例（宽/高），null 表示不锁定
+        this.aspectLock = null;
+        // 选区最小宽高
+        this.minSize = 10;
         
         // 圆角相关
         this.borderRadius = this.loadBorderRadius();
@@ -125,7 +136,7 @@ export class CanvasSelectionManager {
         if (this.autoSelectionActive && this.autoSelectionBounds) {
             this.drawMaskWithCutout(ctx, this.autoSelectionBounds, 0);
 
-            ctx.strokeStyle = '#00ff00';
+            ctx.strokeStyle = this.selectionColor;
             ctx.lineWidth = 2;
             this.drawRoundedRect(
                 ctx,
@@ -159,7 +170,7 @@ export class CanvasSelectionManager {
                 ctx.stroke();
                 ctx.shadowBlur = 0;
             } else {
-                ctx.strokeStyle = '#007bff';
+                ctx.strokeStyle = this.selectionColor;
                 ctx.lineWidth = 2;
                 this.drawRoundedRect(ctx, left + 0.5, top + 0.5, w - 1, h - 1, this.borderRadius);
                 ctx.stroke();
@@ -170,11 +181,45 @@ export class CanvasSelectionManager {
             }
         } else {
 
-            ctx.fillStyle = 'rgba(0, 0, 0, 0.5)';
+            ctx.fillStyle = this.maskColor;
             ctx.fillRect(0, 0, width, height);
         }
     }
     
+    /**
+     * 设置遮罩不透明度（0-255）和选区边框颜色
+     */
+    setAppearance(maskOpacity, selectionColor) {
+        const alpha = Math.min(Math.max(maskOpacity, 0), 255) / 255;
+        this.maskColor = `rgba(0, 0, 0, ${alpha})`;
+        this.selectionColor = selectionColor;
+        this.scheduleDraw();
+    }
+
+    /**
+     * 设置选区比例锁定（宽/高），null 表示不锁定
+     */
+    setAspectLock(ratio) {
+        this.aspectLock = ratio || null;
+    }
+
+    /**
+     * 设置选区最小宽高
+     */
+    setMinSize(size) {
+        this.minSize = Math.max(1, size || 10);
+    }
+
+    /**
+     * 当前生效的比例：按住 Shift 时临时切换锁定状态（未锁定时切换为 1:1）
+     */
+    getEffectiveAspectRatio(shiftKey) {
+        if (this.aspectLock) {
+            return shiftKey ? null : this.aspectLock;
+        }
+        return shiftKey ? 1 : null;
+    }
+
     /**
      * 绘制带镂空的遮罩层
      */
@@ -182,7 +227,7 @@ export class CanvasSelectionManager {
         const width = window.innerWidth;
         const height = window.innerHeight;
 
-        ctx.fillStyle = 'rgba(0, 0, 0, 0.5)';
+        ctx.fillStyle = this.maskColor;
         ctx.fillRect(0, 0, width, height);
 
         ctx.save();
@@ -379,16 +424,26 @@ export class CanvasSelectionManager {
     /**
      * 更新选区（选择模式）
      */
-    updateSelection(mouseX, mouseY) {
+    updateSelection(mouseX, mouseY, shiftKey = false) {
         if (!this.isSelecting) return;
         
         this.currentX = mouseX;
         this.currentY = mouseY;
         
-        const left = Math.min(this.startX, this.currentX);
-        const top = Math.min(this.startY, this.currentY);
-        const width = Math.abs(this.currentX - this.startX);
-        const height = Math.abs(this.currentY - this.startY);
+        const deltaX = this.currentX - this.startX;
+        const deltaY = this.currentY - this.startY;
+        let width = Math.abs(deltaX);
+        let height = Math.abs(deltaY);
+
+        // 锁定比例时以起点为锚点，取较大的一边扩展
+        const aspectRatio = this.getEffectiveAspectRatio(shiftKey);
+        if (aspectRatio) {
+            width = Math.max(width, height * aspectRatio);
+            height = width / aspectRatio;
+        }
+
+        const left = deltaX < 0 ? this.startX - width : this.startX;
+        const top = deltaY < 0 ? this.startY - height : this.startY;
         
         this.selectionRect = { left, top, width, height };
         this.scheduleDraw();
@@ -428,9 +483,10 @@ export class CanvasSelectionManager {
         let { left, top, width, height } = this.resizeStartRect;
 
         const isCorner = ['nw', 'ne', 'se', 'sw'].includes(this.resizeDirection);
-        const keepAspectRatio = shiftKey && isCorner;
+        const lockedRatio = this.aspectLock ? this.getEffectiveAspectRatio(shiftKey) : null;
+        const keepAspectRatio = !this.aspectLock && shiftKey && isCorner;
 
-        this.isKeepingAspectRatio = keepAspectRatio;
+        this.isKeepingAspectRatio = keepAspectRatio || !!lockedRatio;
         
         if (keepAspectRatio) {
             const aspectRatio = this.resizeStartRect.width / this.resizeStartRect.height;
@@ -502,7 +558,7 @@ export class CanvasSelectionManager {
             }
         }
 
-        const minSize = 10;
+        const minSize = this.minSize;
         if (width < minSize) {
             if (this.resizeDirection.includes('w')) left -= minSize - width;
             width = minSize;
@@ -512,6 +568,12 @@ export class CanvasSelectionManager {
             height = minSize;
         }
 
+        if (lockedRatio) {
+            ({ left, top, width, height } = this.fitRectToAspectRatio(
+                { left, top, width, height }, lockedRatio, this.resizeDirection
+            ));
+        }
+
         const constrained = boundsConstraint.constrain(left, top, width, height);
         
         this.selectionRect = {
@@ -524,6 +586,32 @@ export class CanvasSelectionManager {
         this.scheduleDraw();
     }
     
+    /**
+     * 按锁定比例修正调整后的选区：角点以对角为锚点，边以对边中点为锚点
+     */
+    fitRectToAspectRatio(rect, ratio, direction) {
+        let { left, top, width, height } = rect;
+        const right = left + width;
+        const bottom = top + height;
+
+        if (direction === 'e' || direction === 'w') {
+            const newHeight = width / ratio;
+            top += (height - newHeight) / 2;
+            height = newHeight;
+        } else if (direction === 'n' || direction === 's') {
+            const newWidth = height * ratio;
+            left += (width - newWidth) / 2;
+            width = newWidth;
+        } else {
+            width = Math.max(width, height * ratio);
+            height = width / ratio;
+            if (direction.includes('w')) left = right - width;
+            if (direction.includes('n')) top = bottom - height;
+        }
+
+        return { left, top, width, height };
+    }
+
     /**
      * 调整圆角大小
      */
@@ -582,7 +670,7 @@ export class CanvasSelectionManager {
         } else if (this.isSelecting) {
             this.isSelecting = false;
             
-            if (this.selectionRect && this.selectionRect.width > 10 && this.selectionRect.height > 10) {
+            if (this.selectionRect && this.selectionRect.width >= this.minSize && this.selectionRect.height >= this.minSize) {
                 this.showHandles();
                 return 'select-end';
             } else {
@@ -602,6 +690,37 @@ export class CanvasSelectionManager {
         this.scheduleDraw();
     }
     
+    /**
+     * 切换键盘调整模式（移动 / 调整大小）
+     */
+    toggleKeyboardAdjustMode() {
+        this.keyboardAdjustMode = this.keyboardAdjustMode === 'resize' ? 'move' : 'resize';
+        return this.keyboardAdjustMode;
+    }
+
+    /**
+     * 使用方向键微调选区：移动模式平移选区，调整大小模式移动右下边缘
+     */
+    nudgeSelection(direction, step) {
+        if (!this.selectionRect) return false;
+
+        const dx = direction === 'left' ? -step : direction === 'right' ? step : 0;
+        const dy = direction === 'up' ? -step : direction === 'down' ? step : 0;
+        let { left, top, width, height } = this.selectionRect;
+
+        if (this.keyboardAdjustMode === 'resize') {
+            width = Math.max(1, Math.min(width + dx, window.innerWidth - left));
+            height = Math.max(1, Math.min(height + dy, window.innerHeight - top));
+        } else {
+            left = Math.max(0, Math.min(left + dx, window.innerWidth - width));
+            top = Math.max(0, Math.min(top + dy, window.innerHeight - height));
+        }
+
+        this.selectionRect = { left, top, width, height };
+        this.scheduleDraw();
+        return true;
+    }
+
     /**
      * 清除选区
      */
@@ -620,6 +739,7 @@ export class CanvasSelectionManager {
         this.isResizing = false;
         this.isAdjustingRadius = false;
         this.selectionRect = null;
+        this.keyboardAdjustMode = 'move';
         this.hideHandles();
         this.scheduleDraw();
     }
@@ -130,7 +130,10 @@ export class EventManager {
         } else if (e.key === 'ArrowUp' || e.key === 'ArrowDown' || e.key === 'ArrowLeft' || e.key === 'ArrowRight') {
             e.preventDefault();
             const direction = e.key.replace('Arrow', '').toLowerCase();
-            this.onKeyDown?.(`arrow:${direction}`);
+            this.onKeyDown?.(e.shiftKey ? `arrow:${direction}:shift` : `arrow:${direction}`);
+        } else if (e.key === 'Tab') {
+            e.preventDefault();
+            this.onKeyDown?.('tab');
         } else if (e.ctrlKey || e.metaKey) {
             // 处理Ctrl/Cmd组合键
             if (e.key.toLowerCase() === 'z') {
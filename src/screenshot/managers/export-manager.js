@@ -3,6 +3,8 @@
  * 负责将截屏选区内容复制到系统剪贴板
  */
 
+import { ScreenshotAPI } from '../api/screenshot-api.js';
+
 export class ExportManager {
     constructor() {
         this.backgroundManager = null;
@@ -44,6 +46,12 @@ export class ExportManager {
             await this.writeToClipboard(blob);
             
             console.log('截屏已复制到剪贴板');
+
+            // 开启自动保存时同时写入文件（是否保存由后端按设置决定）
+            const savedPath = await ScreenshotAPI.autoSaveRegion(selectionCanvas.toDataURL('image/png'));
+            if (savedPath) {
+                console.log('截屏已保存到:', savedPath);
+            }
         } catch (error) {
             console.error('复制到剪贴板失败:', error);
             throw error;
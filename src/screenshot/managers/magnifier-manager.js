@@ -86,6 +86,10 @@ export class MagnifierManager {
                 <span class="magnifier-label">颜色:</span>
                 <span class="magnifier-color" id="magnifierColor">#000000</span>
             </div>
+            <div class="magnifier-info-row" id="magnifierSelectionRow" style="display: none;">
+                <span class="magnifier-label">选区:</span>
+                <span class="magnifier-coord" id="magnifierSelection"></span>
+            </div>
             <div class="magnifier-info-row magnifier-shortcuts">
                 <span class="magnifier-shortcut">Shift: 切换格式 | C: 复制</span>
             </div>
@@ -148,6 +152,7 @@ export class MagnifierManager {
     hide() {
         this.isVisible = false;
         this.magnifierElement.style.display = 'none';
+        this.updateSelectionInfo(null);
         
         // 取消待处理的动画帧
         if (this.rafId) {
@@ -276,6 +281,25 @@ export class MagnifierManager {
         );
     }
     
+    /**
+     * 更新选区位置和尺寸显示（传入 null 时隐藏）
+     */
+    updateSelectionInfo(rect) {
+        const row = document.getElementById('magnifierSelectionRow');
+        const element = document.getElementById('magnifierSelection');
+        if (!row || !element) return;
+
+        if (!rect) {
+            row.style.display = 'none';
+            return;
+        }
+
+        const left = Math.round(rect.left);
+        const top = Math.round(rect.top);
+        element.textContent = `${left}, ${top}  ${Math.round(rect.width)} × ${Math.round(rect.height)}`;
+        row.style.display = '';
+    }
+
     /**
      * 更新信息显示
      */
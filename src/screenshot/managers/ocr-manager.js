@@ -4,10 +4,13 @@
  */
 
 import Tesseract from 'tesseract.js';
+import { invoke } from '@tauri-apps/api/core';
+import { settingsManager } from '../utils/settings-manager.js';
 
 export class OCRManager {
     constructor() {
         this.worker = null;
+        this.language = null;
         this.isInitialized = false;
         this.isProcessing = false;
         this.supportedLanguages = ['eng', 'chi_sim']; // 英文和简体中文
@@ -17,10 +20,20 @@ export class OCRManager {
      * 初始化OCR Worker (Tesseract.js v6 API)
      */
     async initialize() {
-        if (this.isInitialized) return;
-        
+        // 由后端将设置中的语言解析为 Tesseract 语言包，不支持时直接报错
+        const language = await invoke('check_ocr_language', {
+            language: settingsManager.getSettings().ocrLanguage || ''
+        });
+        if (this.isInitialized && this.language === language) return;
+
+        // 识别语言变化后重新创建 Worker
+        if (this.worker) {
+            await this.terminate();
+        }
+
         try {
-            this.worker = await Tesseract.createWorker('chi_sim+eng', 1, {
+            this.language = language;
+            this.worker = await Tesseract.createWorker(language, 1, {
                 errorHandler: err => console.error('OCR错误:', err)
             });
             
@@ -327,6 +340,26 @@ export class OCRManager {
         }
     }
 
+    /**
+     * 显示识别失败提示，点击或数秒后关闭
+     */
+    showErrorDialog(message) {
+        document.getElementById('ocrErrorDialog')?.remove();
+
+        const dialog = document.createElement('div');
+        dialog.className = 'ocr-loading-dialog';
+        dialog.id = 'ocrErrorDialog';
+        dialog.innerHTML = `
+            <div class="ocr-loading-content">
+                <div class="ocr-loading-text ocr-error-text"></div>
+            </div>
+        `;
+        dialog.querySelector('.ocr-error-text').textContent = message;
+        dialog.addEventListener('click', () => dialog.remove());
+        document.body.appendChild(dialog);
+        setTimeout(() => dialog.remove(), 5000);
+    }
+
     /**
      * 图像预处理：增强对比度，提高OCR识别率
      */
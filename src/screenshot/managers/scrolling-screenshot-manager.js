@@ -41,6 +41,7 @@ export class ScrollingScreenshotManager {
         this.previewListener = null;
         this.completeListener = null;
         this.errorListener = null;
+        this.frameRemovedListener = null;
         
         this.lastUpdatePanelRectTime = 0;
         
@@ -71,6 +72,12 @@ export class ScrollingScreenshotManager {
                     <button id="scrollingResumeBtn" class="scrolling-control-btn" data-tooltip="继续" style="display: none;">
                         <i class="ti ti-player-play"></i>
                     </button>
+                    <button id="scrollingRescanBtn" class="scrolling-control-btn" data-tooltip="回退上一帧">
+                        <i class="ti ti-arrow-back-up"></i>
+                    </button>
+                    <button id="scrollingManualBtn" class="scrolling-control-btn" data-tooltip="手动拼接">
+                        <i class="ti ti-plus"></i>
+                    </button>
                     <button id="scrollingStopBtn" class="scrolling-control-btn" data-tooltip="完成">
                         <i class="ti ti-check"></i>
                     </button>
@@ -114,6 +121,8 @@ export class ScrollingScreenshotManager {
         const resumeBtn = document.getElementById('scrollingResumeBtn');
         const stopBtn = document.getElementById('scrollingStopBtn');
         const cancelBtn = document.getElementById('scrollingCancelBtn');
+        const rescanBtn = document.getElementById('scrollingRescanBtn');
+        const manualBtn = document.getElementById('scrollingManualBtn');
         
         if (startBtn) {
             startBtn.addEventListener('click', () => this.start());
@@ -130,6 +139,12 @@ export class ScrollingScreenshotManager {
         if (cancelBtn) {
             cancelBtn.addEventListener('click', () => this.cancel());
         }
+        if (rescanBtn) {
+            rescanBtn.addEventListener('click', () => this.rescanLastFrame());
+        }
+        if (manualBtn) {
+            manualBtn.addEventListener('click', () => this.addManualFrame());
+        }
     }
 
     /**
@@ -278,6 +293,32 @@ export class ScrollingScreenshotManager {
         }
     }
 
+    /**
+     * 回退最近拼接的一帧，由截取线程重新匹配
+     */
+    async rescanLastFrame() {
+        if (!this.isActive) return;
+
+        try {
+            await ScreenshotAPI.rescanLastScrollFrame();
+        } catch (error) {
+            this.updateStatus('回退失败: ' + error);
+        }
+    }
+
+    /**
+     * 忽略变化检测，强制拼接当前区域
+     */
+    async addManualFrame() {
+        if (!this.isActive) return;
+
+        try {
+            await ScreenshotAPI.addManualScrollFrame();
+        } catch (error) {
+            this.updateStatus('拼接失败: ' + error);
+        }
+    }
+
     /**
      * 取消长截屏
      */
@@ -371,6 +412,10 @@ export class ScrollingScreenshotManager {
         this.errorListener = await window.__TAURI__.event.listen('scrolling-screenshot-error', (event) => {
             this.updateStatus('错误: ' + event.payload);
         });
+        
+        this.frameRemovedListener = await window.__TAURI__.event.listen('scrolling-screenshot-frame-removed', (event) => {
+            this.removeLastPreviewFrame(event.payload);
+        });
     }
 
     /**
@@ -389,6 +434,10 @@ export class ScrollingScreenshotManager {
             this.errorListener();
             this.errorListener = null;
         }
+        if (this.frameRemovedListener) {
+            this.frameRemovedListener();
+            this.frameRemovedListener = null;
+        }
     }
 
     /**
@@ -407,6 +456,22 @@ export class ScrollingScreenshotManager {
         this.processRenderQueue();
     }
 
+    /**
+     * 回退帧后裁剪预览（排在已收到的帧之后处理）
+     */
+    removeLastPreviewFrame(payload) {
+        if (!payload || !this.previewCanvas) return;
+
+        const { removed_height, total_height } = payload;
+
+        this.updateInfo(total_height);
+        this.renderQueue.push({ removed_height, total_height });
+        
+        if (this.isRendering) return;
+        
+        this.processRenderQueue();
+    }
+
     /**
      * 处理渲染队列
      */
@@ -419,7 +484,11 @@ export class ScrollingScreenshotManager {
             const task = this.renderQueue.shift();
             
             try {
-                await this.renderFrame(task);
+                if (task.removed_height !== undefined) {
+                    this.cropPreview(task);
+                } else {
+                    await this.renderFrame(task);
+                }
             } catch (error) {
                 // 跳过失败的帧
             }
@@ -496,6 +565,36 @@ export class ScrollingScreenshotManager {
         });
     }
 
+    /**
+     * 按回退后的总高度等比裁掉预览底部
+     */
+    cropPreview({ removed_height, total_height }) {
+        const previousTotal = total_height + removed_height;
+        if (previousTotal <= 0 || this.currentCanvasHeight === 0) return;
+
+        const newCanvasHeight = Math.round(this.currentCanvasHeight * total_height / previousTotal);
+
+        const tempCanvas = document.createElement('canvas');
+        tempCanvas.width = 216;
+        tempCanvas.height = this.currentCanvasHeight;
+        const tempCtx = tempCanvas.getContext('2d', { willReadFrequently: false });
+        tempCtx.drawImage(this.offscreenCanvas, 0, 0);
+
+        this.offscreenCanvas.height = newCanvasHeight;
+        if (newCanvasHeight > 0) {
+            this.offscreenContext.drawImage(tempCanvas, 0, 0);
+        }
+
+        this.previewCanvas.width = 216;
+        this.previewCanvas.height = newCanvasHeight;
+        if (newCanvasHeight > 0) {
+            this.previewContext.drawImage(this.offscreenCanvas, 0, 0);
+        }
+
+        this.currentCanvasHeight = newCanvasHeight;
+        this.updatePanelRect();
+    }
+
     /**
      * 处理长截屏完成
      */
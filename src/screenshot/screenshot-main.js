@@ -124,6 +124,9 @@ export class ScreenshotController {
         // 放大镜管理器设置
         const settings = settingsManager.getSettings();
         this.magnifierManager.setColorIncludeFormat(settings.colorIncludeFormat);
+        this.selectionManager.setAppearance(settings.maskOpacity, settings.selectionColor);
+        this.selectionManager.setAspectLock(settings.aspectLock);
+        this.selectionManager.setMinSize(settings.minSize);
         this.magnifierManager.setOnColorCopied(() => this.cancelScreenshot());
     }
 
@@ -317,7 +320,13 @@ export class ScreenshotController {
             if (this.magnifierManager && settings.magnifierEnabled) {
                 this.magnifierManager.show();
             }
-        } else if (action === 'move' || action === 'resize') {
+        } else if (action === 'resize') {
+            this.hideAllToolbars();
+            const settings = settingsManager.getSettings();
+            if (this.magnifierManager && settings.magnifierEnabled) {
+                this.magnifierManager.show();
+            }
+        } else if (action === 'move') {
             this.hideAllToolbars();
         }
     }
@@ -330,8 +339,9 @@ export class ScreenshotController {
         
         // 处理选择状态
         if (this.selectionManager.isSelectingState) {
-            this.selectionManager.updateSelection(x, y);
+            this.selectionManager.updateSelection(x, y, shiftKey);
             this.hideAllToolbars();
+            this.updateMagnifierSelectionInfo();
         } else if (this.selectionManager.isMovingState) {
             this.selectionManager.moveSelection(x, y);
             this.hideAllToolbars();
@@ -342,6 +352,7 @@ export class ScreenshotController {
         } else if (this.selectionManager.isResizingState) {
             this.selectionManager.resizeSelection(x, y, shiftKey);
             this.hideAllToolbars();
+            this.updateMagnifierSelectionInfo();
             const selection = this.selectionManager.getSelection();
             const borderRadius = this.selectionManager.getBorderRadius();
             if (selection) {
@@ -406,12 +417,24 @@ export class ScreenshotController {
     /* ==================== 键盘处理 ==================== */
 
     handleKeyDown(key) {
-        // 处理方向键
+        // 处理方向键：已有选区时微调选区，否则移动鼠标
         if (key.startsWith('arrow:')) {
-            const direction = key.split(':')[1];
+            const [, direction, modifier] = key.split(':');
+            if (this.canAdjustSelectionByKeyboard()) {
+                this.nudgeSelection(direction, modifier === 'shift' ? 10 : 1);
+                return;
+            }
             this.arrowKeyController.handleKeyDown(direction);
             return;
         }
+
+        // Tab 切换移动 / 调整大小模式
+        if (key === 'tab') {
+            if (this.canAdjustSelectionByKeyboard()) {
+                this.selectionManager.toggleKeyboardAdjustMode();
+            }
+            return;
+        }
         
         // 处理其他快捷键
         this.keyboardHandler.handleKeyDown(key);
@@ -423,6 +446,41 @@ export class ScreenshotController {
         }
     }
 
+    /**
+     * 在放大镜中显示当前选区的位置和尺寸
+     */
+    updateMagnifierSelectionInfo() {
+        if (!this.magnifierManager) return;
+        const settings = settingsManager.getSettings();
+        const selection = settings.showHints ? this.selectionManager.getSelection() : null;
+        this.magnifierManager.updateSelectionInfo(selection);
+    }
+
+    /**
+     * 是否可以使用方向键调整选区
+     */
+    canAdjustSelectionByKeyboard() {
+        return !!this.selectionManager.getSelection()
+            && !this.selectionManager.longScreenshotMode
+            && this.canUseKeyboardShortcuts();
+    }
+
+    /**
+     * 方向键微调选区并刷新工具栏和信息面板
+     */
+    nudgeSelection(direction, step) {
+        if (!this.selectionManager.nudgeSelection(direction, step)) return;
+
+        const selection = this.selectionManager.getSelection();
+        const mainToolbarPosition = this.toolbarManager.show(selection);
+        this.selectionInfoPanel.show(selection, this.selectionManager.getBorderRadius());
+
+        const currentTool = this.toolbarManager.getCurrentTool();
+        if (currentTool && mainToolbarPosition) {
+            this.showSubToolbarForTool(currentTool, selection, mainToolbarPosition);
+        }
+    }
+
     /**
      * 检查是否可以使用键盘快捷键
      */
@@ -663,6 +721,9 @@ export class ScreenshotController {
             
             const borderRadius = this.selectionManager.getBorderRadius();
             await this.exportManager.copySelectionToClipboard(selection, borderRadius);
+
+            // 记录选区，供重复截取上次区域使用
+            await ScreenshotAPI.rememberRegion(selection);
             
             this.clearAllContent();
             await ScreenshotAPI.hideWindow();
@@ -787,6 +848,8 @@ export class ScreenshotController {
             if (this.magnifierManager) {
                 this.magnifierManager.setColorIncludeFormat(settings.colorIncludeFormat);
             }
+            this.selectionManager.setAspectLock(settings.aspectLock);
+            this.selectionManager.setMinSize(settings.minSize);
 
             if (!this.backgroundManager.canvas) {
                 this.backgroundManager.init();
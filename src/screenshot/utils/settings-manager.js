@@ -10,7 +10,16 @@ export class SettingsManager {
         this.settings = {
             magnifierEnabled: true,
             hintsEnabled: true,
-            colorIncludeFormat: true
+            showHints: true,
+            colorIncludeFormat: true,
+            maskOpacity: 128,
+            selectionColor: '#007bff',
+            // 选区比例锁定（宽/高），null 表示不锁定
+            aspectLock: null,
+            minSize: 10,
+            smartSelectionEnabled: true,
+            // OCR 识别语言（BCP-47），为空时识别简体中文和英文
+            ocrLanguage: ''
         };
         
         this.listeners = new Map();
@@ -26,7 +35,20 @@ export class SettingsManager {
             // 更新本地设置
             this.settings.magnifierEnabled = settings.screenshot_magnifier_enabled !== false;
             this.settings.hintsEnabled = settings.screenshot_hints_enabled !== false;
+            this.settings.showHints = settings.screenshot_show_hints !== false;
             this.settings.colorIncludeFormat = settings.screenshot_color_include_format !== false;
+            if (typeof settings.screenshot_mask_opacity === 'number') {
+                this.settings.maskOpacity = settings.screenshot_mask_opacity;
+            }
+            if (settings.screenshot_selection_color) {
+                this.settings.selectionColor = settings.screenshot_selection_color;
+            }
+            this.settings.aspectLock = this.parseAspectLock(settings.screenshot_aspect_lock);
+            if (typeof settings.screenshot_min_size === 'number' && settings.screenshot_min_size > 0) {
+                this.settings.minSize = settings.screenshot_min_size;
+            }
+            this.settings.smartSelectionEnabled = settings.screenshot_smart_selection_enabled !== false;
+            this.settings.ocrLanguage = settings.ocrLanguage || '';
             
             // 通知监听器
             this.notifyListeners('all', this.settings);
@@ -39,6 +61,16 @@ export class SettingsManager {
         }
     }
 
+    /**
+     * 解析比例设置，如 "16:9" -> 16/9
+     */
+    parseAspectLock(value) {
+        const match = /^(\d+):(\d+)$/.exec(value || '');
+        if (!match) return null;
+        const ratio = Number(match[1]) / Number(match[2]);
+        return ratio > 0 && Number.isFinite(ratio) ? ratio : null;
+    }
+
     /**
      * 获取设置
      */
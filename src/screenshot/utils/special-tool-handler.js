@@ -75,6 +75,7 @@ export class SpecialToolHandler {
         } catch (error) {
             console.error('OCR识别失败:', error);
             this.ocrManager.hideLoadingDialog();
+            this.ocrManager.showErrorDialog(`OCR识别失败: ${error?.message || error}`);
         }
     }
 
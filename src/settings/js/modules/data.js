@@ -232,7 +232,7 @@ export class DataManager {
 
         try {
             showLoading('正在恢复默认配置...');
-            await invoke('reset_settings_to_default');
+            await invoke('reset_settings_to_defaults');
 
             localStorage.clear();
 
@@ -19,6 +19,7 @@ export class ShortcutManager {
         this.bindToggleShortcut();
         this.bindPreviewShortcut();
         this.bindScreenshotShortcut();
+        this.bindLastRegionScreenshotShortcut();
         this.bindClipboardShortcuts();
     }
 
@@ -275,6 +276,32 @@ export class ShortcutManager {
         }
     }
 
+    /**
+     * 绑定重截上次区域快捷键
+     */
+    bindLastRegionScreenshotShortcut() {
+        const input = document.getElementById('screenshot-last-region-shortcut');
+        const clearBtn = document.getElementById('clear-screenshot-last-region-shortcut');
+
+        if (input) {
+            this._setupShortcutInput(input, 'screenshot_last_region_shortcut', async (recording) => {
+                try {
+                    await invoke('set_shortcut_recording', { recording });
+                } catch (err) {
+                    console.error('设置快捷键录制状态失败:', err);
+                }
+            });
+        }
+
+        if (clearBtn) {
+            clearBtn.addEventListener('click', () => {
+                input.value = '';
+                this.settings.screenshot_last_region_shortcut = '';
+                this.saveSettings();
+            });
+        }
+    }
+
     /**
      * 绑定剪贴板窗口快捷键
      */
@@ -327,7 +354,7 @@ export class ShortcutManager {
             }
         });
 
-        input.addEventListener('keydown', (e) => {
+        input.addEventListener('keydown', async (e) => {
             if (!isRecording) return;
 
             e.preventDefault();
@@ -348,9 +375,16 @@ export class ShortcutManager {
             const shortcut = [...modifiers, keyName].join('+');
             
             input.value = shortcut;
-            this.settings[settingKey] = shortcut;
-
             stopRecording();
+
+            // 与其他快捷键冲突时恢复原值
+            const conflict = await this._checkConflict(shortcut, settingKey);
+            if (conflict) {
+                input.value = oldValue || '';
+                showNotification(`快捷键 ${shortcut} 已用于「${conflict}」`, 'error');
+                return;
+            }
+            this.settings[settingKey] = shortcut;
             
             // 触发快捷键更改回调
             if (onShortcutChange) {
@@ -389,6 +423,18 @@ export class ShortcutManager {
         };
     }
 
+    /**
+     * 检查快捷键是否与全局快捷键或窗口快捷键冲突，返回冲突项用途
+     */
+    async _checkConflict(shortcut, settingKey) {
+        try {
+            return await invoke('check_shortcut_conflict', { shortcut, settingKey });
+        } catch (err) {
+            console.error('检查快捷键冲突失败:', err);
+            return null;
+        }
+    }
+
     /**
      * 格式化按键名称
      */
@@ -35,6 +35,9 @@ const defaultSettings = {
   runAsAdmin: false,
   showStartupNotification: true,
   historyLimit: 100,
+  historyOrder: 'newest_first',
+  logLevel: 'info',
+  logToFile: false,
   theme: 'light',
   opacity: 0.9,
   backgroundImagePath: '',
@@ -51,6 +54,7 @@ const defaultSettings = {
   pasteSoundPath: '',
   screenshot_enabled: true,
   screenshot_shortcut: 'Ctrl+Shift+A',
+  screenshot_last_region_shortcut: '',
   screenshot_quality: 85,
   screenshot_auto_save: false,
   screenshot_show_hints: true,
@@ -88,6 +92,7 @@ const defaultSettings = {
   aiOutputMode: 'stream',
   mouseMiddleButtonEnabled: true,
   mouseMiddleButtonModifier: 'None',
+  doubleClickAction: 'paste',
   windowPositionMode: 'smart',
   rememberWindowSize: false,
   savedWindowPosition: null,
@@ -196,6 +201,9 @@ async function initializeUI() {
   setInputValue('run-as-admin', settings.runAsAdmin);
   setInputValue('show-startup-notification', settings.showStartupNotification);
   setInputValue('history-limit', settings.historyLimit);
+  setInputValue('history-order', settings.historyOrder || 'newest_first');
+  setInputValue('log-level', settings.logLevel || 'info');
+  setInputValue('log-to-file', settings.logToFile);
   setInputValue('toggle-shortcut', settings.toggleShortcut || 'Alt+V');
   setInputValue('number-shortcuts', settings.numberShortcuts);
   setInputValue('number-shortcuts-modifier', settings.numberShortcutsModifier || 'Ctrl');
@@ -233,6 +241,7 @@ async function initializeUI() {
   // 截屏设置
   setInputValue('screenshot-enabled', settings.screenshot_enabled);
   setInputValue('screenshot-shortcut', settings.screenshot_shortcut);
+  setInputValue('screenshot-last-region-shortcut', settings.screenshot_last_region_shortcut);
   setInputValue('screenshot-quality', settings.screenshot_quality);
   setInputValue('screenshot-auto-save', settings.screenshot_auto_save);
   setInputValue('screenshot-show-hints', settings.screenshot_show_hints);
@@ -260,6 +269,7 @@ async function initializeUI() {
   // 鼠标设置
   setInputValue('mouse-middle-button-enabled', settings.mouseMiddleButtonEnabled);
   setInputValue('mouse-middle-button-modifier', settings.mouseMiddleButtonModifier || 'None');
+  setInputValue('double-click-action', settings.doubleClickAction || 'paste');
 
   // 动画和行为设置
   setInputValue('clipboard-animation-enabled', settings.clipboardAnimationEnabled);
@@ -466,7 +476,8 @@ async function closeSettingsWindow() {
 
 function bindBasicSettingEvents() {
   const settingInputs = [
-    'auto-start', 'start-hidden', 'show-startup-notification', 'history-limit',
+    'auto-start', 'start-hidden', 'show-startup-notification', 'history-limit', 'history-order',
+    'log-level', 'log-to-file',
     'number-shortcuts', 'number-shortcuts-modifier', 'clipboard-monitor',
     'ignore-duplicates', 'save-images', 'show-image-preview',
     'sound-enabled', 'copy-sound-path', 'paste-sound-path',
@@ -477,7 +488,7 @@ function bindBasicSettingEvents() {
     'screenshot-magnifier-enabled', 'screenshot-hints-enabled', 'screenshot-color-include-format',
     'ai-target-language', 'ai-translate-on-copy', 'ai-translate-on-paste',
     'ai-translation-prompt', 'ai-input-speed', 'ai-newline-mode', 'ai-output-mode',
-    'mouse-middle-button-enabled', 'mouse-middle-button-modifier', 'clipboard-animation-enabled',
+    'mouse-middle-button-enabled', 'mouse-middle-button-modifier', 'double-click-action', 'clipboard-animation-enabled',
     'window-position-mode', 'remember-window-size', 'auto-scroll-to-top-on-show',
     'title-bar-position', 'edge-hide-enabled', 'edge-hide-offset', 'auto-focus-search', 'sidebar-hover-delay',
     'image-data-priority-apps'
@@ -884,10 +895,13 @@ function bindAdminRunEvents() {
             if (shouldRestart) {
               try {
                 showNotification('正在重启...', 'info');
-                await invoke('restart_as_admin');
+                await invoke('request_elevation');
               } catch (error) {
                 console.error('重启为管理员失败:', error);
-                showNotification('重启失败: ' + error, 'error');
+                // UAC 被拒绝时由 elevation-cancelled 事件提示
+                if (!String(error).includes('已取消')) {
+                  showNotification('重启失败: ' + error, 'error');
+                }
               }
             } else {
               showNotification('设置已保存，下次启动时将以管理员权限运行', 'success');
@@ -960,6 +974,14 @@ function switchSection(sectionName) {
 
 async function setupSettingsSync() {
   try {
+    await listen('before-elevation', async () => {
+      await saveSettings();
+    });
+
+    await listen('elevation-cancelled', () => {
+      showNotification('已取消管理员权限请求，当前仍以普通权限运行', 'warning');
+    });
+
     await listen('settings-changed', (event) => {
       const newSettings = event?.payload || {};
       if (typeof newSettings.clipboardMonitor === 'boolean') {
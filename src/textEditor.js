@@ -27,6 +27,8 @@ let charCount;
 let lineCount;
 let wordWrapBtn;
 let resetBtn;
+let previewBtn;
+let expansionPreview;
 let cancelBtn;
 let saveBtn;
 let loadingOverlay;
@@ -160,6 +162,8 @@ function initDOMReferences() {
   lineCount = document.getElementById('line-count');
   wordWrapBtn = document.getElementById('word-wrap-btn');
   resetBtn = document.getElementById('reset-btn');
+  previewBtn = document.getElementById('preview-btn');
+  expansionPreview = document.getElementById('expansion-preview');
   cancelBtn = document.getElementById('cancel-btn');
   saveBtn = document.getElementById('save-btn');
   loadingOverlay = document.getElementById('loading-overlay');
@@ -185,6 +189,7 @@ function setupEventListeners() {
   // 工具栏按钮
   wordWrapBtn.addEventListener('click', toggleWordWrap);
   resetBtn.addEventListener('click', resetToOriginal);
+  previewBtn.addEventListener('click', toggleExpansionPreview);
 
   // 操作按钮
   cancelBtn.addEventListener('click', handleCancel);
@@ -214,6 +219,27 @@ async function setupDataListener() {
 
 // 设置窗口控制
 function setupWindowControls() {
+  // 置顶按钮
+  const pinBtn = document.getElementById('pin-btn');
+  if (pinBtn) {
+    const updatePinButton = (topmost) => {
+      pinBtn.classList.toggle('active', topmost);
+      pinBtn.title = topmost ? '取消置顶' : '窗口置顶';
+    };
+
+    invoke('get_editor_topmost').then(updatePinButton).catch(() => {});
+
+    pinBtn.addEventListener('click', async () => {
+      try {
+        const topmost = !pinBtn.classList.contains('active');
+        await invoke('set_editor_topmost', { topmost });
+        updatePinButton(topmost);
+      } catch (error) {
+        console.error('切换窗口置顶失败:', error);
+      }
+    });
+  }
+
   // 最小化按钮
   const minimizeBtn = document.getElementById('minimize-btn');
   if (minimizeBtn) {
@@ -277,6 +303,8 @@ async function loadEditorData(data) {
     // 加载分组列表
     await loadGroups();
 
+    previewBtn.style.display = 'flex';
+
     // 设置当前分组
     if (data.groupId) {
       groupSelect.value = data.groupId;
@@ -367,6 +395,27 @@ function toggleWordWrap() {
   wordWrapBtn.title = isWrapped ? '取消自动换行' : '启用自动换行';
 }
 
+// 切换占位符展开预览
+async function toggleExpansionPreview() {
+  const showPreview = expansionPreview.style.display === 'none';
+  previewBtn.classList.toggle('active', showPreview);
+  editorTextarea.style.display = showPreview ? 'none' : '';
+  expansionPreview.style.display = showPreview ? 'block' : 'none';
+
+  if (showPreview) {
+    try {
+      expansionPreview.textContent = await invoke('preview_quick_text_expansion', {
+        content: editorTextarea.value
+      });
+    } catch (error) {
+      console.error('预览占位符失败:', error);
+      expansionPreview.textContent = editorTextarea.value;
+    }
+  } else {
+    editorTextarea.focus();
+  }
+}
+
 // 加载分组列表
 async function loadGroups() {
   try {
@@ -0,0 +1,75 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+const statusEl = document.getElementById('result-status');
+const textEl = document.getElementById('result-text');
+const copyBtn = document.getElementById('copy-btn');
+const closeBtn = document.getElementById('close-btn');
+
+function setStatus(text, isError = false) {
+  statusEl.textContent = text;
+  statusEl.classList.toggle('error', isError);
+}
+
+function setText(text) {
+  textEl.textContent = text;
+  textEl.scrollTop = textEl.scrollHeight;
+}
+
+// 按后端保存的状态还原窗口内容
+function applyState(state) {
+  setText(state.text);
+  if (state.status === 'translating') {
+    setStatus('翻译中...');
+  } else if (state.status === 'complete') {
+    setStatus('翻译完成');
+  } else if (state.status === 'error') {
+    setStatus(state.message, true);
+  }
+}
+
+async function init() {
+  await Promise.all([
+    listen('translation-window-start', () => {
+      setText('');
+      setStatus('翻译中...');
+    }),
+    listen('translation-window-chunk', (event) => {
+      setText(event.payload.text);
+    }),
+    listen('translation-window-complete', () => {
+      setStatus('翻译完成');
+    }),
+    listen('translation-window-error', (event) => {
+      setStatus(event.payload.message, true);
+    }),
+  ]);
+
+  // 窗口首次创建时，页面加载前发送的事件会丢失，监听就绪后主动拉取当前状态
+  try {
+    applyState(await invoke('get_translation_window_state'));
+  } catch (error) {
+    console.error('获取翻译状态失败:', error);
+  }
+}
+
+init();
+
+copyBtn.addEventListener('click', async () => {
+  try {
+    await navigator.clipboard.writeText(textEl.textContent);
+    setStatus('已复制');
+  } catch (error) {
+    setStatus('复制失败', true);
+  }
+});
+
+closeBtn.addEventListener('click', () => {
+  invoke('close_translation_window');
+});
+
+document.addEventListener('keydown', (event) => {
+  if (event.key === 'Escape') {
+    invoke('close_translation_window');
+  }
+});